@@ -1,10 +1,36 @@
 //! Implementation of an `Address` which refers either an account hash, or a contract hash.
+//!
+//! An `Address` is serialized as the [`Key`] it converts to:
+//!
+//! * [`Address::Account`] as [`Key::Account`],
+//! * [`Address::Contract`] as [`Key::Hash`] of the contract package hash.
+//!
+//! Deserializing a [`Key::Hash`] always yields a contract package hash, since the token only
+//! ever sees contracts under their package. Every other variant of [`Key`] is rejected: a
+//! [`Key::URef`] for example can't appear on the call stack, so tokens credited to it could never
+//! be moved again.
+//!
+//! Other identities convert to the address they act under:
+//!
+//! * a [`PublicKey`] to the account hash of the account it controls, which can be credited before
+//!   the account exists,
+//! * a [`Contract`] to the package it belongs to, see [`Address::from_contract`].
+//!
+//! As a string, an `Address` is formatted as its [`Key`], i.e. `account-hash-…` or `hash-…`.
+//! Parsing also accepts a formatted contract package hash, i.e. `contract-package-wasm…`. With
+//! the `std` feature, serde (de)serializes an `Address` as this string.
+#[cfg(feature = "std")]
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt, str::FromStr};
+
 use casper_types::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped, ContractPackageHash, Key,
+    CLType, CLTyped, Contract, ContractPackageHash, Key, PublicKey,
 };
+#[cfg(feature = "std")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// An enum representing an [`AccountHash`] or a [`ContractPackageHash`].
 #[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy, Debug)]
@@ -33,6 +59,15 @@ impl Address {
             None
         }
     }
+
+    /// Returns the address of the package `contract` belongs to.
+    ///
+    /// A contract hash can't be turned into an address on its own, as a package may hold several
+    /// contract versions. Off-chain callers can read the [`Contract`] stored under its hash and
+    /// pass it here instead.
+    pub fn from_contract(contract: &Contract) -> Address {
+        Address::Contract(contract.contract_package_hash())
+    }
 }
 
 impl From<ContractPackageHash> for Address {
@@ -47,6 +82,34 @@ impl From<AccountHash> for Address {
     }
 }
 
+impl From<&PublicKey> for Address {
+    fn from(public_key: &PublicKey) -> Self {
+        Self::Account(public_key.to_account_hash())
+    }
+}
+
+impl From<PublicKey> for Address {
+    fn from(public_key: PublicKey) -> Self {
+        Self::from(&public_key)
+    }
+}
+
+impl TryFrom<Key> for Address {
+    type Error = Key;
+
+    /// Converts a [`Key::Account`] or a [`Key::Hash`], or returns back any other key.
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        match key {
+            Key::Account(account_hash) => Ok(Address::Account(account_hash)),
+            Key::Hash(raw_contract_package_hash) => {
+                let contract_package_hash = ContractPackageHash::new(raw_contract_package_hash);
+                Ok(Address::Contract(contract_package_hash))
+            }
+            _ => Err(key),
+        }
+    }
+}
+
 impl From<Address> for Key {
     fn from(address: Address) -> Self {
         match address {
@@ -56,6 +119,60 @@ impl From<Address> for Key {
     }
 }
 
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&Key::from(*self).to_formatted_string())
+    }
+}
+
+/// Error returned when parsing an [`Address`] from a string.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum AddressFromStrError {
+    /// The string is not a formatted key nor a formatted contract package hash.
+    InvalidFormat,
+    /// The string is a formatted key of a variant which is not an address, e.g. `uref-…`.
+    UnsupportedKey,
+}
+
+impl fmt::Display for AddressFromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressFromStrError::InvalidFormat => f.write_str("invalid address format"),
+            AddressFromStrError::UnsupportedKey => f.write_str("key is not an address"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressFromStrError {}
+
+impl FromStr for Address {
+    type Err = AddressFromStrError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Ok(contract_package_hash) = ContractPackageHash::from_formatted_str(input) {
+            return Ok(Address::Contract(contract_package_hash));
+        }
+        let key = Key::from_formatted_str(input).map_err(|_| AddressFromStrError::InvalidFormat)?;
+        Address::try_from(key).map_err(|_| AddressFromStrError::UnsupportedKey)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let formatted = String::deserialize(deserializer)?;
+        formatted.parse().map_err(de::Error::custom)
+    }
+}
+
 impl CLTyped for Address {
     fn cl_type() -> casper_types::CLType {
         CLType::Key
@@ -75,16 +192,7 @@ impl ToBytes for Address {
 impl FromBytes for Address {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (key, remainder) = Key::from_bytes(bytes)?;
-
-        let address = match key {
-            Key::Account(account_hash) => Address::Account(account_hash),
-            Key::Hash(raw_contract_package_hash) => {
-                let contract_package_hash = ContractPackageHash::new(raw_contract_package_hash);
-                Address::Contract(contract_package_hash)
-            }
-            _ => return Err(bytesrepr::Error::Formatting),
-        };
-
+        let address = Address::try_from(key).map_err(|_| bytesrepr::Error::Formatting)?;
         Ok((address, remainder))
     }
 }
@@ -0,0 +1,35 @@
+//! Implementation of the aggregation of batched token amounts.
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_types::U256;
+
+use crate::error::Error;
+
+/// Sums the amounts of every distinct id of a batch, in the order each id first appears.
+///
+/// Returns [`Error::LengthMismatch`] unless there are as many amounts as ids, and
+/// [`Error::Overflow`] if the sum for an id overflows.
+pub(crate) fn coalesce<'a>(
+    ids: &'a [String],
+    amounts: &[U256],
+) -> Result<Vec<(&'a str, U256)>, Error> {
+    if ids.len() != amounts.len() {
+        return Err(Error::LengthMismatch);
+    }
+    let mut positions: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut totals: Vec<(&str, U256)> = Vec::new();
+    for (id, amount) in ids.iter().zip(amounts) {
+        match positions.get(id.as_str()) {
+            Some(&position) => {
+                let total = &mut totals[position].1;
+                *total = total.checked_add(*amount).ok_or(Error::Overflow)?;
+            }
+            None => {
+                positions.insert(id, totals.len());
+                totals.push((id, *amount));
+            }
+        }
+    }
+    Ok(totals)
+}
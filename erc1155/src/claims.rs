@@ -0,0 +1,75 @@
+//! Implementation of Merkle airdrop claims.
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, URef};
+
+use crate::{
+    constants::{CLAIMED_KEY_NAME, CLAIM_ROOTS_KEY_NAME},
+    detail, Address,
+};
+
+/// Creates a dictionary item key for the root of a campaign.
+#[inline]
+fn make_campaign_item_key(campaign: &str) -> String {
+    let key_bytes = runtime::blake2b(campaign.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Creates a dictionary item key for a leaf claimed within a campaign.
+#[inline]
+fn make_claimed_item_key(campaign: &str, leaf: [u8; 32]) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut campaign.to_bytes().unwrap_or_revert());
+    preimage.extend_from_slice(&leaf);
+
+    let key_bytes = runtime::blake2b(&preimage);
+    base64::encode(&key_bytes)
+}
+
+/// Get claim roots uref of contract context.
+pub(crate) fn claim_roots_uref() -> URef {
+    detail::get_uref(CLAIM_ROOTS_KEY_NAME)
+}
+
+/// Get claimed leaves uref of contract context.
+pub(crate) fn claimed_uref() -> URef {
+    detail::get_uref(CLAIMED_KEY_NAME)
+}
+
+/// Reads the Merkle root of a campaign, and the address which registered it, from a dictionary.
+pub(crate) fn read_claim_root_from(
+    claim_roots_uref: URef,
+    campaign: &str,
+) -> Option<([u8; 32], Address)> {
+    let dictionary_item_key = make_campaign_item_key(campaign);
+    storage::dictionary_get(claim_roots_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the Merkle root of a campaign, and the address which registered it, into a dictionary.
+pub(crate) fn write_claim_root_to(
+    claim_roots_uref: URef,
+    campaign: &str,
+    root: [u8; 32],
+    registrar: Address,
+) {
+    let dictionary_item_key = make_campaign_item_key(campaign);
+    storage::dictionary_put(claim_roots_uref, &dictionary_item_key, (root, registrar));
+}
+
+/// Reads whether a leaf of a campaign was already claimed.
+pub(crate) fn read_claimed_from(claimed_uref: URef, campaign: &str, leaf: [u8; 32]) -> bool {
+    let dictionary_item_key = make_claimed_item_key(campaign, leaf);
+    storage::dictionary_get(claimed_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Marks a leaf of a campaign as claimed.
+pub(crate) fn write_claimed_to(claimed_uref: URef, campaign: &str, leaf: [u8; 32]) {
+    let dictionary_item_key = make_claimed_item_key(campaign, leaf);
+    storage::dictionary_put(claimed_uref, &dictionary_item_key, true);
+}
@@ -0,0 +1,90 @@
+//! Implementation of the settings chosen when installing the contract.
+use alloc::vec::Vec;
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped,
+};
+
+use crate::{constants::CALLER_POLICY_KEY_NAME, detail, Address};
+
+/// Settings of the contract which can only be chosen at install.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Config {
+    /// Signers of the multisig admin. The installer becomes the only signer when empty.
+    pub signers: Vec<Address>,
+    /// Amount of signer approvals needed to execute a multisig proposal. Zero means one.
+    pub threshold: u32,
+    /// Minimum delay between queueing and executing a timelocked operation.
+    pub min_delay: u64,
+    /// Rule resolving the caller of every entry point.
+    pub caller_policy: CallerPolicy,
+}
+
+const CALLER_POLICY_IMMEDIATE_CALLER_TAG: u8 = 0;
+const CALLER_POLICY_ORIGINATING_ACCOUNT_TAG: u8 = 1;
+const CALLER_POLICY_REJECT_STORED_SESSIONS_TAG: u8 = 2;
+
+/// Rule resolving the caller of an entry point from the call stack.
+///
+/// The caller is the address whose balances, operators and roles an entry point acts upon.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CallerPolicy {
+    /// The caller is the element of the call stack right below the token: the account hash for
+    /// session and stored session code, and the contract package hash for stored contracts.
+    ImmediateCaller,
+    /// The caller is the account which sent the deploy, whichever contracts it went through.
+    ///
+    /// # Security
+    /// Like `tx.origin` on Ethereum, this lets any contract an account calls act as that account:
+    /// a malicious contract can transfer, approve and burn the tokens of every account which
+    /// calls it. Only choose it when every contract calling the token is trusted.
+    OriginatingAccount,
+    /// Same as [`CallerPolicy::ImmediateCaller`], but calls made directly from stored session code
+    /// are rejected.
+    RejectStoredSessions,
+}
+
+impl Default for CallerPolicy {
+    fn default() -> Self {
+        CallerPolicy::ImmediateCaller
+    }
+}
+
+impl CLTyped for CallerPolicy {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for CallerPolicy {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            CallerPolicy::ImmediateCaller => CALLER_POLICY_IMMEDIATE_CALLER_TAG,
+            CallerPolicy::OriginatingAccount => CALLER_POLICY_ORIGINATING_ACCOUNT_TAG,
+            CallerPolicy::RejectStoredSessions => CALLER_POLICY_REJECT_STORED_SESSIONS_TAG,
+        };
+        tag.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for CallerPolicy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let caller_policy = match tag {
+            CALLER_POLICY_IMMEDIATE_CALLER_TAG => CallerPolicy::ImmediateCaller,
+            CALLER_POLICY_ORIGINATING_ACCOUNT_TAG => CallerPolicy::OriginatingAccount,
+            CALLER_POLICY_REJECT_STORED_SESSIONS_TAG => CallerPolicy::RejectStoredSessions,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((caller_policy, remainder))
+    }
+}
+
+/// Reads the caller policy chosen at install.
+pub(crate) fn read_caller_policy() -> CallerPolicy {
+    detail::read_from(CALLER_POLICY_KEY_NAME)
+}
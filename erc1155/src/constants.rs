@@ -59,3 +59,233 @@ pub const FROM_RUNTIME_ARG_NAME: &str = "from";
 pub const URI_RUNTIME_ARG_NAME: &str = "uri";
 /// Name of `approved` runtime argument.
 pub const APPROVED__RUNTIME_ARG_NAME: &str = "approved";
+/// Name of named-key for `owner`
+pub const OWNER_KEY_NAME: &str = "owner";
+/// Name of dictionary-key for `claim_roots`
+pub const CLAIM_ROOTS_KEY_NAME: &str = "claim_roots";
+/// Name of dictionary-key for `claimed`
+pub const CLAIMED_KEY_NAME: &str = "claimed";
+/// Name of `set_claim_root` entry point.
+pub const SET_CLAIM_ROOT_ENTRY_POINT_NAME: &str = "set_claim_root";
+/// Name of `claim` entry point.
+pub const CLAIM_ENTRY_POINT_NAME: &str = "claim";
+/// Name of `campaign` runtime argument.
+pub const CAMPAIGN_RUNTIME_ARG_NAME: &str = "campaign";
+/// Name of `root` runtime argument.
+pub const ROOT_RUNTIME_ARG_NAME: &str = "root";
+/// Name of `proof` runtime argument.
+pub const PROOF_RUNTIME_ARG_NAME: &str = "proof";
+/// Name of dictionary-key for `sales`
+pub const SALES_KEY_NAME: &str = "sales";
+/// Name of dictionary-key for `purchases`
+pub const PURCHASES_KEY_NAME: &str = "purchases";
+/// Name of named-key for `treasury`
+pub const TREASURY_KEY_NAME: &str = "treasury";
+/// Name of `set_sale` entry point.
+pub const SET_SALE_ENTRY_POINT_NAME: &str = "set_sale";
+/// Name of `purchase` entry point.
+pub const PURCHASE_ENTRY_POINT_NAME: &str = "purchase";
+/// Name of `withdraw` entry point.
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+/// Name of `price` runtime argument.
+pub const PRICE_RUNTIME_ARG_NAME: &str = "price";
+/// Name of `start_time` runtime argument.
+pub const START_TIME_RUNTIME_ARG_NAME: &str = "start_time";
+/// Name of `end_time` runtime argument.
+pub const END_TIME_RUNTIME_ARG_NAME: &str = "end_time";
+/// Name of `wallet_limit` runtime argument.
+pub const WALLET_LIMIT_RUNTIME_ARG_NAME: &str = "wallet_limit";
+/// Name of `supply` runtime argument.
+pub const SUPPLY_RUNTIME_ARG_NAME: &str = "supply";
+/// Name of `purse` runtime argument.
+pub const PURSE_RUNTIME_ARG_NAME: &str = "purse";
+/// Name of dictionary-key for `wrapped_tokens`
+pub const WRAPPED_TOKENS_KEY_NAME: &str = "wrapped_tokens";
+/// Name of `wrap` entry point.
+pub const WRAP_ENTRY_POINT_NAME: &str = "wrap";
+/// Name of `unwrap` entry point.
+pub const UNWRAP_ENTRY_POINT_NAME: &str = "unwrap";
+/// Name of `erc20_contract` runtime argument.
+pub const ERC20_CONTRACT_RUNTIME_ARG_NAME: &str = "erc20_contract";
+/// Name of dictionary-key for `fractions`
+pub const FRACTIONS_KEY_NAME: &str = "fractions";
+/// Name of dictionary-key for `nft_locks`
+pub const NFT_LOCKS_KEY_NAME: &str = "nft_locks";
+/// Name of `fractionalize` entry point.
+pub const FRACTIONALIZE_ENTRY_POINT_NAME: &str = "fractionalize";
+/// Name of `redeem` entry point.
+pub const REDEEM_ENTRY_POINT_NAME: &str = "redeem";
+/// Name of `fractionalized_nft` entry point.
+pub const FRACTIONALIZED_NFT_ENTRY_POINT_NAME: &str = "fractionalized_nft";
+/// Name of `is_nft_locked` entry point.
+pub const IS_NFT_LOCKED_ENTRY_POINT_NAME: &str = "is_nft_locked";
+/// Name of `nft_contract` runtime argument.
+pub const NFT_CONTRACT_RUNTIME_ARG_NAME: &str = "nft_contract";
+/// Name of `token_id` runtime argument.
+pub const NFT_TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
+/// Name of dictionary-key for `offers`
+pub const OFFERS_KEY_NAME: &str = "offers";
+/// Name of named-key for `offers_count`
+pub const OFFERS_COUNT_KEY_NAME: &str = "offers_count";
+/// Name of `create_offer` entry point.
+pub const CREATE_OFFER_ENTRY_POINT_NAME: &str = "create_offer";
+/// Name of `accept_offer` entry point.
+pub const ACCEPT_OFFER_ENTRY_POINT_NAME: &str = "accept_offer";
+/// Name of `cancel_offer` entry point.
+pub const CANCEL_OFFER_ENTRY_POINT_NAME: &str = "cancel_offer";
+/// Name of `offer_id` runtime argument.
+pub const OFFER_ID_RUNTIME_ARG_NAME: &str = "offer_id";
+/// Name of `taker` runtime argument.
+pub const TAKER_RUNTIME_ARG_NAME: &str = "taker";
+/// Name of `offered_ids` runtime argument.
+pub const OFFERED_IDS_RUNTIME_ARG_NAME: &str = "offered_ids";
+/// Name of `offered_amounts` runtime argument.
+pub const OFFERED_AMOUNTS_RUNTIME_ARG_NAME: &str = "offered_amounts";
+/// Name of `wanted_ids` runtime argument.
+pub const WANTED_IDS_RUNTIME_ARG_NAME: &str = "wanted_ids";
+/// Name of `wanted_amounts` runtime argument.
+pub const WANTED_AMOUNTS_RUNTIME_ARG_NAME: &str = "wanted_amounts";
+/// Name of `expiry` runtime argument.
+pub const EXPIRY_RUNTIME_ARG_NAME: &str = "expiry";
+/// Name of named-key for `snapshot_id`
+pub const SNAPSHOT_ID_KEY_NAME: &str = "snapshot_id";
+/// Name of dictionary-key for `balance_snapshots`
+pub const BALANCE_SNAPSHOTS_KEY_NAME: &str = "balance_snapshots";
+/// Name of dictionary-key for `total_supply_snapshots`
+pub const TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME: &str = "total_supply_snapshots";
+/// Name of `snapshot` entry point.
+pub const SNAPSHOT_ENTRY_POINT_NAME: &str = "snapshot";
+/// Name of `balance_of_at` entry point.
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+/// Name of `total_supply_at` entry point.
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+/// Name of `snapshot_id` runtime argument.
+pub const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+/// Name of dictionary-key for `rentals`
+pub const RENTALS_KEY_NAME: &str = "rentals";
+/// Name of dictionary-key for `rented_from`
+pub const RENTED_FROM_KEY_NAME: &str = "rented_from";
+/// Name of `set_user` entry point.
+pub const SET_USER_ENTRY_POINT_NAME: &str = "set_user";
+/// Name of `revoke_user` entry point.
+pub const REVOKE_USER_ENTRY_POINT_NAME: &str = "revoke_user";
+/// Name of `user_of` entry point.
+pub const USER_OF_ENTRY_POINT_NAME: &str = "user_of";
+/// Name of `usable_balance_of` entry point.
+pub const USABLE_BALANCE_OF_ENTRY_POINT_NAME: &str = "usable_balance_of";
+/// Name of `user` runtime argument.
+pub const USER_RUNTIME_ARG_NAME: &str = "user";
+/// Name of `expires` runtime argument.
+pub const EXPIRES_RUNTIME_ARG_NAME: &str = "expires";
+/// Name of dictionary-key for `expiries`
+pub const EXPIRIES_KEY_NAME: &str = "expiries";
+/// Name of `mint_with_expiry` entry point.
+pub const MINT_WITH_EXPIRY_ENTRY_POINT_NAME: &str = "mint_with_expiry";
+/// Name of `sweep_expired` entry point.
+pub const SWEEP_EXPIRED_ENTRY_POINT_NAME: &str = "sweep_expired";
+/// Name of `expiry_of` entry point.
+pub const EXPIRY_OF_ENTRY_POINT_NAME: &str = "expiry_of";
+/// Name of dictionary-key for `token_types`
+pub const TOKEN_TYPES_KEY_NAME: &str = "token_types";
+/// Name of dictionary-key for `minters`
+pub const MINTERS_KEY_NAME: &str = "minters";
+/// Name of named-key for `last_token_id`
+pub const LAST_TOKEN_ID_KEY_NAME: &str = "last_token_id";
+/// Name of `create` entry point.
+pub const CREATE_ENTRY_POINT_NAME: &str = "create";
+/// Name of `set_minter` entry point.
+pub const SET_MINTER_ENTRY_POINT_NAME: &str = "set_minter";
+/// Name of `creator_of` entry point.
+pub const CREATOR_OF_ENTRY_POINT_NAME: &str = "creator_of";
+/// Name of `exists` entry point.
+pub const EXISTS_ENTRY_POINT_NAME: &str = "exists";
+/// Name of `initial_supply` runtime argument.
+pub const INITIAL_SUPPLY_RUNTIME_ARG_NAME: &str = "initial_supply";
+/// Name of `fungible` runtime argument.
+pub const FUNGIBLE_RUNTIME_ARG_NAME: &str = "fungible";
+/// Name of `minter` runtime argument.
+pub const MINTER_RUNTIME_ARG_NAME: &str = "minter";
+/// Name of dictionary-key for `nft_owners`
+pub const NFT_OWNERS_KEY_NAME: &str = "nft_owners";
+/// Name of `owner_of` entry point.
+pub const OWNER_OF_ENTRY_POINT_NAME: &str = "owner_of";
+/// Name of named-key for `signers`
+pub const SIGNERS_KEY_NAME: &str = "signers";
+/// Name of named-key for `threshold`
+pub const THRESHOLD_KEY_NAME: &str = "threshold";
+/// Name of dictionary-key for `proposals`
+pub const PROPOSALS_KEY_NAME: &str = "proposals";
+/// Name of named-key for `proposals_count`
+pub const PROPOSALS_COUNT_KEY_NAME: &str = "proposals_count";
+/// Name of named-key for `paused`
+pub const PAUSED_KEY_NAME: &str = "paused";
+/// Name of `propose` entry point.
+pub const PROPOSE_ENTRY_POINT_NAME: &str = "propose";
+/// Name of `approve` entry point.
+pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
+/// Name of `execute` entry point.
+pub const EXECUTE_ENTRY_POINT_NAME: &str = "execute";
+/// Name of `action` runtime argument.
+pub const ACTION_RUNTIME_ARG_NAME: &str = "action";
+/// Name of `proposal_id` runtime argument.
+pub const PROPOSAL_ID_RUNTIME_ARG_NAME: &str = "proposal_id";
+/// Name of `signers` runtime argument.
+pub const SIGNERS_RUNTIME_ARG_NAME: &str = "signers";
+/// Name of `threshold` runtime argument.
+pub const THRESHOLD_RUNTIME_ARG_NAME: &str = "threshold";
+/// Name of dictionary-key for `operations`
+pub const OPERATIONS_KEY_NAME: &str = "operations";
+/// Name of named-key for `operations_count`
+pub const OPERATIONS_COUNT_KEY_NAME: &str = "operations_count";
+/// Name of named-key for `min_delay`
+pub const MIN_DELAY_KEY_NAME: &str = "min_delay";
+/// Name of dictionary-key for `supply_caps`
+pub const SUPPLY_CAPS_KEY_NAME: &str = "supply_caps";
+/// Name of `queue_operation` entry point.
+pub const QUEUE_OPERATION_ENTRY_POINT_NAME: &str = "queue_operation";
+/// Name of `cancel_operation` entry point.
+pub const CANCEL_OPERATION_ENTRY_POINT_NAME: &str = "cancel_operation";
+/// Name of `execute_operation` entry point.
+pub const EXECUTE_OPERATION_ENTRY_POINT_NAME: &str = "execute_operation";
+/// Name of `min_delay` entry point.
+pub const MIN_DELAY_ENTRY_POINT_NAME: &str = "min_delay";
+/// Name of `supply_cap` entry point.
+pub const SUPPLY_CAP_ENTRY_POINT_NAME: &str = "supply_cap";
+/// Name of `operation` runtime argument.
+pub const OPERATION_RUNTIME_ARG_NAME: &str = "operation";
+/// Name of `operation_id` runtime argument.
+pub const OPERATION_ID_RUNTIME_ARG_NAME: &str = "operation_id";
+/// Name of `delay` runtime argument.
+pub const DELAY_RUNTIME_ARG_NAME: &str = "delay";
+/// Name of `min_delay` runtime argument.
+pub const MIN_DELAY_RUNTIME_ARG_NAME: &str = "min_delay";
+/// Name of named-key for `pending_owner`
+pub const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+/// Name of `owner` entry point.
+pub const OWNER_ENTRY_POINT_NAME: &str = "owner";
+/// Name of `pending_owner` entry point.
+pub const PENDING_OWNER_ENTRY_POINT_NAME: &str = "pending_owner";
+/// Name of `transfer_ownership` entry point.
+pub const TRANSFER_OWNERSHIP_ENTRY_POINT_NAME: &str = "transfer_ownership";
+/// Name of `accept_ownership` entry point.
+pub const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+/// Name of `cancel_ownership_transfer` entry point.
+pub const CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME: &str = "cancel_ownership_transfer";
+/// Name of `renounce_ownership` entry point.
+pub const RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME: &str = "renounce_ownership";
+/// Name of `new_owner` runtime argument.
+pub const NEW_OWNER_RUNTIME_ARG_NAME: &str = "new_owner";
+/// Name of named-key for `caller_policy`
+pub const CALLER_POLICY_KEY_NAME: &str = "caller_policy";
+/// Name of `caller_policy` entry point.
+pub const CALLER_POLICY_ENTRY_POINT_NAME: &str = "caller_policy";
+/// Name of `caller_policy` runtime argument.
+pub const CALLER_POLICY_RUNTIME_ARG_NAME: &str = "caller_policy";
+/// Name of `safe_multi_transfer_from` entry point.
+pub const SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "safe_multi_transfer_from";
+/// Name of `recipients` runtime argument.
+pub const RECIPIENTS_RUNTIME_ARG_NAME: &str = "recipients";
+/// Name of `mint_batch` entry point.
+pub const MINT_BATCH_ENTRY_POINT_NAME: &str = "mint_batch";
+/// Name of `burn_batch` entry point.
+pub const BURN_BATCH_ENTRY_POINT_NAME: &str = "burn_batch";
@@ -7,7 +7,11 @@ use casper_contract::{
 };
 use casper_types::{bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, URef};
 
-use crate::{error::Error, Address};
+use crate::{
+    config::{self, CallerPolicy},
+    error::Error,
+    Address,
+};
 
 /// Gets [`URef`] under a name.
 pub(crate) fn get_uref(name: &str) -> URef {
@@ -17,6 +21,19 @@ pub(crate) fn get_uref(name: &str) -> URef {
     key.try_into().unwrap_or_revert()
 }
 
+/// Gets [`URef`] under a name, if the key exists.
+///
+/// Used for the keys of extensions which might not be installed.
+pub(crate) fn get_optional_uref(name: &str) -> Option<URef> {
+    runtime::get_key(name).map(|key| key.try_into().unwrap_or_revert())
+}
+
+/// Unwraps the [`URef`] of an extension, reverting with [`ApiError::MissingKey`] if the extension
+/// isn't installed.
+pub(crate) fn unwrap_installed(uref: Option<URef>) -> URef {
+    uref.ok_or(ApiError::MissingKey).unwrap_or_revert()
+}
+
 /// Reads value from a named key.
 pub(crate) fn read_from<T>(name: &str) -> T
 where
@@ -52,13 +69,43 @@ fn call_stack_element_to_address(call_stack_element: CallStackElement) -> Addres
     }
 }
 
-/// Gets the immediate session caller of the current execution.
+/// Gets the account which sent the deploy of the current execution.
+fn get_originating_account_address() -> Result<Address, Error> {
+    match runtime::get_call_stack().into_iter().next() {
+        Some(CallStackElement::Session { account_hash })
+        | Some(CallStackElement::StoredSession { account_hash, .. }) => {
+            Ok(Address::from(account_hash))
+        }
+        _ => Err(Error::InvalidContext),
+    }
+}
+
+/// Gets the caller of the current execution, according to the [`CallerPolicy`] chosen at install.
 ///
-/// This function ensures that only session code can execute this function, and disallows stored
-/// session/stored contracts.
+/// Returns [`Error::InvalidContext`] if the call stack has no caller, or if the policy rejects the
+/// shape of the call stack.
 pub(crate) fn get_immediate_caller_address() -> Result<Address, Error> {
-    get_immediate_call_stack_item()
-        .map(call_stack_element_to_address)
-        .ok_or(Error::InvalidContext)
+    match config::read_caller_policy() {
+        CallerPolicy::ImmediateCaller => get_immediate_call_stack_item()
+            .map(call_stack_element_to_address)
+            .ok_or(Error::InvalidContext),
+        CallerPolicy::OriginatingAccount => get_originating_account_address(),
+        CallerPolicy::RejectStoredSessions => match get_immediate_call_stack_item() {
+            Some(CallStackElement::StoredSession { .. }) | None => Err(Error::InvalidContext),
+            Some(call_stack_element) => Ok(call_stack_element_to_address(call_stack_element)),
+        },
+    }
 }
 
+/// Gets the address of the contract package currently executing.
+///
+/// Returns [`Error::InvalidContext`] unless the current execution is stored contract code.
+pub(crate) fn get_self_address() -> Result<Address, Error> {
+    match runtime::get_call_stack().into_iter().last() {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Ok(Address::from(contract_package_hash)),
+        _ => Err(Error::InvalidContext),
+    }
+}
@@ -2,21 +2,54 @@
 use alloc::{string::String, vec, vec::Vec};
 
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
+    CLType, CLTyped, ContractHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Parameter, URef, U256, U512,
 };
 
 use crate::{
     address::Address,
+    config::CallerPolicy,
     constants::{
-        ACCOUNTS_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, AMOUNTS_RUNTIME_ARG_NAME,
-        AMOUNT_RUNTIME_ARG_NAME, APPROVED_RUNTIME_ARG_NAME, BALANCE_OF_BATCH_ENTRY_POINT_NAME,
-        BALANCE_OF_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, FROM_RUNTIME_ARG_NAME,
-        IS_APPROVAL_FOR_ALL_ENTRY_POINT_NAME, MINT_ENTRY_POINT_NAME, OPERATOR_RUNTIME_ARG_NAME,
-        OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME, SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
-        SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME, TOKEN_IDS_RUNTIME_ARG_NAME,
-        TOKEN_ID_RUNTIME_ARG_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, URI_ENTRY_POINT_NAME,
+        ACCEPT_OFFER_ENTRY_POINT_NAME, ACCEPT_OWNERSHIP_ENTRY_POINT_NAME,
+        ACCOUNTS_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, ACTION_RUNTIME_ARG_NAME,
+        AMOUNTS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, APPROVED_RUNTIME_ARG_NAME,
+        APPROVE_ENTRY_POINT_NAME, BALANCE_OF_AT_ENTRY_POINT_NAME,
+        BALANCE_OF_BATCH_ENTRY_POINT_NAME, BALANCE_OF_ENTRY_POINT_NAME,
+        BURN_BATCH_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CALLER_POLICY_ENTRY_POINT_NAME,
+        CAMPAIGN_RUNTIME_ARG_NAME, CANCEL_OFFER_ENTRY_POINT_NAME,
+        CANCEL_OPERATION_ENTRY_POINT_NAME, CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME,
+        CLAIM_ENTRY_POINT_NAME, CREATE_ENTRY_POINT_NAME, CREATE_OFFER_ENTRY_POINT_NAME,
+        CREATOR_OF_ENTRY_POINT_NAME, DELAY_RUNTIME_ARG_NAME, END_TIME_RUNTIME_ARG_NAME,
+        ERC20_CONTRACT_RUNTIME_ARG_NAME, EXECUTE_ENTRY_POINT_NAME,
+        EXECUTE_OPERATION_ENTRY_POINT_NAME, EXISTS_ENTRY_POINT_NAME, EXPIRES_RUNTIME_ARG_NAME,
+        EXPIRY_OF_ENTRY_POINT_NAME, EXPIRY_RUNTIME_ARG_NAME, FRACTIONALIZED_NFT_ENTRY_POINT_NAME,
+        FRACTIONALIZE_ENTRY_POINT_NAME, FROM_RUNTIME_ARG_NAME, FUNGIBLE_RUNTIME_ARG_NAME,
+        INITIAL_SUPPLY_RUNTIME_ARG_NAME, IS_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+        IS_NFT_LOCKED_ENTRY_POINT_NAME, MINTER_RUNTIME_ARG_NAME, MINT_BATCH_ENTRY_POINT_NAME,
+        MINT_ENTRY_POINT_NAME, MINT_WITH_EXPIRY_ENTRY_POINT_NAME, MIN_DELAY_ENTRY_POINT_NAME,
+        NEW_OWNER_RUNTIME_ARG_NAME, NFT_CONTRACT_RUNTIME_ARG_NAME, NFT_TOKEN_ID_RUNTIME_ARG_NAME,
+        OFFERED_AMOUNTS_RUNTIME_ARG_NAME, OFFERED_IDS_RUNTIME_ARG_NAME, OFFER_ID_RUNTIME_ARG_NAME,
+        OPERATION_ID_RUNTIME_ARG_NAME, OPERATION_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME,
+        OWNER_ENTRY_POINT_NAME, OWNER_OF_ENTRY_POINT_NAME, OWNER_RUNTIME_ARG_NAME,
+        PENDING_OWNER_ENTRY_POINT_NAME, PRICE_RUNTIME_ARG_NAME, PROOF_RUNTIME_ARG_NAME,
+        PROPOSAL_ID_RUNTIME_ARG_NAME, PROPOSE_ENTRY_POINT_NAME, PURCHASE_ENTRY_POINT_NAME,
+        PURSE_RUNTIME_ARG_NAME, QUEUE_OPERATION_ENTRY_POINT_NAME, RECIPIENTS_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, REDEEM_ENTRY_POINT_NAME, RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME,
+        REVOKE_USER_ENTRY_POINT_NAME, ROOT_RUNTIME_ARG_NAME,
+        SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME, SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME,
+        SAFE_TRANSFER_FROM_ENTRY_POINT_NAME, SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+        SET_CLAIM_ROOT_ENTRY_POINT_NAME, SET_MINTER_ENTRY_POINT_NAME, SET_SALE_ENTRY_POINT_NAME,
+        SET_USER_ENTRY_POINT_NAME, SNAPSHOT_ENTRY_POINT_NAME, SNAPSHOT_ID_RUNTIME_ARG_NAME,
+        START_TIME_RUNTIME_ARG_NAME, SUPPLY_CAP_ENTRY_POINT_NAME, SUPPLY_RUNTIME_ARG_NAME,
+        SWEEP_EXPIRED_ENTRY_POINT_NAME, TAKER_RUNTIME_ARG_NAME, TOKEN_IDS_RUNTIME_ARG_NAME,
+        TOKEN_ID_RUNTIME_ARG_NAME, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME,
+        TRANSFER_OWNERSHIP_ENTRY_POINT_NAME, UNWRAP_ENTRY_POINT_NAME, URI_ENTRY_POINT_NAME,
+        URI_RUNTIME_ARG_NAME, USABLE_BALANCE_OF_ENTRY_POINT_NAME, USER_OF_ENTRY_POINT_NAME,
+        USER_RUNTIME_ARG_NAME, WALLET_LIMIT_RUNTIME_ARG_NAME, WANTED_AMOUNTS_RUNTIME_ARG_NAME,
+        WANTED_IDS_RUNTIME_ARG_NAME, WITHDRAW_ENTRY_POINT_NAME, WRAP_ENTRY_POINT_NAME,
     },
+    multisig::Action,
+    timelock::Operation,
 };
 
 /// Returns the `uri` entry point.
@@ -24,7 +57,7 @@ pub fn uri() -> EntryPoint {
     EntryPoint::new(
         String::from(URI_ENTRY_POINT_NAME),
         Vec::new(),
-        U256::cl_type(),
+        String::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
@@ -121,6 +154,21 @@ pub fn safe_batch_transfer_from() -> EntryPoint {
         EntryPointType::Contract,
     )
 }
+/// Returns the `safe_multi_transfer_from` entry point.
+pub fn safe_multi_transfer_from() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(RECIPIENTS_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+            Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<String>::cl_type()),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
 /// Returns the `mint` entry point.
 pub fn mint() -> EntryPoint {
     EntryPoint::new(
@@ -149,6 +197,402 @@ pub fn burn() -> EntryPoint {
         EntryPointType::Contract,
     )
 }
+/// Returns the `mint_batch` entry point.
+pub fn mint_batch() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_BATCH_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<String>::cl_type()),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `burn_batch` entry point.
+pub fn burn_batch() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_BATCH_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<String>::cl_type()),
+            Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `set_claim_root` entry point.
+pub fn set_claim_root() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_CLAIM_ROOT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(CAMPAIGN_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ROOT_RUNTIME_ARG_NAME, <[u8; 32]>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `claim` entry point.
+pub fn claim() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CLAIM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(CAMPAIGN_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(PROOF_RUNTIME_ARG_NAME, Vec::<[u8; 32]>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `set_sale` entry point.
+pub fn set_sale() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_SALE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(PRICE_RUNTIME_ARG_NAME, U512::cl_type()),
+            Parameter::new(START_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(END_TIME_RUNTIME_ARG_NAME, u64::cl_type()),
+            Parameter::new(WALLET_LIMIT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(SUPPLY_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `purchase` entry point.
+pub fn purchase() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PURCHASE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(PURSE_RUNTIME_ARG_NAME, URef::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `withdraw` entry point.
+pub fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U512::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `wrap` entry point.
+pub fn wrap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WRAP_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ERC20_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `unwrap` entry point.
+pub fn unwrap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNWRAP_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `fractionalize` entry point.
+pub fn fractionalize() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FRACTIONALIZE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(NFT_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(NFT_TOKEN_ID_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `redeem` entry point.
+pub fn redeem() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REDEEM_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `fractionalized_nft` entry point.
+pub fn fractionalized_nft() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FRACTIONALIZED_NFT_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        Option::<(ContractHash, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `is_nft_locked` entry point.
+pub fn is_nft_locked() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_NFT_LOCKED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(NFT_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(NFT_TOKEN_ID_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `create_offer` entry point.
+pub fn create_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_OFFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TAKER_RUNTIME_ARG_NAME, Option::<Address>::cl_type()),
+            Parameter::new(OFFERED_IDS_RUNTIME_ARG_NAME, Vec::<String>::cl_type()),
+            Parameter::new(OFFERED_AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+            Parameter::new(WANTED_IDS_RUNTIME_ARG_NAME, Vec::<String>::cl_type()),
+            Parameter::new(WANTED_AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+            Parameter::new(EXPIRY_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `accept_offer` entry point.
+pub fn accept_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OFFER_ENTRY_POINT_NAME),
+        vec![Parameter::new(OFFER_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `cancel_offer` entry point.
+pub fn cancel_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_OFFER_ENTRY_POINT_NAME),
+        vec![Parameter::new(OFFER_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `snapshot` entry point.
+pub fn snapshot() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `balance_of_at` entry point.
+pub fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `total_supply_at` entry point.
+pub fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `set_user` entry point.
+pub fn set_user() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_USER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(USER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(EXPIRES_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `revoke_user` entry point.
+pub fn revoke_user() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_USER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `user_of` entry point.
+pub fn user_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(USER_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `usable_balance_of` entry point.
+pub fn usable_balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(USABLE_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(USER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `mint_with_expiry` entry point.
+pub fn mint_with_expiry() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_WITH_EXPIRY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(EXPIRY_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `sweep_expired` entry point.
+pub fn sweep_expired() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SWEEP_EXPIRED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(ACCOUNTS_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `expiry_of` entry point.
+pub fn expiry_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXPIRY_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        Option::<u64>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `create` entry point.
+pub fn create() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, Option::<String>::cl_type()),
+            Parameter::new(INITIAL_SUPPLY_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(URI_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(FUNGIBLE_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `set_minter` entry point.
+pub fn set_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MINTER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+            Parameter::new(MINTER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(APPROVED_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `creator_of` entry point.
+pub fn creator_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATOR_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `exists` entry point.
+pub fn exists() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXISTS_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `owner_of` entry point.
+pub fn owner_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OWNER_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
 /// Returns the default set of ERC1155 token entry points.
 pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
@@ -162,5 +606,249 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(safe_batch_transfer_from());
     entry_points.add_entry_point(mint());
     entry_points.add_entry_point(burn());
+    entry_points.add_entry_point(mint_batch());
+    entry_points.add_entry_point(burn_batch());
+    entry_points
+}
+/// Returns the `propose` entry point.
+pub fn propose() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACTION_RUNTIME_ARG_NAME, Action::cl_type()),
+            Parameter::new(EXPIRY_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `approve` entry point.
+pub fn approve() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `execute` entry point.
+pub fn execute() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID_RUNTIME_ARG_NAME, u64::cl_type())],
+        Option::<u64>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `queue_operation` entry point.
+pub fn queue_operation() -> EntryPoint {
+    EntryPoint::new(
+        String::from(QUEUE_OPERATION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OPERATION_RUNTIME_ARG_NAME, Operation::cl_type()),
+            Parameter::new(DELAY_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `cancel_operation` entry point.
+pub fn cancel_operation() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_OPERATION_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            OPERATION_ID_RUNTIME_ARG_NAME,
+            u64::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `execute_operation` entry point.
+pub fn execute_operation() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_OPERATION_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            OPERATION_ID_RUNTIME_ARG_NAME,
+            u64::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `min_delay` entry point.
+pub fn min_delay() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MIN_DELAY_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `supply_cap` entry point.
+pub fn supply_cap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SUPPLY_CAP_ENTRY_POINT_NAME),
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type())],
+        Option::<U256>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `owner` entry point.
+pub fn owner() -> EntryPoint {
+    EntryPoint::new(
+        String::from(OWNER_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `pending_owner` entry point.
+pub fn pending_owner() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PENDING_OWNER_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `transfer_ownership` entry point.
+pub fn transfer_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            NEW_OWNER_RUNTIME_ARG_NAME,
+            Address::cl_type(),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `accept_ownership` entry point.
+pub fn accept_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OWNERSHIP_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `cancel_ownership_transfer` entry point.
+pub fn cancel_ownership_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `renounce_ownership` entry point.
+pub fn renounce_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the `caller_policy` entry point.
+pub fn caller_policy() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CALLER_POLICY_ENTRY_POINT_NAME),
+        Vec::new(),
+        CallerPolicy::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+/// Returns the entry points of the Merkle claims extension.
+pub fn claims() -> Vec<EntryPoint> {
+    vec![set_claim_root(), claim()]
+}
+/// Returns the entry points of the primary sale extension.
+pub fn sales() -> Vec<EntryPoint> {
+    vec![set_sale(), purchase(), withdraw()]
+}
+/// Returns the entry points of the ERC20 wrapper extension.
+pub fn wrapper() -> Vec<EntryPoint> {
+    vec![wrap(), unwrap()]
+}
+/// Returns the entry points of the NFT fractionalization extension.
+pub fn fractions() -> Vec<EntryPoint> {
+    vec![
+        fractionalize(),
+        redeem(),
+        fractionalized_nft(),
+        is_nft_locked(),
+    ]
+}
+/// Returns the entry points of the escrow extension.
+pub fn escrow() -> Vec<EntryPoint> {
+    vec![create_offer(), accept_offer(), cancel_offer()]
+}
+/// Returns the entry points of the snapshots extension.
+pub fn snapshots() -> Vec<EntryPoint> {
+    vec![snapshot(), balance_of_at(), total_supply_at()]
+}
+/// Returns the entry points of the rentals extension.
+pub fn rentals() -> Vec<EntryPoint> {
+    vec![set_user(), revoke_user(), user_of(), usable_balance_of()]
+}
+/// Returns the entry points of the expiring token ids extension.
+pub fn expiries() -> Vec<EntryPoint> {
+    vec![mint_with_expiry(), sweep_expired(), expiry_of()]
+}
+/// Returns the entry points of the token type registry extension.
+pub fn registry() -> Vec<EntryPoint> {
+    vec![create(), set_minter(), creator_of(), exists(), owner_of()]
+}
+/// Returns the entry points of the multisig admin extension.
+///
+/// When combined with [`timelock`], executed proposals are only applied by its
+/// `execute_operation` entry point.
+pub fn multisig() -> Vec<EntryPoint> {
+    vec![propose(), approve(), execute()]
+}
+/// Returns the entry points of the ownership extension.
+pub fn ownership() -> Vec<EntryPoint> {
+    vec![
+        owner(),
+        pending_owner(),
+        transfer_ownership(),
+        accept_ownership(),
+        cancel_ownership_transfer(),
+        renounce_ownership(),
+    ]
+}
+/// Returns the entry points of the timelock extension.
+pub fn timelock() -> Vec<EntryPoint> {
+    vec![
+        queue_operation(),
+        cancel_operation(),
+        execute_operation(),
+        min_delay(),
+        supply_cap(),
+    ]
+}
+/// Returns the default set of ERC1155 token entry points extended with `extensions`.
+pub fn extended(extensions: Vec<EntryPoint>) -> EntryPoints {
+    let mut entry_points = default();
+    for entry_point in extensions {
+        entry_points.add_entry_point(entry_point);
+    }
     entry_points
 }
@@ -1,16 +1,25 @@
 //! Error handling on the casper platform.
+use core::convert::TryFrom;
+use core::fmt;
+
 use casper_types::ApiError;
 
 /// Errors which can be returned by the library.
 ///
-/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`].
+/// When an `Error` is returned from a smart contract, it is converted to an [`ApiError::User`],
+/// and clients can convert it back with [`Error::try_from`].
 ///
-/// Where a smart contract consuming this library needs to define further error variants, it can
-/// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
-/// variant.
+/// The `u16` codes of [`ApiError::User`] are split in three areas:
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 4)]` (i.e. [0, 65532]) to avoid
-/// conflicting with the other `Error` variants.
+/// * `[u16::MAX - 255, u16::MAX]` is reserved for the variants of `Error`, counting down from
+///   `u16::MAX`.
+/// * The 8 [`ErrorRange`]s of 256 codes right below are reserved for extension modules, which
+///   return their errors via the [`Error::Extension`] variant.
+/// * `[0, Error::MAX_USER_ERROR]` (i.e. [0, 63231]) is left to the smart contract consuming this
+///   library, which can return further error variants via the [`Error::User`] variant or
+///   equivalently via the [`ApiError::User`] variant. A user error outside of this range is
+///   decoded by clients as an error of the library or of an extension module.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
     InvalidContext,
@@ -20,14 +29,198 @@ pub enum Error {
     InsufficientAllowance,
     /// Operation would cause an integer overflow.
     Overflow,
+    /// Caller is not allowed to perform the operation.
+    PermissionDenied,
+    /// Claim campaign has no registered Merkle root.
+    UnknownCampaign,
+    /// Merkle proof does not match the campaign root.
+    InvalidProof,
+    /// Leaf of the claim campaign was already claimed.
+    AlreadyClaimed,
+    /// Token id is not on sale at the current blocktime.
+    SaleNotActive,
+    /// Purse does not hold enough motes to pay for the purchase.
+    InsufficientPayment,
+    /// Purchase exceeds the amount of tokens left on sale.
+    SoldOut,
+    /// Purchase exceeds the amount of tokens a single wallet can purchase.
+    PurchaseLimitExceeded,
+    /// Token id does not wrap any ERC20 token.
+    UnknownWrappedToken,
+    /// NFT is already locked in the contract.
+    NftLocked,
+    /// Token id does not hold the fractions of a locked NFT.
+    NftNotLocked,
+    /// Amount is not valid for the operation.
+    InvalidAmount,
+    /// Lists of recipients, token ids or amounts have different lengths.
+    LengthMismatch,
+    /// Offer does not exist, or was already accepted or cancelled.
+    UnknownOffer,
+    /// Offer can no longer be accepted.
+    OfferExpired,
+    /// Snapshot id was not taken yet.
+    UnknownSnapshot,
+    /// Amount is locked by an active rental.
+    BalanceRented,
+    /// Balance is not rented out to the caller.
+    NotRented,
+    /// Token id expired.
+    TokenExpired,
+    /// Token id did not expire yet.
+    TokenNotExpired,
+    /// Token id was already minted or created.
+    AlreadyMinted,
+    /// Token id was not created.
+    UnknownTokenType,
+    /// Amount would give a non-fungible token id a supply above one.
+    NonFungibleAmount,
+    /// Proposal does not exist, or was already executed.
+    UnknownProposal,
+    /// Proposal can no longer be approved or executed.
+    ProposalExpired,
+    /// Proposal was not approved by enough signers.
+    InsufficientApprovals,
+    /// Threshold is zero or above the amount of signers.
+    InvalidThreshold,
+    /// Token transfers are paused.
+    Paused,
+    /// Operation was never queued, or was already executed or cancelled.
+    UnknownOperation,
+    /// Operation can't be executed before its eta.
+    OperationNotReady,
+    /// Delay is shorter than the minimum delay of the timelock.
+    DelayTooShort,
+    /// No ownership transfer is pending.
+    NoPendingOwner,
+    /// Token id is reserved for the tokens minted by an extension.
+    ReservedTokenId,
+    /// Token id contains the separator of non-fungible item ids.
+    InvalidTokenId,
+    /// Minting would exceed the supply cap of the token.
+    SupplyCapExceeded,
+    /// Sale terms are invalid: its window is empty, or its price or supply is zero.
+    InvalidSale,
+    /// Offer has no offered or no wanted tokens.
+    EmptyOffer,
+    /// Error of an extension module.
+    Extension(ExtensionError),
     /// User error.
     User(u16),
 }
 
+impl Error {
+    /// Largest code of an [`Error::User`] which can't be mistaken for another variant.
+    pub const MAX_USER_ERROR: u16 = EXTENSION_ERRORS_START - 1;
+}
+
+/// Amount of codes of an [`ErrorRange`].
+const ERROR_RANGE_LEN: u16 = 256;
+/// Amount of [`ErrorRange`]s reserved for extension modules.
+const ERROR_RANGES_COUNT: usize = 8;
+/// First code reserved for the variants of [`Error`].
+const LIBRARY_ERRORS_START: u16 = u16::MAX - (ERROR_RANGE_LEN - 1);
+/// First code reserved for the errors of extension modules.
+const EXTENSION_ERRORS_START: u16 =
+    LIBRARY_ERRORS_START - ERROR_RANGE_LEN * ERROR_RANGES_COUNT as u16;
+
+/// A range of 256 error codes reserved for the errors of an extension module.
+///
+/// An extension module registers its errors by picking a range of [`ErrorRange::ALL`] no other
+/// module of the contract uses, and returning [`ErrorRange::error`] for each of its error codes.
+/// The library itself never returns errors of these ranges.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ErrorRange(u8);
+
+impl ErrorRange {
+    /// Every range reserved for extension modules.
+    pub const ALL: [ErrorRange; ERROR_RANGES_COUNT] = [
+        ErrorRange(0),
+        ErrorRange(1),
+        ErrorRange(2),
+        ErrorRange(3),
+        ErrorRange(4),
+        ErrorRange(5),
+        ErrorRange(6),
+        ErrorRange(7),
+    ];
+
+    /// Returns the index of the range in [`ErrorRange::ALL`].
+    pub fn index(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the error `code` of the range.
+    pub fn error(self, code: u8) -> Error {
+        Error::Extension(ExtensionError { range: self, code })
+    }
+
+    /// Returns the first code of the range, counting down from the codes of the library.
+    fn start(self) -> u16 {
+        LIBRARY_ERRORS_START - ERROR_RANGE_LEN * (u16::from(self.0) + 1)
+    }
+}
+
+/// An error returned by an extension module, within its [`ErrorRange`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ExtensionError {
+    range: ErrorRange,
+    code: u8,
+}
+
+impl ExtensionError {
+    /// Returns the range the error belongs to.
+    pub fn range(self) -> ErrorRange {
+        self.range
+    }
+
+    /// Returns the code of the error within its range.
+    pub fn code(self) -> u8 {
+        self.code
+    }
+}
+
 const ERROR_INVALID_CONTEXT: u16 = u16::MAX;
 const ERROR_INSUFFICIENT_BALANCE: u16 = u16::MAX - 1;
 const ERROR_INSUFFICIENT_ALLOWANCE: u16 = u16::MAX - 2;
 const ERROR_OVERFLOW: u16 = u16::MAX - 3;
+const ERROR_PERMISSION_DENIED: u16 = u16::MAX - 4;
+const ERROR_UNKNOWN_CAMPAIGN: u16 = u16::MAX - 5;
+const ERROR_INVALID_PROOF: u16 = u16::MAX - 6;
+const ERROR_ALREADY_CLAIMED: u16 = u16::MAX - 7;
+const ERROR_SALE_NOT_ACTIVE: u16 = u16::MAX - 8;
+const ERROR_INSUFFICIENT_PAYMENT: u16 = u16::MAX - 9;
+const ERROR_SOLD_OUT: u16 = u16::MAX - 10;
+const ERROR_PURCHASE_LIMIT_EXCEEDED: u16 = u16::MAX - 11;
+const ERROR_UNKNOWN_WRAPPED_TOKEN: u16 = u16::MAX - 12;
+const ERROR_NFT_LOCKED: u16 = u16::MAX - 13;
+const ERROR_NFT_NOT_LOCKED: u16 = u16::MAX - 14;
+const ERROR_INVALID_AMOUNT: u16 = u16::MAX - 15;
+const ERROR_LENGTH_MISMATCH: u16 = u16::MAX - 16;
+const ERROR_UNKNOWN_OFFER: u16 = u16::MAX - 17;
+const ERROR_OFFER_EXPIRED: u16 = u16::MAX - 18;
+const ERROR_UNKNOWN_SNAPSHOT: u16 = u16::MAX - 19;
+const ERROR_BALANCE_RENTED: u16 = u16::MAX - 20;
+const ERROR_NOT_RENTED: u16 = u16::MAX - 21;
+const ERROR_TOKEN_EXPIRED: u16 = u16::MAX - 22;
+const ERROR_TOKEN_NOT_EXPIRED: u16 = u16::MAX - 23;
+const ERROR_ALREADY_MINTED: u16 = u16::MAX - 24;
+const ERROR_UNKNOWN_TOKEN_TYPE: u16 = u16::MAX - 25;
+const ERROR_NON_FUNGIBLE_AMOUNT: u16 = u16::MAX - 26;
+const ERROR_UNKNOWN_PROPOSAL: u16 = u16::MAX - 27;
+const ERROR_PROPOSAL_EXPIRED: u16 = u16::MAX - 28;
+const ERROR_INSUFFICIENT_APPROVALS: u16 = u16::MAX - 29;
+const ERROR_INVALID_THRESHOLD: u16 = u16::MAX - 30;
+const ERROR_PAUSED: u16 = u16::MAX - 31;
+const ERROR_UNKNOWN_OPERATION: u16 = u16::MAX - 32;
+const ERROR_OPERATION_NOT_READY: u16 = u16::MAX - 33;
+const ERROR_DELAY_TOO_SHORT: u16 = u16::MAX - 34;
+const ERROR_NO_PENDING_OWNER: u16 = u16::MAX - 35;
+const ERROR_RESERVED_TOKEN_ID: u16 = u16::MAX - 36;
+const ERROR_INVALID_TOKEN_ID: u16 = u16::MAX - 37;
+const ERROR_SUPPLY_CAP_EXCEEDED: u16 = u16::MAX - 38;
+const ERROR_INVALID_SALE: u16 = u16::MAX - 39;
+const ERROR_EMPTY_OFFER: u16 = u16::MAX - 40;
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -36,8 +229,175 @@ impl From<Error> for ApiError {
             Error::InsufficientBalance => ERROR_INSUFFICIENT_BALANCE,
             Error::InsufficientAllowance => ERROR_INSUFFICIENT_ALLOWANCE,
             Error::Overflow => ERROR_OVERFLOW,
+            Error::PermissionDenied => ERROR_PERMISSION_DENIED,
+            Error::UnknownCampaign => ERROR_UNKNOWN_CAMPAIGN,
+            Error::InvalidProof => ERROR_INVALID_PROOF,
+            Error::AlreadyClaimed => ERROR_ALREADY_CLAIMED,
+            Error::SaleNotActive => ERROR_SALE_NOT_ACTIVE,
+            Error::InsufficientPayment => ERROR_INSUFFICIENT_PAYMENT,
+            Error::SoldOut => ERROR_SOLD_OUT,
+            Error::PurchaseLimitExceeded => ERROR_PURCHASE_LIMIT_EXCEEDED,
+            Error::UnknownWrappedToken => ERROR_UNKNOWN_WRAPPED_TOKEN,
+            Error::NftLocked => ERROR_NFT_LOCKED,
+            Error::NftNotLocked => ERROR_NFT_NOT_LOCKED,
+            Error::InvalidAmount => ERROR_INVALID_AMOUNT,
+            Error::LengthMismatch => ERROR_LENGTH_MISMATCH,
+            Error::UnknownOffer => ERROR_UNKNOWN_OFFER,
+            Error::OfferExpired => ERROR_OFFER_EXPIRED,
+            Error::UnknownSnapshot => ERROR_UNKNOWN_SNAPSHOT,
+            Error::BalanceRented => ERROR_BALANCE_RENTED,
+            Error::NotRented => ERROR_NOT_RENTED,
+            Error::TokenExpired => ERROR_TOKEN_EXPIRED,
+            Error::TokenNotExpired => ERROR_TOKEN_NOT_EXPIRED,
+            Error::AlreadyMinted => ERROR_ALREADY_MINTED,
+            Error::UnknownTokenType => ERROR_UNKNOWN_TOKEN_TYPE,
+            Error::NonFungibleAmount => ERROR_NON_FUNGIBLE_AMOUNT,
+            Error::UnknownProposal => ERROR_UNKNOWN_PROPOSAL,
+            Error::ProposalExpired => ERROR_PROPOSAL_EXPIRED,
+            Error::InsufficientApprovals => ERROR_INSUFFICIENT_APPROVALS,
+            Error::InvalidThreshold => ERROR_INVALID_THRESHOLD,
+            Error::Paused => ERROR_PAUSED,
+            Error::UnknownOperation => ERROR_UNKNOWN_OPERATION,
+            Error::OperationNotReady => ERROR_OPERATION_NOT_READY,
+            Error::DelayTooShort => ERROR_DELAY_TOO_SHORT,
+            Error::NoPendingOwner => ERROR_NO_PENDING_OWNER,
+            Error::ReservedTokenId => ERROR_RESERVED_TOKEN_ID,
+            Error::InvalidTokenId => ERROR_INVALID_TOKEN_ID,
+            Error::SupplyCapExceeded => ERROR_SUPPLY_CAP_EXCEEDED,
+            Error::InvalidSale => ERROR_INVALID_SALE,
+            Error::EmptyOffer => ERROR_EMPTY_OFFER,
+            Error::Extension(ExtensionError { range, code }) => range.start() + u16::from(code),
             Error::User(user_error) => user_error,
         };
         ApiError::User(user_error)
     }
 }
+
+impl TryFrom<u16> for Error {
+    type Error = u16;
+
+    /// Decodes the code of an [`ApiError::User`], or returns it back if it is reserved for the
+    /// library but not assigned to any variant.
+    fn try_from(user_error: u16) -> Result<Self, Self::Error> {
+        let error = match user_error {
+            ERROR_INVALID_CONTEXT => Error::InvalidContext,
+            ERROR_INSUFFICIENT_BALANCE => Error::InsufficientBalance,
+            ERROR_INSUFFICIENT_ALLOWANCE => Error::InsufficientAllowance,
+            ERROR_OVERFLOW => Error::Overflow,
+            ERROR_PERMISSION_DENIED => Error::PermissionDenied,
+            ERROR_UNKNOWN_CAMPAIGN => Error::UnknownCampaign,
+            ERROR_INVALID_PROOF => Error::InvalidProof,
+            ERROR_ALREADY_CLAIMED => Error::AlreadyClaimed,
+            ERROR_SALE_NOT_ACTIVE => Error::SaleNotActive,
+            ERROR_INSUFFICIENT_PAYMENT => Error::InsufficientPayment,
+            ERROR_SOLD_OUT => Error::SoldOut,
+            ERROR_PURCHASE_LIMIT_EXCEEDED => Error::PurchaseLimitExceeded,
+            ERROR_UNKNOWN_WRAPPED_TOKEN => Error::UnknownWrappedToken,
+            ERROR_NFT_LOCKED => Error::NftLocked,
+            ERROR_NFT_NOT_LOCKED => Error::NftNotLocked,
+            ERROR_INVALID_AMOUNT => Error::InvalidAmount,
+            ERROR_LENGTH_MISMATCH => Error::LengthMismatch,
+            ERROR_UNKNOWN_OFFER => Error::UnknownOffer,
+            ERROR_OFFER_EXPIRED => Error::OfferExpired,
+            ERROR_UNKNOWN_SNAPSHOT => Error::UnknownSnapshot,
+            ERROR_BALANCE_RENTED => Error::BalanceRented,
+            ERROR_NOT_RENTED => Error::NotRented,
+            ERROR_TOKEN_EXPIRED => Error::TokenExpired,
+            ERROR_TOKEN_NOT_EXPIRED => Error::TokenNotExpired,
+            ERROR_ALREADY_MINTED => Error::AlreadyMinted,
+            ERROR_UNKNOWN_TOKEN_TYPE => Error::UnknownTokenType,
+            ERROR_NON_FUNGIBLE_AMOUNT => Error::NonFungibleAmount,
+            ERROR_UNKNOWN_PROPOSAL => Error::UnknownProposal,
+            ERROR_PROPOSAL_EXPIRED => Error::ProposalExpired,
+            ERROR_INSUFFICIENT_APPROVALS => Error::InsufficientApprovals,
+            ERROR_INVALID_THRESHOLD => Error::InvalidThreshold,
+            ERROR_PAUSED => Error::Paused,
+            ERROR_UNKNOWN_OPERATION => Error::UnknownOperation,
+            ERROR_OPERATION_NOT_READY => Error::OperationNotReady,
+            ERROR_DELAY_TOO_SHORT => Error::DelayTooShort,
+            ERROR_NO_PENDING_OWNER => Error::NoPendingOwner,
+            ERROR_RESERVED_TOKEN_ID => Error::ReservedTokenId,
+            ERROR_INVALID_TOKEN_ID => Error::InvalidTokenId,
+            ERROR_SUPPLY_CAP_EXCEEDED => Error::SupplyCapExceeded,
+            ERROR_INVALID_SALE => Error::InvalidSale,
+            ERROR_EMPTY_OFFER => Error::EmptyOffer,
+            _ if user_error >= LIBRARY_ERRORS_START => return Err(user_error),
+            _ if user_error >= EXTENSION_ERRORS_START => {
+                let offset = LIBRARY_ERRORS_START - 1 - user_error;
+                let range = ErrorRange::ALL[usize::from(offset / ERROR_RANGE_LEN)];
+                range.error((user_error - range.start()) as u8)
+            }
+            _ => Error::User(user_error),
+        };
+        Ok(error)
+    }
+}
+
+impl TryFrom<ApiError> for Error {
+    type Error = ApiError;
+
+    /// Decodes an [`ApiError::User`], or returns back any other [`ApiError`] and the codes
+    /// reserved for the library but not assigned to any variant.
+    fn try_from(api_error: ApiError) -> Result<Self, Self::Error> {
+        match api_error {
+            ApiError::User(user_error) => Error::try_from(user_error).map_err(ApiError::User),
+            _ => Err(api_error),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            Error::InvalidContext => "contract called from within an invalid context",
+            Error::InsufficientBalance => "insufficient balance",
+            Error::InsufficientAllowance => "insufficient allowance",
+            Error::Overflow => "operation would overflow",
+            Error::PermissionDenied => "caller is not allowed to perform the operation",
+            Error::UnknownCampaign => "claim campaign has no registered Merkle root",
+            Error::InvalidProof => "Merkle proof does not match the campaign root",
+            Error::AlreadyClaimed => "already claimed",
+            Error::SaleNotActive => "token is not on sale",
+            Error::InsufficientPayment => "insufficient payment",
+            Error::SoldOut => "not enough tokens left on sale",
+            Error::PurchaseLimitExceeded => "purchase limit exceeded",
+            Error::UnknownWrappedToken => "token does not wrap any ERC20 token",
+            Error::NftLocked => "NFT is already locked",
+            Error::NftNotLocked => "token does not hold the fractions of a locked NFT",
+            Error::InvalidAmount => "invalid amount",
+            Error::LengthMismatch => "lists have different lengths",
+            Error::UnknownOffer => "offer does not exist or is closed",
+            Error::OfferExpired => "offer expired",
+            Error::UnknownSnapshot => "snapshot was not taken yet",
+            Error::BalanceRented => "balance is rented out",
+            Error::NotRented => "balance is not rented out to the caller",
+            Error::TokenExpired => "token expired",
+            Error::TokenNotExpired => "token did not expire yet",
+            Error::AlreadyMinted => "token was already minted",
+            Error::UnknownTokenType => "token type was not created",
+            Error::NonFungibleAmount => "amount is invalid for a non-fungible token",
+            Error::UnknownProposal => "proposal does not exist or was executed",
+            Error::ProposalExpired => "proposal expired",
+            Error::InsufficientApprovals => "proposal was not approved by enough signers",
+            Error::InvalidThreshold => "invalid threshold",
+            Error::Paused => "token transfers are paused",
+            Error::UnknownOperation => "operation is not queued",
+            Error::OperationNotReady => "operation is not ready",
+            Error::DelayTooShort => "delay is shorter than the minimum delay",
+            Error::NoPendingOwner => "no ownership transfer is pending",
+            Error::ReservedTokenId => "token id is reserved",
+            Error::InvalidTokenId => "invalid token id",
+            Error::SupplyCapExceeded => "supply cap exceeded",
+            Error::InvalidSale => "invalid sale terms",
+            Error::EmptyOffer => "empty offer bundle",
+            Error::Extension(ExtensionError { range, code }) => {
+                return write!(f, "extension error {} of range {}", code, range.index());
+            }
+            Error::User(user_error) => return write!(f, "user error {}", user_error),
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,115 @@
+//! Implementation of escrowed offers.
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{OFFERS_COUNT_KEY_NAME, OFFERS_KEY_NAME},
+    detail, Address,
+};
+
+/// An offer to swap tokens locked by a maker for tokens of a taker.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Offer {
+    /// Address which created the offer and whose tokens are locked.
+    pub maker: Address,
+    /// Address allowed to accept the offer, or `None` if anyone can.
+    pub taker: Option<Address>,
+    /// Token ids locked by the maker.
+    pub offered_ids: Vec<String>,
+    /// Amounts of the token ids locked by the maker.
+    pub offered_amounts: Vec<U256>,
+    /// Token ids asked to the taker.
+    pub wanted_ids: Vec<String>,
+    /// Amounts of the token ids asked to the taker.
+    pub wanted_amounts: Vec<U256>,
+    /// Blocktime from which the offer can no longer be accepted.
+    pub expiry: u64,
+    /// Whether the offer can still be accepted or cancelled.
+    pub open: bool,
+}
+
+impl CLTyped for Offer {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Offer {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.maker.to_bytes()?);
+        result.append(&mut self.taker.to_bytes()?);
+        result.append(&mut self.offered_ids.to_bytes()?);
+        result.append(&mut self.offered_amounts.to_bytes()?);
+        result.append(&mut self.wanted_ids.to_bytes()?);
+        result.append(&mut self.wanted_amounts.to_bytes()?);
+        result.append(&mut self.expiry.to_bytes()?);
+        result.append(&mut self.open.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.maker.serialized_length()
+            + self.taker.serialized_length()
+            + self.offered_ids.serialized_length()
+            + self.offered_amounts.serialized_length()
+            + self.wanted_ids.serialized_length()
+            + self.wanted_amounts.serialized_length()
+            + self.expiry.serialized_length()
+            + self.open.serialized_length()
+    }
+}
+
+impl FromBytes for Offer {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (maker, remainder) = Address::from_bytes(bytes)?;
+        let (taker, remainder) = Option::<Address>::from_bytes(remainder)?;
+        let (offered_ids, remainder) = Vec::<String>::from_bytes(remainder)?;
+        let (offered_amounts, remainder) = Vec::<U256>::from_bytes(remainder)?;
+        let (wanted_ids, remainder) = Vec::<String>::from_bytes(remainder)?;
+        let (wanted_amounts, remainder) = Vec::<U256>::from_bytes(remainder)?;
+        let (expiry, remainder) = u64::from_bytes(remainder)?;
+        let (open, remainder) = bool::from_bytes(remainder)?;
+        let offer = Offer {
+            maker,
+            taker,
+            offered_ids,
+            offered_amounts,
+            wanted_ids,
+            wanted_amounts,
+            expiry,
+            open,
+        };
+        Ok((offer, remainder))
+    }
+}
+
+/// Get Offers uref of contract context.
+pub(crate) fn offers_uref() -> URef {
+    detail::get_uref(OFFERS_KEY_NAME)
+}
+
+/// Reads an offer from a dictionary.
+pub(crate) fn read_offer_from(offers_uref: URef, offer_id: u64) -> Option<Offer> {
+    storage::dictionary_get(offers_uref, &offer_id.to_string()).unwrap_or_revert()
+}
+
+/// Writes an offer into a dictionary.
+pub(crate) fn write_offer_to(offers_uref: URef, offer_id: u64, offer: Offer) {
+    storage::dictionary_put(offers_uref, &offer_id.to_string(), offer);
+}
+
+/// Reads the amount of offers created so far.
+pub(crate) fn read_offers_count() -> u64 {
+    detail::read_from(OFFERS_COUNT_KEY_NAME)
+}
+
+/// Writes the amount of offers created so far.
+pub(crate) fn write_offers_count(offers_count: u64) {
+    storage::write(detail::get_uref(OFFERS_COUNT_KEY_NAME), offers_count);
+}
@@ -0,0 +1,227 @@
+//! Implementation of contract events.
+//!
+//! Every event is written into a new [`URef`](casper_types::URef) as a map of strings holding its
+//! `event_type` and fields, so off-chain consumers can find it in the transforms of the deploy
+//! which emitted it.
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use casper_contract::contract_api::storage;
+use casper_types::U256;
+
+use crate::Address;
+
+/// Name of the event map entry holding the type of the event.
+pub const EVENT_TYPE: &str = "event_type";
+
+/// Events emitted by the contract.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Event {
+    /// Tokens of a single id were transferred.
+    TransferSingle {
+        /// Address which performed the transfer.
+        operator: Address,
+        /// Owner of the transferred tokens.
+        from: Address,
+        /// Recipient of the transferred tokens.
+        to: Address,
+        /// Transferred token id.
+        id: String,
+        /// Amount of transferred tokens.
+        amount: U256,
+    },
+    /// Tokens of several ids were transferred to the same recipient.
+    ///
+    /// Its map holds the amount of transferred ids under `count`, and every id and amount under
+    /// `id_{index}` and `amount_{index}`.
+    TransferBatch {
+        /// Address which performed the transfer.
+        operator: Address,
+        /// Owner of the transferred tokens.
+        from: Address,
+        /// Recipient of the transferred tokens.
+        to: Address,
+        /// Transferred token ids.
+        ids: Vec<String>,
+        /// Amounts of transferred tokens, for each id.
+        amounts: Vec<U256>,
+    },
+    /// A multisig proposal was created.
+    ProposalCreated {
+        /// Id of the new proposal.
+        proposal_id: u64,
+        /// Signer which created the proposal.
+        proposer: Address,
+    },
+    /// A multisig proposal was approved by a signer.
+    ProposalApproved {
+        /// Id of the approved proposal.
+        proposal_id: u64,
+        /// Signer which approved the proposal.
+        signer: Address,
+    },
+    /// A multisig proposal was executed.
+    ProposalExecuted {
+        /// Id of the executed proposal.
+        proposal_id: u64,
+    },
+    /// A timelocked operation was queued.
+    OperationQueued {
+        /// Id of the queued operation.
+        operation_id: u64,
+        /// Blocktime from which the operation can be executed.
+        eta: u64,
+    },
+    /// A timelocked operation was cancelled.
+    OperationCancelled {
+        /// Id of the cancelled operation.
+        operation_id: u64,
+    },
+    /// A timelocked operation was executed.
+    OperationExecuted {
+        /// Id of the executed operation.
+        operation_id: u64,
+    },
+    /// The owner started to transfer the ownership of the contract.
+    OwnershipTransferStarted {
+        /// Current owner of the contract.
+        owner: Address,
+        /// Address which can accept the ownership.
+        pending_owner: Address,
+    },
+    /// The pending owner accepted the ownership of the contract.
+    OwnershipTransferred {
+        /// Previous owner of the contract.
+        previous_owner: Address,
+        /// New owner of the contract.
+        new_owner: Address,
+    },
+    /// The owner cancelled a pending transfer of the ownership of the contract.
+    OwnershipTransferCancelled {
+        /// Current owner of the contract.
+        owner: Address,
+        /// Address which could have accepted the ownership.
+        pending_owner: Address,
+    },
+    /// The owner renounced the ownership of the contract.
+    OwnershipRenounced {
+        /// Previous owner of the contract.
+        previous_owner: Address,
+    },
+}
+
+impl Event {
+    /// Returns the event as the map of strings written to the global state.
+    pub fn to_map(&self) -> BTreeMap<String, String> {
+        let mut map = BTreeMap::new();
+        let mut insert = |name: &str, value: String| {
+            map.insert(name.to_string(), value);
+        };
+        match self {
+            Event::TransferSingle {
+                operator,
+                from,
+                to,
+                id,
+                amount,
+            } => {
+                insert(EVENT_TYPE, "transfer_single".to_string());
+                insert("operator", address_to_string(*operator));
+                insert("from", address_to_string(*from));
+                insert("to", address_to_string(*to));
+                insert("id", id.clone());
+                insert("amount", amount.to_string());
+            }
+            Event::TransferBatch {
+                operator,
+                from,
+                to,
+                ids,
+                amounts,
+            } => {
+                insert(EVENT_TYPE, "transfer_batch".to_string());
+                insert("operator", address_to_string(*operator));
+                insert("from", address_to_string(*from));
+                insert("to", address_to_string(*to));
+                insert("count", ids.len().to_string());
+                for (index, (id, amount)) in ids.iter().zip(amounts).enumerate() {
+                    insert(&format!("id_{}", index), id.clone());
+                    insert(&format!("amount_{}", index), amount.to_string());
+                }
+            }
+            Event::ProposalCreated {
+                proposal_id,
+                proposer,
+            } => {
+                insert(EVENT_TYPE, "proposal_created".to_string());
+                insert("proposal_id", proposal_id.to_string());
+                insert("proposer", address_to_string(*proposer));
+            }
+            Event::ProposalApproved {
+                proposal_id,
+                signer,
+            } => {
+                insert(EVENT_TYPE, "proposal_approved".to_string());
+                insert("proposal_id", proposal_id.to_string());
+                insert("signer", address_to_string(*signer));
+            }
+            Event::ProposalExecuted { proposal_id } => {
+                insert(EVENT_TYPE, "proposal_executed".to_string());
+                insert("proposal_id", proposal_id.to_string());
+            }
+            Event::OperationQueued { operation_id, eta } => {
+                insert(EVENT_TYPE, "operation_queued".to_string());
+                insert("operation_id", operation_id.to_string());
+                insert("eta", eta.to_string());
+            }
+            Event::OperationCancelled { operation_id } => {
+                insert(EVENT_TYPE, "operation_cancelled".to_string());
+                insert("operation_id", operation_id.to_string());
+            }
+            Event::OperationExecuted { operation_id } => {
+                insert(EVENT_TYPE, "operation_executed".to_string());
+                insert("operation_id", operation_id.to_string());
+            }
+            Event::OwnershipTransferStarted {
+                owner,
+                pending_owner,
+            } => {
+                insert(EVENT_TYPE, "ownership_transfer_started".to_string());
+                insert("owner", address_to_string(*owner));
+                insert("pending_owner", address_to_string(*pending_owner));
+            }
+            Event::OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            } => {
+                insert(EVENT_TYPE, "ownership_transferred".to_string());
+                insert("previous_owner", address_to_string(*previous_owner));
+                insert("new_owner", address_to_string(*new_owner));
+            }
+            Event::OwnershipTransferCancelled {
+                owner,
+                pending_owner,
+            } => {
+                insert(EVENT_TYPE, "ownership_transfer_cancelled".to_string());
+                insert("owner", address_to_string(*owner));
+                insert("pending_owner", address_to_string(*pending_owner));
+            }
+            Event::OwnershipRenounced { previous_owner } => {
+                insert(EVENT_TYPE, "ownership_renounced".to_string());
+                insert("previous_owner", address_to_string(*previous_owner));
+            }
+        }
+        map
+    }
+}
+
+/// Formats an address the same way as the [`Key`](casper_types::Key) it converts to.
+fn address_to_string(address: Address) -> String {
+    address.to_string()
+}
+
+/// Writes `event` into the global state.
+pub(crate) fn emit(event: &Event) {
+    storage::new_uref(event.to_map());
+}
@@ -0,0 +1,33 @@
+//! Implementation of token ids expiring at a given blocktime.
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, URef};
+
+use crate::{constants::EXPIRIES_KEY_NAME, detail};
+
+/// Creates a dictionary item key for the expiry of a token id.
+#[inline]
+fn make_dictionary_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Get expiries uref of contract context, if the extension is installed.
+pub(crate) fn expiries_uref() -> Option<URef> {
+    detail::get_optional_uref(EXPIRIES_KEY_NAME)
+}
+
+/// Reads the expiry of a token id, if it has one.
+pub(crate) fn read_expiry_from(expiries_uref: URef, id: &str) -> Option<u64> {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_get(expiries_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the expiry of a token id.
+pub(crate) fn write_expiry_to(expiries_uref: URef, id: &str, expiry: u64) {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_put(expiries_uref, &dictionary_item_key, expiry);
+}
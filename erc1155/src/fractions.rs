@@ -0,0 +1,169 @@
+//! Implementation of NFT fractionalization.
+//!
+//! Only NFTs following the CEP-47 interface are supported, where tokens are identified by a `U256`
+//! and moved with the `transfer` and `transfer_from` entry points taking a list of `token_ids`.
+//! CEP-78 contracts identify tokens by `token_id` or `token_hash` and move them with different
+//! runtime arguments, so they can't be fractionalized.
+use alloc::{string::String, vec, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    runtime_args, ContractHash, RuntimeArgs, URef, U256,
+};
+
+use crate::{
+    constants::{FRACTIONS_KEY_NAME, NFT_LOCKS_KEY_NAME},
+    detail, Address,
+};
+
+/// Prefix of the token ids which hold the fractions of an NFT.
+///
+/// Like the prefix of wrapped ids, it starts with the `#` separator of non-fungible item ids, which
+/// ids created through [`crate::ERC1155::create`] can't contain.
+const FRACTION_TOKEN_ID_PREFIX: &str = "#nft-";
+/// Name of the NFT `transfer` entry point.
+const NFT_TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+/// Name of the NFT `transfer_from` entry point.
+const NFT_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+/// Name of the NFT `sender` runtime argument.
+const NFT_SENDER_RUNTIME_ARG_NAME: &str = "sender";
+/// Name of the NFT `recipient` runtime argument.
+const NFT_RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
+/// Name of the NFT `token_ids` runtime argument.
+const NFT_TOKEN_IDS_RUNTIME_ARG_NAME: &str = "token_ids";
+
+/// Serializes the NFT identified by `token_id` of `nft_contract`.
+fn nft_preimage(nft_contract: ContractHash, token_id: U256) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut preimage = Vec::new();
+    preimage.append(&mut nft_contract.to_bytes()?);
+    preimage.append(&mut token_id.to_bytes()?);
+    Ok(preimage)
+}
+
+/// Returns the token id of the fractions of `token_id` of `nft_contract`.
+///
+/// On-chain `hash` is `runtime::blake2b`, and off-chain `merkle::blake2b` computes the
+/// same hash.
+pub fn fraction_token_id<H>(
+    nft_contract: ContractHash,
+    token_id: U256,
+    hash: H,
+) -> Result<String, bytesrepr::Error>
+where
+    H: Fn(&[u8]) -> [u8; 32],
+{
+    let key_bytes = hash(&nft_preimage(nft_contract, token_id)?);
+    let mut id = String::from(FRACTION_TOKEN_ID_PREFIX);
+    id.push_str(&base64::encode_config(&key_bytes, base64::URL_SAFE_NO_PAD));
+    Ok(id)
+}
+
+/// Returns true if `id` is within the namespace of the ids returned by [`fraction_token_id`], which
+/// only [`crate::ERC1155::fractionalize`] can mint and [`crate::ERC1155::redeem`] can burn.
+pub(crate) fn is_fraction_token_id(id: &str) -> bool {
+    id.starts_with(FRACTION_TOKEN_ID_PREFIX)
+}
+
+/// Creates a dictionary item key for a fraction token id.
+#[inline]
+fn make_fraction_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Creates a dictionary item key for the lock of an NFT.
+#[inline]
+fn make_lock_item_key(nft_contract: ContractHash, token_id: U256) -> String {
+    let preimage = nft_preimage(nft_contract, token_id).unwrap_or_revert();
+    let key_bytes = runtime::blake2b(&preimage);
+    base64::encode(&key_bytes)
+}
+
+/// Get Fractions uref of contract context.
+pub(crate) fn fractions_uref() -> URef {
+    detail::get_uref(FRACTIONS_KEY_NAME)
+}
+
+/// Get NFT locks uref of contract context.
+pub(crate) fn nft_locks_uref() -> URef {
+    detail::get_uref(NFT_LOCKS_KEY_NAME)
+}
+
+/// Reads the NFT fractionalized into a token id.
+pub(crate) fn read_fractionalized_nft_from(
+    fractions_uref: URef,
+    id: &str,
+) -> Option<(ContractHash, U256)> {
+    let dictionary_item_key = make_fraction_item_key(id);
+    storage::dictionary_get(fractions_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the NFT fractionalized into a token id.
+pub(crate) fn write_fractionalized_nft_to(
+    fractions_uref: URef,
+    id: &str,
+    nft_contract: ContractHash,
+    token_id: U256,
+) {
+    let dictionary_item_key = make_fraction_item_key(id);
+    storage::dictionary_put(
+        fractions_uref,
+        &dictionary_item_key,
+        (nft_contract, token_id),
+    );
+}
+
+/// Reads whether an NFT is locked in the contract.
+pub(crate) fn read_nft_lock_from(
+    nft_locks_uref: URef,
+    nft_contract: ContractHash,
+    token_id: U256,
+) -> bool {
+    let dictionary_item_key = make_lock_item_key(nft_contract, token_id);
+    storage::dictionary_get(nft_locks_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes whether an NFT is locked in the contract.
+pub(crate) fn write_nft_lock_to(
+    nft_locks_uref: URef,
+    nft_contract: ContractHash,
+    token_id: U256,
+    locked: bool,
+) {
+    let dictionary_item_key = make_lock_item_key(nft_contract, token_id);
+    storage::dictionary_put(nft_locks_uref, &dictionary_item_key, locked);
+}
+
+/// Moves an NFT from `sender` to `recipient`, using the approval given by `sender` to the calling
+/// contract.
+pub(crate) fn transfer_nft_from(
+    nft_contract: ContractHash,
+    sender: Address,
+    recipient: Address,
+    token_id: U256,
+) {
+    let transfer_from_args = runtime_args! {
+        NFT_SENDER_RUNTIME_ARG_NAME => sender,
+        NFT_RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        NFT_TOKEN_IDS_RUNTIME_ARG_NAME => vec![token_id],
+    };
+    runtime::call_contract::<()>(
+        nft_contract,
+        NFT_TRANSFER_FROM_ENTRY_POINT_NAME,
+        transfer_from_args,
+    );
+}
+
+/// Moves an NFT held by the calling contract to `recipient`.
+pub(crate) fn transfer_nft(nft_contract: ContractHash, recipient: Address, token_id: U256) {
+    let transfer_args = runtime_args! {
+        NFT_RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        NFT_TOKEN_IDS_RUNTIME_ARG_NAME => vec![token_id],
+    };
+    runtime::call_contract::<()>(nft_contract, NFT_TRANSFER_ENTRY_POINT_NAME, transfer_args);
+}
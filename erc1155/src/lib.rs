@@ -14,32 +14,87 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod address;
 mod balances;
+mod batch;
+mod claims;
+mod config;
 pub mod constants;
 mod detail;
 pub mod entry_points;
 mod error;
+mod escrow;
+pub mod events;
+mod expiries;
+mod fractions;
+pub mod merkle;
+mod multisig;
 mod operators;
+mod owner;
+mod pause;
+mod registry;
+mod rentals;
+mod sales;
+#[cfg(feature = "std")]
+pub mod schema;
+mod snapshots;
+mod timelock;
 mod total_supply;
+mod wrapper;
 
+use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use once_cell::unsync::OnceCell;
 
 use casper_contract::{
-    contract_api::{runtime, storage},
+    contract_api::{runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{contracts::NamedKeys, EntryPoints, Key, URef, U256};
+use casper_types::{
+    contracts::NamedKeys, ContractHash, EntryPoint, EntryPoints, Key, URef, U256, U512,
+};
 
-pub use address::Address;
+pub use address::{Address, AddressFromStrError};
+pub use config::{CallerPolicy, Config};
 use constants::{
-    BALANCES_KEY_NAME, ERC1155_TOKEN_CONTRACT_KEY_NAME, OPERATORS_KEY_NAME, TOTAL_SUPPLY_KEY_NAME,
-    URI_KEY_NAME,
+    ACCEPT_OFFER_ENTRY_POINT_NAME, APPROVE_ENTRY_POINT_NAME, BALANCES_KEY_NAME,
+    BALANCE_OF_AT_ENTRY_POINT_NAME, BALANCE_SNAPSHOTS_KEY_NAME, CALLER_POLICY_KEY_NAME,
+    CANCEL_OFFER_ENTRY_POINT_NAME, CANCEL_OPERATION_ENTRY_POINT_NAME, CLAIMED_KEY_NAME,
+    CLAIM_ENTRY_POINT_NAME, CLAIM_ROOTS_KEY_NAME, CREATE_ENTRY_POINT_NAME,
+    CREATE_OFFER_ENTRY_POINT_NAME, CREATOR_OF_ENTRY_POINT_NAME, ERC1155_TOKEN_CONTRACT_KEY_NAME,
+    EXECUTE_ENTRY_POINT_NAME, EXECUTE_OPERATION_ENTRY_POINT_NAME, EXISTS_ENTRY_POINT_NAME,
+    EXPIRIES_KEY_NAME, EXPIRY_OF_ENTRY_POINT_NAME, FRACTIONALIZE_ENTRY_POINT_NAME,
+    FRACTIONS_KEY_NAME, LAST_TOKEN_ID_KEY_NAME, MINTERS_KEY_NAME,
+    MINT_WITH_EXPIRY_ENTRY_POINT_NAME, MIN_DELAY_ENTRY_POINT_NAME, MIN_DELAY_KEY_NAME,
+    NFT_LOCKS_KEY_NAME, NFT_OWNERS_KEY_NAME, OFFERS_COUNT_KEY_NAME, OFFERS_KEY_NAME,
+    OPERATIONS_COUNT_KEY_NAME, OPERATIONS_KEY_NAME, OPERATORS_KEY_NAME, OWNER_KEY_NAME,
+    OWNER_OF_ENTRY_POINT_NAME, PAUSED_KEY_NAME, PENDING_OWNER_KEY_NAME, PROPOSALS_COUNT_KEY_NAME,
+    PROPOSALS_KEY_NAME, PROPOSE_ENTRY_POINT_NAME, PURCHASES_KEY_NAME, PURCHASE_ENTRY_POINT_NAME,
+    QUEUE_OPERATION_ENTRY_POINT_NAME, REDEEM_ENTRY_POINT_NAME, RENTALS_KEY_NAME,
+    RENTED_FROM_KEY_NAME, REVOKE_USER_ENTRY_POINT_NAME, SALES_KEY_NAME,
+    SET_CLAIM_ROOT_ENTRY_POINT_NAME, SET_MINTER_ENTRY_POINT_NAME, SET_SALE_ENTRY_POINT_NAME,
+    SET_USER_ENTRY_POINT_NAME, SIGNERS_KEY_NAME, SNAPSHOT_ENTRY_POINT_NAME, SNAPSHOT_ID_KEY_NAME,
+    SUPPLY_CAPS_KEY_NAME, SUPPLY_CAP_ENTRY_POINT_NAME, SWEEP_EXPIRED_ENTRY_POINT_NAME,
+    THRESHOLD_KEY_NAME, TOKEN_TYPES_KEY_NAME, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+    TOTAL_SUPPLY_KEY_NAME, TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME, TREASURY_KEY_NAME,
+    UNWRAP_ENTRY_POINT_NAME, URI_KEY_NAME, USABLE_BALANCE_OF_ENTRY_POINT_NAME,
+    USER_OF_ENTRY_POINT_NAME, WITHDRAW_ENTRY_POINT_NAME, WRAPPED_TOKENS_KEY_NAME,
+    WRAP_ENTRY_POINT_NAME,
 };
-pub use error::Error;
+pub use error::{Error, ErrorRange, ExtensionError};
+pub use escrow::Offer;
+use events::Event;
+pub use fractions::fraction_token_id;
+pub use multisig::{Action, Proposal};
+pub use registry::{base_token_id, nft_token_id, TokenType};
+pub use rentals::Rental;
+pub use sales::Sale;
+pub use timelock::{Operation, QueuedOperation};
+pub use wrapper::wrapped_token_id;
 
 /// Implementation of ERC1155 standard functionality.
 #[derive(Default)]
@@ -47,14 +102,45 @@ pub struct ERC1155 {
     balances_uref: OnceCell<URef>,
     operators_uref: OnceCell<URef>,
     total_supply_uref: OnceCell<URef>,
+    claim_roots_uref: OnceCell<URef>,
+    claimed_uref: OnceCell<URef>,
+    sales_uref: OnceCell<URef>,
+    purchases_uref: OnceCell<URef>,
+    wrapped_tokens_uref: OnceCell<URef>,
+    fractions_uref: OnceCell<URef>,
+    nft_locks_uref: OnceCell<URef>,
+    offers_uref: OnceCell<URef>,
+    snapshot_id_uref: OnceCell<Option<URef>>,
+    balance_snapshots_uref: OnceCell<URef>,
+    total_supply_snapshots_uref: OnceCell<URef>,
+    rentals_uref: OnceCell<Option<URef>>,
+    rented_from_uref: OnceCell<URef>,
+    expiries_uref: OnceCell<Option<URef>>,
+    token_types_uref: OnceCell<Option<URef>>,
+    minters_uref: OnceCell<URef>,
+    nft_owners_uref: OnceCell<URef>,
+    proposals_uref: OnceCell<URef>,
+    operations_uref: OnceCell<URef>,
+    supply_caps_uref: OnceCell<Option<URef>>,
 }
 
 impl ERC1155 {
-    fn new(balances_uref: URef, operators_uref: URef, total_supply_uref: URef) -> Self {
+    fn new(
+        balances_uref: URef,
+        operators_uref: URef,
+        total_supply_uref: URef,
+        snapshot_id_uref: Option<URef>,
+        token_types_uref: Option<URef>,
+        nft_owners_uref: Option<URef>,
+    ) -> Self {
         Self {
             balances_uref: balances_uref.into(),
             operators_uref: operators_uref.into(),
             total_supply_uref: total_supply_uref.into(),
+            snapshot_id_uref: snapshot_id_uref.into(),
+            token_types_uref: token_types_uref.into(),
+            nft_owners_uref: nft_owners_uref.map_or_else(OnceCell::new, OnceCell::from),
+            ..Default::default()
         }
     }
 
@@ -72,11 +158,113 @@ impl ERC1155 {
             .get_or_init(total_supply::total_supply_uref)
     }
 
+    fn claim_roots_uref(&self) -> URef {
+        *self.claim_roots_uref.get_or_init(claims::claim_roots_uref)
+    }
+
+    fn claimed_uref(&self) -> URef {
+        *self.claimed_uref.get_or_init(claims::claimed_uref)
+    }
+
+    fn sales_uref(&self) -> URef {
+        *self.sales_uref.get_or_init(sales::sales_uref)
+    }
+
+    fn purchases_uref(&self) -> URef {
+        *self.purchases_uref.get_or_init(sales::purchases_uref)
+    }
+
+    fn wrapped_tokens_uref(&self) -> URef {
+        *self
+            .wrapped_tokens_uref
+            .get_or_init(wrapper::wrapped_tokens_uref)
+    }
+
+    fn fractions_uref(&self) -> URef {
+        *self.fractions_uref.get_or_init(fractions::fractions_uref)
+    }
+
+    fn nft_locks_uref(&self) -> URef {
+        *self.nft_locks_uref.get_or_init(fractions::nft_locks_uref)
+    }
+
+    fn offers_uref(&self) -> URef {
+        *self.offers_uref.get_or_init(escrow::offers_uref)
+    }
+
+    fn snapshot_id_uref(&self) -> Option<URef> {
+        *self
+            .snapshot_id_uref
+            .get_or_init(snapshots::snapshot_id_uref)
+    }
+
+    fn balance_snapshots_uref(&self) -> URef {
+        *self
+            .balance_snapshots_uref
+            .get_or_init(snapshots::balance_snapshots_uref)
+    }
+
+    fn total_supply_snapshots_uref(&self) -> URef {
+        *self
+            .total_supply_snapshots_uref
+            .get_or_init(snapshots::total_supply_snapshots_uref)
+    }
+
+    fn rentals_uref(&self) -> Option<URef> {
+        *self.rentals_uref.get_or_init(rentals::rentals_uref)
+    }
+
+    fn rented_from_uref(&self) -> URef {
+        *self.rented_from_uref.get_or_init(rentals::rented_from_uref)
+    }
+
+    fn expiries_uref(&self) -> Option<URef> {
+        *self.expiries_uref.get_or_init(expiries::expiries_uref)
+    }
+
+    fn token_types_uref(&self) -> Option<URef> {
+        *self
+            .token_types_uref
+            .get_or_init(registry::token_types_uref)
+    }
+
+    fn minters_uref(&self) -> URef {
+        *self.minters_uref.get_or_init(registry::minters_uref)
+    }
+
+    fn nft_owners_uref(&self) -> URef {
+        *self.nft_owners_uref.get_or_init(registry::nft_owners_uref)
+    }
+
+    fn proposals_uref(&self) -> URef {
+        *self.proposals_uref.get_or_init(multisig::proposals_uref)
+    }
+
+    fn operations_uref(&self) -> URef {
+        *self.operations_uref.get_or_init(timelock::operations_uref)
+    }
+
+    fn supply_caps_uref(&self) -> Option<URef> {
+        *self
+            .supply_caps_uref
+            .get_or_init(timelock::supply_caps_uref)
+    }
+
     fn read_total_supply(&self, id: &str) -> U256 {
         total_supply::read_total_supply_from(self.total_supply_uref(), &id)
     }
 
     fn write_total_supply(&self, id: &str, amount: U256) {
+        let snapshot_id = self.read_snapshot_id();
+        if snapshot_id > 0 {
+            let total_supply = self.read_total_supply(id);
+            snapshots::record_total_supply_to(
+                self.total_supply_snapshots_uref(),
+                id,
+                snapshot_id,
+                total_supply,
+            );
+        }
         total_supply::write_total_supply_to(self.total_supply_uref(), &id, amount)
     }
 
@@ -85,6 +273,20 @@ impl ERC1155 {
     }
 
     fn write_balance(&mut self, to: Address, token_id: &str, amount: U256) {
+        let snapshot_id = self.read_snapshot_id();
+        if snapshot_id > 0 {
+            let balance = self.read_balance(to, token_id);
+            snapshots::record_balance_to(
+                self.balance_snapshots_uref(),
+                to,
+                token_id,
+                snapshot_id,
+                balance,
+            );
+        }
+        if amount == U256::one() && self.is_non_fungible(token_id) {
+            self.write_nft_owner(token_id, to);
+        }
         balances::write_balance_to(self.balances_uref(), to, &token_id, amount)
     }
 
@@ -96,6 +298,445 @@ impl ERC1155 {
         operators::write_operator_to(self.operators_uref(), owner, spender, approved)
     }
 
+    fn read_claim_root(&self, campaign: &str) -> Option<([u8; 32], Address)> {
+        claims::read_claim_root_from(self.claim_roots_uref(), campaign)
+    }
+
+    fn write_claim_root(&mut self, campaign: &str, root: [u8; 32], registrar: Address) {
+        claims::write_claim_root_to(self.claim_roots_uref(), campaign, root, registrar)
+    }
+
+    fn read_claimed(&self, campaign: &str, leaf: [u8; 32]) -> bool {
+        claims::read_claimed_from(self.claimed_uref(), campaign, leaf)
+    }
+
+    fn write_claimed(&mut self, campaign: &str, leaf: [u8; 32]) {
+        claims::write_claimed_to(self.claimed_uref(), campaign, leaf)
+    }
+
+    fn read_sale(&self, id: &str) -> Option<Sale> {
+        sales::read_sale_from(self.sales_uref(), id)
+    }
+
+    fn write_sale(&mut self, id: &str, sale: Sale) {
+        sales::write_sale_to(self.sales_uref(), id, sale)
+    }
+
+    fn read_purchased(&self, account: Address, id: &str) -> U256 {
+        sales::read_purchased_from(self.purchases_uref(), account, id)
+    }
+
+    fn write_purchased(&mut self, account: Address, id: &str, amount: U256) {
+        sales::write_purchased_to(self.purchases_uref(), account, id, amount)
+    }
+
+    fn read_wrapped_token(&self, id: &str) -> Option<ContractHash> {
+        wrapper::read_wrapped_token_from(self.wrapped_tokens_uref(), id)
+    }
+
+    fn write_wrapped_token(&mut self, id: &str, erc20_contract: ContractHash) {
+        wrapper::write_wrapped_token_to(self.wrapped_tokens_uref(), id, erc20_contract)
+    }
+
+    fn read_fractionalized_nft(&self, id: &str) -> Option<(ContractHash, U256)> {
+        fractions::read_fractionalized_nft_from(self.fractions_uref(), id)
+    }
+
+    fn write_fractionalized_nft(&mut self, id: &str, nft_contract: ContractHash, token_id: U256) {
+        fractions::write_fractionalized_nft_to(self.fractions_uref(), id, nft_contract, token_id)
+    }
+
+    fn read_nft_lock(&self, nft_contract: ContractHash, token_id: U256) -> bool {
+        fractions::read_nft_lock_from(self.nft_locks_uref(), nft_contract, token_id)
+    }
+
+    fn write_nft_lock(&mut self, nft_contract: ContractHash, token_id: U256, locked: bool) {
+        fractions::write_nft_lock_to(self.nft_locks_uref(), nft_contract, token_id, locked)
+    }
+
+    fn read_offer(&self, offer_id: u64) -> Option<Offer> {
+        escrow::read_offer_from(self.offers_uref(), offer_id)
+    }
+
+    fn write_offer(&mut self, offer_id: u64, offer: Offer) {
+        escrow::write_offer_to(self.offers_uref(), offer_id, offer)
+    }
+
+    /// Reads the id of the last snapshot, which stays zero without the snapshots extension.
+    fn read_snapshot_id(&self) -> u64 {
+        self.snapshot_id_uref()
+            .map_or(0, snapshots::read_snapshot_id_from)
+    }
+
+    fn write_snapshot_id(&mut self, snapshot_id: u64) {
+        let snapshot_id_uref = detail::unwrap_installed(self.snapshot_id_uref());
+        snapshots::write_snapshot_id_to(snapshot_id_uref, snapshot_id)
+    }
+
+    fn read_rental(&self, owner: Address, id: &str) -> Option<Rental> {
+        self.rentals_uref()
+            .and_then(|rentals_uref| rentals::read_rental_from(rentals_uref, owner, id))
+    }
+
+    fn write_rental(&mut self, owner: Address, id: &str, rental: Rental) {
+        let rentals_uref = detail::unwrap_installed(self.rentals_uref());
+        rentals::write_rental_to(rentals_uref, owner, id, rental)
+    }
+
+    fn read_rented_from(&self, user: Address, id: &str) -> Vec<Address> {
+        rentals::read_rented_from_from(self.rented_from_uref(), user, id)
+    }
+
+    fn write_rented_from(&mut self, user: Address, id: &str, owners: Vec<Address>) {
+        rentals::write_rented_from_to(self.rented_from_uref(), user, id, owners)
+    }
+
+    /// Returns the active rental of `id` granted by `owner`, if any.
+    fn read_active_rental(&self, owner: Address, id: &str) -> Option<Rental> {
+        let blocktime = u64::from(runtime::get_blocktime());
+        self.read_rental(owner, id)
+            .filter(|rental| rental.is_active(blocktime))
+    }
+
+    /// Returns an error if leaving `remaining` tokens of `id` to `owner` would release tokens
+    /// locked by an active rental.
+    fn check_rented(&self, owner: Address, id: &str, remaining: U256) -> Result<(), Error> {
+        match self.read_active_rental(owner, id) {
+            Some(rental) if remaining < rental.amount => Err(Error::BalanceRented),
+            _ => Ok(()),
+        }
+    }
+
+    fn read_expiry(&self, id: &str) -> Option<u64> {
+        self.expiries_uref()
+            .and_then(|expiries_uref| expiries::read_expiry_from(expiries_uref, id))
+    }
+
+    fn write_expiry(&mut self, id: &str, expiry: u64) {
+        let expiries_uref = detail::unwrap_installed(self.expiries_uref());
+        expiries::write_expiry_to(expiries_uref, id, expiry)
+    }
+
+    /// Returns true if `id` has an expiry which the current blocktime reached.
+    fn is_expired(&self, id: &str) -> bool {
+        let blocktime = u64::from(runtime::get_blocktime());
+        self.read_expiry(id)
+            .map_or(false, |expiry| blocktime >= expiry)
+    }
+
+    /// Returns an error if `id` expired.
+    fn check_expired(&self, id: &str) -> Result<(), Error> {
+        if self.is_expired(id) {
+            return Err(Error::TokenExpired);
+        }
+        Ok(())
+    }
+
+    fn read_token_type(&self, id: &str) -> Option<TokenType> {
+        self.token_types_uref()
+            .and_then(|token_types_uref| registry::read_token_type_from(token_types_uref, id))
+    }
+
+    fn write_token_type(&mut self, id: &str, token_type: TokenType) {
+        let token_types_uref = detail::unwrap_installed(self.token_types_uref());
+        registry::write_token_type_to(token_types_uref, id, token_type)
+    }
+
+    fn read_minter(&self, id: &str, minter: Address) -> bool {
+        registry::read_minter_from(self.minters_uref(), id, minter)
+    }
+
+    fn write_minter(&mut self, id: &str, minter: Address, approved: bool) {
+        registry::write_minter_to(self.minters_uref(), id, minter, approved)
+    }
+
+    fn read_nft_owner(&self, id: &str) -> Option<Address> {
+        registry::read_nft_owner_from(self.nft_owners_uref(), id)
+    }
+
+    fn write_nft_owner(&mut self, id: &str, owner: Address) {
+        registry::write_nft_owner_to(self.nft_owners_uref(), id, owner)
+    }
+
+    /// Returns true if the base type of `id` was created as non-fungible.
+    fn is_non_fungible(&self, id: &str) -> bool {
+        self.read_token_type(base_token_id(id))
+            .map_or(false, |token_type| !token_type.fungible)
+    }
+
+    /// Returns an error if moving `amount` tokens of `id` can't be done with a non-fungible id.
+    fn check_non_fungible_amount(&self, id: &str, amount: U256) -> Result<(), Error> {
+        if amount > U256::one() && self.is_non_fungible(id) {
+            return Err(Error::NonFungibleAmount);
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless the base type of `id` was created, by `minter` or with `minter` as
+    /// one of its minters.
+    fn check_creator_or_minter(&self, minter: Address, id: &str) -> Result<(), Error> {
+        let base = base_token_id(id);
+        let token_type = self.read_token_type(base).ok_or(Error::UnknownTokenType)?;
+        if minter != token_type.creator && !self.read_minter(base, minter) {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless `minter` can mint tokens of `id`, see [`ERC1155::only_minter`].
+    fn check_minter(&self, minter: Address, id: &str) -> Result<(), Error> {
+        if self.read_token_type(base_token_id(id)).is_some() {
+            return self.check_creator_or_minter(minter, id);
+        }
+        if minter != detail::get_self_address()? {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Mints `amount` tokens of `id` to `to` on behalf of `minter`, which must be able to mint
+    /// `id`.
+    fn mint_by(
+        &mut self,
+        minter: Address,
+        to: Address,
+        id: &str,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.check_minter(minter, id)?;
+        self.mint(to, id, amount)
+    }
+
+    /// Returns an error if `id` is reserved for the tokens minted by an extension.
+    fn check_reserved(&self, id: &str) -> Result<(), Error> {
+        if wrapper::is_wrapped_token_id(id) || fractions::is_fraction_token_id(id) {
+            return Err(Error::ReservedTokenId);
+        }
+        Ok(())
+    }
+
+    /// Returns true if `id` was neither created nor minted yet.
+    fn is_free(&self, id: &str) -> bool {
+        self.read_token_type(id).is_none() && self.read_total_supply(id).is_zero()
+    }
+
+    /// Assigns the next free numeric id.
+    fn next_token_id(&mut self) -> Result<String, Error> {
+        let mut last_token_id = registry::read_last_token_id();
+        loop {
+            last_token_id = last_token_id.checked_add(1).ok_or(Error::Overflow)?;
+            let id = last_token_id.to_string();
+            if self.is_free(&id) {
+                registry::write_last_token_id(last_token_id);
+                return Ok(id);
+            }
+        }
+    }
+
+    fn read_proposal(&self, proposal_id: u64) -> Option<Proposal> {
+        multisig::read_proposal_from(self.proposals_uref(), proposal_id)
+    }
+
+    fn write_proposal(&mut self, proposal_id: u64, proposal: Proposal) {
+        multisig::write_proposal_to(self.proposals_uref(), proposal_id, proposal)
+    }
+
+    fn read_operation(&self, operation_id: u64) -> Option<QueuedOperation> {
+        timelock::read_operation_from(self.operations_uref(), operation_id)
+    }
+
+    fn write_operation(&mut self, operation_id: u64, queued_operation: QueuedOperation) {
+        timelock::write_operation_to(self.operations_uref(), operation_id, queued_operation)
+    }
+
+    /// Reads an operation which can still be executed or cancelled.
+    fn read_pending_operation(&self, operation_id: u64) -> Result<QueuedOperation, Error> {
+        self.read_operation(operation_id)
+            .filter(|queued_operation| queued_operation.pending)
+            .ok_or(Error::UnknownOperation)
+    }
+
+    fn read_supply_cap(&self, id: &str) -> Option<U256> {
+        self.supply_caps_uref()
+            .and_then(|supply_caps_uref| timelock::read_supply_cap_from(supply_caps_uref, id))
+    }
+
+    fn write_supply_cap(&mut self, id: &str, cap: Option<U256>) {
+        let supply_caps_uref = detail::unwrap_installed(self.supply_caps_uref());
+        timelock::write_supply_cap_to(supply_caps_uref, id, cap)
+    }
+
+    /// Returns an error unless the immediate caller may cancel and execute `operation`: a signer
+    /// of the multisig admin for the actions it approved, and the owner otherwise.
+    fn only_operation_admin(&self, operation: &Operation) -> Result<(), Error> {
+        match operation {
+            Operation::Action(_) => self.only_signer().map(|_| ()),
+            _ => self.only_owner(),
+        }
+    }
+
+    /// Queues `operation` to be executed once `delay` elapsed, without any permission checks.
+    fn schedule_operation(&mut self, operation: Operation, delay: u64) -> Result<u64, Error> {
+        if delay < timelock::read_min_delay() {
+            return Err(Error::DelayTooShort);
+        }
+        let eta = u64::from(runtime::get_blocktime())
+            .checked_add(delay)
+            .ok_or(Error::Overflow)?;
+        let operation_id = timelock::read_operations_count();
+        let queued_operation = QueuedOperation {
+            operation,
+            eta,
+            pending: true,
+        };
+        self.write_operation(operation_id, queued_operation);
+        timelock::write_operations_count(operation_id + 1);
+        events::emit(&Event::OperationQueued { operation_id, eta });
+        Ok(operation_id)
+    }
+
+    /// Makes `new_owner` the pending owner of the contract, without any permission checks.
+    fn start_ownership_transfer(&mut self, new_owner: Address) -> Result<(), Error> {
+        let owner = owner::read_owner().ok_or(Error::PermissionDenied)?;
+        owner::write_pending_owner(Some(new_owner));
+        events::emit(&Event::OwnershipTransferStarted {
+            owner,
+            pending_owner: new_owner,
+        });
+        Ok(())
+    }
+
+    /// Returns an error if token transfers are paused.
+    fn check_paused(&self) -> Result<(), Error> {
+        if pause::read_paused() {
+            return Err(Error::Paused);
+        }
+        Ok(())
+    }
+
+    /// Returns the immediate caller, or an error unless it is a signer of the multisig admin.
+    fn only_signer(&self) -> Result<Address, Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if !multisig::read_signers().contains(&caller) {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(caller)
+    }
+
+    /// Reads a proposal which can still be approved or executed.
+    fn read_pending_proposal(&self, proposal_id: u64) -> Result<Proposal, Error> {
+        let proposal = self
+            .read_proposal(proposal_id)
+            .filter(|proposal| !proposal.executed)
+            .ok_or(Error::UnknownProposal)?;
+        if u64::from(runtime::get_blocktime()) >= proposal.expiry {
+            return Err(Error::ProposalExpired);
+        }
+        Ok(proposal)
+    }
+
+    /// Performs an action approved by the multisig admin, once its timelock delay elapsed if the
+    /// timelock is installed.
+    fn execute_action(&mut self, action: Action) -> Result<(), Error> {
+        match action {
+            Action::Mint { to, id, amount } => {
+                let this = detail::get_self_address()?;
+                self.mint_by(this, to, &id, amount)
+            }
+            Action::Burn { owner, id, amount } => {
+                // Tokens held by the contract itself are escrowed by open offers.
+                if owner == detail::get_self_address()? {
+                    return Err(Error::PermissionDenied);
+                }
+                self.burn(owner, &id, amount)
+            }
+            Action::SetSigner { signer, approved } => {
+                let mut signers = multisig::read_signers();
+                signers.retain(|current| *current != signer);
+                if approved {
+                    signers.push(signer);
+                }
+                if multisig::read_threshold() as usize > signers.len() {
+                    return Err(Error::InvalidThreshold);
+                }
+                multisig::write_signers(signers);
+                Ok(())
+            }
+            Action::SetThreshold(threshold) => {
+                if threshold == 0 || threshold as usize > multisig::read_signers().len() {
+                    return Err(Error::InvalidThreshold);
+                }
+                multisig::write_threshold(threshold);
+                Ok(())
+            }
+            Action::SetPaused(paused) => {
+                pause::write_paused(paused);
+                Ok(())
+            }
+            Action::SetUri(uri) => {
+                storage::write(detail::get_uref(URI_KEY_NAME), uri);
+                Ok(())
+            }
+        }
+    }
+
+    /// Moves `amount` tokens of `id` from `from` to `to`, without any permission checks.
+    fn move_balance(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: &str,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.check_paused()?;
+        self.check_expired(id)?;
+        self.check_non_fungible_amount(id, amount)?;
+        let sender_balance = self
+            .read_balance(from, id)
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientBalance)?;
+        self.check_rented(from, id, sender_balance)?;
+        self.transfer_balance(from, to, id, amount)
+    }
+
+    /// Moves `amount` tokens of `id` from `from` to `to`, bypassing the pause, expiry and rental
+    /// checks of `move_balance`.
+    fn transfer_balance(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: &str,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let sender_balance = {
+            let balance = self.read_balance(from, id);
+            balance
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?
+        };
+        self.write_balance(from, id, sender_balance);
+        let recipient_balance = {
+            let balance = self.read_balance(to, id);
+            balance.checked_add(amount).ok_or(Error::Overflow)?
+        };
+        self.write_balance(to, id, recipient_balance);
+        Ok(())
+    }
+
+    /// Batched version of `move_balance`.
+    fn move_balances(
+        &mut self,
+        from: Address,
+        to: Address,
+        ids: &[String],
+        amounts: &[U256],
+    ) -> Result<(), Error> {
+        for (id, amount) in batch::coalesce(ids, amounts)? {
+            self.move_balance(from, to, id, amount)?;
+        }
+        Ok(())
+    }
+
     /// Installs the ERC1155 contract with the default set of entry points.
     ///
     /// This should be called from within `fn call()` of your contract.
@@ -104,6 +745,35 @@ impl ERC1155 {
         ERC1155::install_custom(uri, ERC1155_TOKEN_CONTRACT_KEY_NAME, default_entry_points)
     }
 
+    /// Installs the ERC1155 contract with the default set of entry points, extended with the
+    /// entry points of optional extensions such as [`entry_points::claims`].
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install_with_extensions(
+        uri: String,
+        extensions: Vec<EntryPoint>,
+    ) -> Result<ERC1155, Error> {
+        ERC1155::install_with_config(uri, extensions, Config::default())
+    }
+
+    /// Installs the ERC1155 contract like [`ERC1155::install_with_extensions`], with the settings
+    /// of `config`.
+    ///
+    /// This should be called from within `fn call()` of your contract.
+    pub fn install_with_config(
+        uri: String,
+        extensions: Vec<EntryPoint>,
+        config: Config,
+    ) -> Result<ERC1155, Error> {
+        let entry_points = entry_points::extended(extensions);
+        ERC1155::install_custom_with_config(
+            uri,
+            ERC1155_TOKEN_CONTRACT_KEY_NAME,
+            entry_points,
+            config,
+        )
+    }
+
     /// Returns the URI of the token.
     pub fn uri(&self) -> String {
         detail::read_from(URI_KEY_NAME)
@@ -114,8 +784,11 @@ impl ERC1155 {
         self.read_total_supply(&id)
     }
 
-    /// Returns the balance of `account`.
+    /// Returns the balance of `account`, or zero if `id` expired.
     pub fn balance_of(&self, account: Address, id: &str) -> U256 {
+        if self.is_expired(id) {
+            return U256::zero();
+        }
         self.read_balance(account, id)
     }
 
@@ -156,23 +829,36 @@ impl ERC1155 {
         if (from != spender && !operator) || amount == U256::zero() || from == to {
             return Ok(());
         } else {
+            self.check_paused()?;
+            self.check_expired(&id)?;
+            self.check_non_fungible_amount(&id, amount)?;
             let sender_balance = {
                 let balance = self.read_balance(from, &id);
                 balance
                     .checked_sub(amount)
                     .ok_or(Error::InsufficientBalance)?
             };
+            self.check_rented(from, &id, sender_balance)?;
             let recipient_balance = {
                 let balance = self.read_balance(to, &id);
                 balance.checked_add(amount).ok_or(Error::Overflow)?
             };
             self.write_balance(from, &id, sender_balance);
             self.write_balance(to, &id, recipient_balance);
+            events::emit(&Event::TransferSingle {
+                operator: spender,
+                from,
+                to,
+                id: id.to_string(),
+                amount,
+            });
             Ok(())
         }
     }
 
     /// Batched version of safe_transfer_from.
+    ///
+    /// Amounts of a repeated id are summed up, so each balance is read and written once.
     pub fn safe_batch_transfer_from(
         &mut self,
         from: Address,
@@ -185,53 +871,163 @@ impl ERC1155 {
         if (from != spender && !operator) || from == to {
             return Ok(());
         } else {
-            for (i, _) in ids.iter().enumerate() {
+            self.check_paused()?;
+            for (id, amount) in batch::coalesce(&ids, &amounts)? {
+                self.check_expired(id)?;
+                self.check_non_fungible_amount(id, amount)?;
                 let sender_balance = {
-                    let balance = self.read_balance(from, &ids[i]);
+                    let balance = self.read_balance(from, id);
                     balance
-                        .checked_sub(amounts[i])
+                        .checked_sub(amount)
                         .ok_or(Error::InsufficientBalance)?
                 };
+                self.check_rented(from, id, sender_balance)?;
                 let recipient_balance = {
-                    let balance = self.read_balance(to, &ids[i]);
-                    balance.checked_add(amounts[i]).ok_or(Error::Overflow)?
+                    let balance = self.read_balance(to, id);
+                    balance.checked_add(amount).ok_or(Error::Overflow)?
                 };
-                self.write_balance(from, &ids[i], sender_balance);
-                self.write_balance(to, &ids[i], recipient_balance);
+                self.write_balance(from, id, sender_balance);
+                self.write_balance(to, id, recipient_balance);
             }
+            events::emit(&Event::TransferBatch {
+                operator: spender,
+                from,
+                to,
+                ids,
+                amounts,
+            });
             Ok(())
         }
     }
 
-    /// Mints `amount` new tokens and adds them to `owner`'s balance and to the token total supply.
-    /// # Security
-    /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
-    /// public entry point.
-    pub fn mint(&mut self, to: Address, id: &str, amount: U256) -> Result<(), Error> {
-        let new_balance = {
-            let balance = self.read_balance(to, &id);
-            balance.checked_add(amount).ok_or(Error::Overflow)?
-        };
-        let new_total_supply = {
-            let total_supply = self.read_total_supply(&id);
-            total_supply.checked_add(amount).ok_or(Error::Overflow)?
-        };
-        self.write_balance(to, &id, new_balance);
-        self.write_total_supply(&id, new_total_supply);
+    /// Transfers `amounts[i]` tokens of `ids[i]` from `from` to `recipients[i]`, for every `i`.
+    ///
+    /// The balance of `from` is read and written once per distinct id, for the sum of the amounts
+    /// of that id. Emits one batch transfer event per distinct recipient.
+    pub fn safe_multi_transfer_from(
+        &mut self,
+        from: Address,
+        recipients: Vec<Address>,
+        ids: Vec<String>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        if recipients.len() != ids.len() || ids.len() != amounts.len() {
+            return Err(Error::LengthMismatch);
+        }
+        let spender = detail::get_immediate_caller_address()?;
+        let operator = self.read_operator(from, spender);
+        if from != spender && !operator {
+            return Ok(());
+        }
+        self.check_paused()?;
+        let mut debits: BTreeMap<&str, U256> = BTreeMap::new();
+        for (id, amount) in ids.iter().zip(&amounts) {
+            let debit = debits.entry(id.as_str()).or_insert_with(U256::zero);
+            *debit = debit.checked_add(*amount).ok_or(Error::Overflow)?;
+        }
+        for (id, debit) in &debits {
+            self.check_expired(id)?;
+            self.check_non_fungible_amount(id, *debit)?;
+            let sender_balance = {
+                let balance = self.read_balance(from, id);
+                balance
+                    .checked_sub(*debit)
+                    .ok_or(Error::InsufficientBalance)?
+            };
+            self.check_rented(from, id, sender_balance)?;
+            self.write_balance(from, id, sender_balance);
+        }
+        let mut transfers: Vec<(Address, Vec<String>, Vec<U256>)> = Vec::new();
+        for ((to, id), amount) in recipients.into_iter().zip(ids).zip(amounts) {
+            let recipient_balance = {
+                let balance = self.read_balance(to, &id);
+                balance.checked_add(amount).ok_or(Error::Overflow)?
+            };
+            self.write_balance(to, &id, recipient_balance);
+            match transfers
+                .iter_mut()
+                .find(|(recipient, _, _)| *recipient == to)
+            {
+                Some((_, ids, amounts)) => {
+                    ids.push(id);
+                    amounts.push(amount);
+                }
+                None => transfers.push((to, vec![id], vec![amount])),
+            }
+        }
+        for (to, ids, amounts) in transfers {
+            events::emit(&Event::TransferBatch {
+                operator: spender,
+                from,
+                to,
+                ids,
+                amounts,
+            });
+        }
         Ok(())
     }
 
+    /// Mints `amount` new tokens and adds them to `owner`'s balance and to the token total supply.
+    ///
+    /// Ids reserved for the tokens of extensions, i.e. the ids returned by [`wrapped_token_id`]
+    /// and [`fraction_token_id`], can't be minted.
+    /// # Security
+    /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
+    /// public entry point.
+    pub fn mint(&mut self, to: Address, id: &str, amount: U256) -> Result<(), Error> {
+        self.check_reserved(id)?;
+        self.mint_tokens(to, id, amount)
+    }
+
     /// Burns (i.e. subtracts) `amount` of tokens from `owner`'s balance and from the token total supply.
+    ///
+    /// Ids reserved for the tokens of extensions, i.e. the ids returned by [`wrapped_token_id`]
+    /// and [`fraction_token_id`], can't be burnt.
     /// # Security
     /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
     /// public entry point.
     pub fn burn(&mut self, owner: Address, id: &str, amount: U256) -> Result<(), Error> {
+        self.check_reserved(id)?;
+        self.burn_tokens(owner, id, amount)
+    }
+
+    /// Mints `amount` tokens of `id` to `to`, including reserved ids.
+    fn mint_tokens(&mut self, to: Address, id: &str, amount: U256) -> Result<(), Error> {
+        self.check_expired(id)?;
+        let new_balance = {
+            let balance = self.read_balance(to, &id);
+            balance.checked_add(amount).ok_or(Error::Overflow)?
+        };
+        let new_total_supply = {
+            let total_supply = self.read_total_supply(&id);
+            total_supply.checked_add(amount).ok_or(Error::Overflow)?
+        };
+        if new_total_supply > U256::one() && self.is_non_fungible(id) {
+            return Err(Error::NonFungibleAmount);
+        }
+        if self
+            .read_supply_cap(id)
+            .map_or(false, |cap| new_total_supply > cap)
+        {
+            return Err(Error::SupplyCapExceeded);
+        }
+        self.write_balance(to, &id, new_balance);
+        self.write_total_supply(&id, new_total_supply);
+        Ok(())
+    }
+
+    /// Burns `amount` tokens of `id` from `owner`, including reserved ids.
+    ///
+    /// Tokens locked by an active rental can't be burnt.
+    fn burn_tokens(&mut self, owner: Address, id: &str, amount: U256) -> Result<(), Error> {
+        self.check_non_fungible_amount(id, amount)?;
         let new_balance = {
             let balance = self.read_balance(owner, &id);
             balance
                 .checked_sub(amount)
                 .ok_or(Error::InsufficientBalance)?
         };
+        self.check_rented(owner, id, new_balance)?;
         let new_total_supply = {
             let total_supply = self.read_total_supply(&id);
             total_supply.checked_sub(amount).ok_or(Error::Overflow)?
@@ -241,6 +1037,898 @@ impl ERC1155 {
         Ok(())
     }
 
+    /// Batched version of `mint`, summing up the amounts of a repeated id.
+    /// # Security
+    /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
+    /// public entry point.
+    pub fn mint_batch(
+        &mut self,
+        to: Address,
+        ids: Vec<String>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        for (id, amount) in batch::coalesce(&ids, &amounts)? {
+            self.mint(to, id, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Batched version of `burn`, summing up the amounts of a repeated id.
+    /// # Security
+    /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
+    /// public entry point.
+    pub fn burn_batch(
+        &mut self,
+        owner: Address,
+        ids: Vec<String>,
+        amounts: Vec<U256>,
+    ) -> Result<(), Error> {
+        for (id, amount) in batch::coalesce(&ids, &amounts)? {
+            self.burn(owner, id, amount)?;
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless the immediate caller is `holder` or an operator approved by `holder`.
+    pub fn only_holder_or_operator(&self, holder: Address) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if caller != holder && !self.read_operator(holder, caller) {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Returns an error unless the immediate caller is the owner of the contract.
+    pub fn only_owner(&self) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if Some(caller) != owner::read_owner() {
+            return Err(Error::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    /// Returns the rule resolving the caller of every entry point, chosen at install.
+    pub fn caller_policy(&self) -> CallerPolicy {
+        config::read_caller_policy()
+    }
+
+    /// Returns the owner of the contract, or `None` if ownership was renounced.
+    pub fn owner(&self) -> Option<Address> {
+        owner::read_owner()
+    }
+
+    /// Returns the address which can accept the ownership of the contract, if any.
+    pub fn pending_owner(&self) -> Option<Address> {
+        owner::read_pending_owner()
+    }
+
+    /// Starts to transfer the ownership of the contract to `new_owner`, which becomes the owner
+    /// once it calls [`ERC1155::accept_ownership`].
+    ///
+    /// Only the owner of the contract can transfer its ownership. Any previous pending transfer is
+    /// replaced. To delay the transfer, the owner can queue an [`Operation::TransferOwnership`] in
+    /// the timelock instead.
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Error> {
+        self.only_owner()?;
+        self.start_ownership_transfer(new_owner)
+    }
+
+    /// Makes the immediate caller the owner of the contract, if it is the pending owner.
+    pub fn accept_ownership(&mut self) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        let pending_owner = owner::read_pending_owner().ok_or(Error::NoPendingOwner)?;
+        if caller != pending_owner {
+            return Err(Error::PermissionDenied);
+        }
+        let previous_owner = owner::read_owner().ok_or(Error::PermissionDenied)?;
+        owner::write_owner(Some(pending_owner));
+        owner::write_pending_owner(None);
+        events::emit(&Event::OwnershipTransferred {
+            previous_owner,
+            new_owner: pending_owner,
+        });
+        Ok(())
+    }
+
+    /// Cancels the pending transfer of the ownership of the contract.
+    ///
+    /// Only the owner of the contract can cancel the transfer. A transfer still queued in the
+    /// timelock is cancelled through [`ERC1155::cancel_operation`] instead.
+    pub fn cancel_ownership_transfer(&mut self) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        self.only_owner()?;
+        let pending_owner = owner::read_pending_owner().ok_or(Error::NoPendingOwner)?;
+        owner::write_pending_owner(None);
+        events::emit(&Event::OwnershipTransferCancelled {
+            owner,
+            pending_owner,
+        });
+        Ok(())
+    }
+
+    /// Leaves the contract without owner, which disables every function restricted to the owner.
+    ///
+    /// Only the owner of the contract can renounce its ownership. Any pending transfer is
+    /// cancelled.
+    pub fn renounce_ownership(&mut self) -> Result<(), Error> {
+        let previous_owner = detail::get_immediate_caller_address()?;
+        self.only_owner()?;
+        owner::write_owner(None);
+        owner::write_pending_owner(None);
+        events::emit(&Event::OwnershipRenounced { previous_owner });
+        Ok(())
+    }
+
+    /// Returns the Merkle root registered for the claim `campaign`, if any.
+    pub fn claim_root(&self, campaign: &str) -> Option<[u8; 32]> {
+        self.read_claim_root(campaign).map(|(root, _)| root)
+    }
+
+    /// Registers the Merkle `root` of the claim `campaign`, replacing any previous root.
+    ///
+    /// Only the owner of the contract can register claim roots, and the claimed tokens are minted
+    /// on their behalf.
+    pub fn set_claim_root(&mut self, campaign: &str, root: [u8; 32]) -> Result<(), Error> {
+        self.only_owner()?;
+        let registrar = detail::get_immediate_caller_address()?;
+        self.write_claim_root(campaign, root, registrar);
+        Ok(())
+    }
+
+    /// Mints `amount` tokens of `id` to the caller if `proof` shows that the claim is part of
+    /// `campaign`.
+    ///
+    /// See [`merkle`] for the way leaves and proofs are computed. Every leaf can be claimed once, as
+    /// long as the account which registered `campaign` can mint `id`.
+    pub fn claim(
+        &mut self,
+        campaign: &str,
+        id: &str,
+        amount: U256,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), Error> {
+        let account = detail::get_immediate_caller_address()?;
+        let (root, registrar) = self
+            .read_claim_root(campaign)
+            .ok_or(Error::UnknownCampaign)?;
+        let leaf = merkle::leaf_hash(account, id, amount, |bytes| runtime::blake2b(bytes))
+            .unwrap_or_revert();
+        if !merkle::verify(root, leaf, &proof, |bytes| runtime::blake2b(bytes)) {
+            return Err(Error::InvalidProof);
+        }
+        if self.read_claimed(campaign, leaf) {
+            return Err(Error::AlreadyClaimed);
+        }
+        self.write_claimed(campaign, leaf);
+        self.mint_by(registrar, account, id, amount)
+    }
+
+    /// Returns the primary sale of `id`, if any.
+    pub fn sale(&self, id: &str) -> Option<Sale> {
+        self.read_sale(id)
+    }
+
+    /// Puts `supply` tokens of `id` on sale for `price` motes each, between `start_time` and
+    /// `end_time`, limiting every wallet to `wallet_limit` tokens (zero for no limit).
+    ///
+    /// Tokens sold under a previous configuration of `id` still count towards the new `supply`.
+    /// The sale must open before it closes, and both `price` and `supply` must be nonzero.
+    /// Only the owner of the contract can configure sales, of token types created through
+    /// [`ERC1155::create`] which the owner created or can mint.
+    pub fn set_sale(
+        &mut self,
+        id: &str,
+        price: U512,
+        start_time: u64,
+        end_time: u64,
+        wallet_limit: U256,
+        supply: U256,
+    ) -> Result<(), Error> {
+        self.only_owner()?;
+        if start_time >= end_time || price.is_zero() || supply.is_zero() {
+            return Err(Error::InvalidSale);
+        }
+        let seller = detail::get_immediate_caller_address()?;
+        self.check_creator_or_minter(seller, id)?;
+        let sold = self.read_sale(id).map_or_else(U256::zero, |sale| sale.sold);
+        let sale = Sale {
+            price,
+            start_time,
+            end_time,
+            wallet_limit,
+            supply,
+            sold,
+            seller,
+        };
+        self.write_sale(id, sale);
+        Ok(())
+    }
+
+    /// Mints `amount` tokens of `id` to the caller, paying for them with the motes held in `purse`.
+    ///
+    /// The price of the tokens is moved from `purse` into the treasury purse of the contract, and
+    /// whatever is left stays in `purse`. The seller of the sale must still be able to mint `id`.
+    pub fn purchase(&mut self, id: &str, amount: U256, purse: URef) -> Result<(), Error> {
+        let buyer = detail::get_immediate_caller_address()?;
+        let mut sale = self.read_sale(id).ok_or(Error::SaleNotActive)?;
+        if !sale.is_open(runtime::get_blocktime().into()) {
+            return Err(Error::SaleNotActive);
+        }
+        self.check_creator_or_minter(sale.seller, id)?;
+        sale.sold = sale.sold.checked_add(amount).ok_or(Error::Overflow)?;
+        if sale.sold > sale.supply {
+            return Err(Error::SoldOut);
+        }
+        let purchased = {
+            let purchased = self.read_purchased(buyer, id);
+            purchased.checked_add(amount).ok_or(Error::Overflow)?
+        };
+        if !sale.wallet_limit.is_zero() && purchased > sale.wallet_limit {
+            return Err(Error::PurchaseLimitExceeded);
+        }
+        let cost = sale.cost(amount).ok_or(Error::Overflow)?;
+        let balance = system::get_purse_balance(purse).unwrap_or_revert();
+        if balance < cost {
+            return Err(Error::InsufficientPayment);
+        }
+        system::transfer_from_purse_to_purse(purse, sales::treasury_purse(), cost, None)
+            .unwrap_or_revert();
+        self.write_sale(id, sale);
+        self.write_purchased(buyer, id, purchased);
+        self.mint(buyer, id, amount)
+    }
+
+    /// Transfers `amount` motes from the treasury purse to the main purse of the caller.
+    ///
+    /// Only the owner of the contract can withdraw, and only into an account.
+    pub fn withdraw(&mut self, amount: U512) -> Result<(), Error> {
+        self.only_owner()?;
+        let caller = detail::get_immediate_caller_address()?;
+        let account_hash = caller.as_account_hash().ok_or(Error::InvalidContext)?;
+        system::transfer_from_purse_to_account(
+            sales::treasury_purse(),
+            *account_hash,
+            amount,
+            None,
+        )
+        .unwrap_or_revert();
+        Ok(())
+    }
+
+    /// Returns the ERC20 contract wrapped by `id`, if any.
+    pub fn wrapped_token(&self, id: &str) -> Option<ContractHash> {
+        self.read_wrapped_token(id)
+    }
+
+    /// Pulls `amount` tokens of `erc20_contract` from the caller, and mints the amount the
+    /// contract actually received of the id returned by [`wrapped_token_id`] to them.
+    ///
+    /// The caller must have approved the package of this contract to spend `amount` of their ERC20
+    /// tokens beforehand. Wrapped ids can only be minted by `wrap`.
+    pub fn wrap(&mut self, erc20_contract: ContractHash, amount: U256) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        let this = detail::get_self_address()?;
+        let id = wrapped_token_id(erc20_contract);
+        let balance_before = wrapper::balance_of(erc20_contract, this);
+        wrapper::transfer_from(erc20_contract, owner, this, amount);
+        // Tokens taking a fee on transfers deliver less than `amount`, and only the delivered
+        // tokens can be unwrapped later.
+        let received = wrapper::balance_of(erc20_contract, this)
+            .checked_sub(balance_before)
+            .ok_or(Error::InsufficientBalance)?;
+        if received.is_zero() || received > amount {
+            return Err(Error::InvalidAmount);
+        }
+        self.write_wrapped_token(&id, erc20_contract);
+        self.mint_tokens(owner, &id, received)
+    }
+
+    /// Burns `amount` tokens of the wrapped `id` owned by the caller, and returns the same amount of
+    /// the underlying ERC20 tokens to them.
+    ///
+    /// Wrapped ids can only be burnt by `unwrap`.
+    pub fn unwrap(&mut self, id: &str, amount: U256) -> Result<(), Error> {
+        let owner = detail::get_immediate_caller_address()?;
+        let erc20_contract = self
+            .read_wrapped_token(id)
+            .ok_or(Error::UnknownWrappedToken)?;
+        self.burn_tokens(owner, id, amount)?;
+        wrapper::transfer(erc20_contract, owner, amount);
+        Ok(())
+    }
+
+    /// Returns the NFT contract and token id fractionalized into `id`, if any.
+    pub fn fractionalized_nft(&self, id: &str) -> Option<(ContractHash, U256)> {
+        self.read_fractionalized_nft(id)
+    }
+
+    /// Returns true if `token_id` of `nft_contract` is locked in the contract.
+    pub fn is_nft_locked(&self, nft_contract: ContractHash, token_id: U256) -> bool {
+        self.read_nft_lock(nft_contract, token_id)
+    }
+
+    /// Locks `token_id` of `nft_contract` into the contract, and mints `amount` fractions of it
+    /// under the id returned by [`fraction_token_id`] to the caller.
+    ///
+    /// `nft_contract` must implement the CEP-47 interface; CEP-78 contracts aren't supported. The
+    /// caller must have approved the package of this contract to transfer the NFT beforehand.
+    /// Fraction ids can only be minted by `fractionalize`.
+    pub fn fractionalize(
+        &mut self,
+        nft_contract: ContractHash,
+        token_id: U256,
+        amount: U256,
+    ) -> Result<(), Error> {
+        let depositor = detail::get_immediate_caller_address()?;
+        let this = detail::get_self_address()?;
+        if amount.is_zero() {
+            return Err(Error::InvalidAmount);
+        }
+        if self.read_nft_lock(nft_contract, token_id) {
+            return Err(Error::NftLocked);
+        }
+        let id = fraction_token_id(nft_contract, token_id, |bytes| runtime::blake2b(bytes))
+            .unwrap_or_revert();
+        fractions::transfer_nft_from(nft_contract, depositor, this, token_id);
+        self.write_nft_lock(nft_contract, token_id, true);
+        self.write_fractionalized_nft(&id, nft_contract, token_id);
+        self.mint_tokens(depositor, &id, amount)
+    }
+
+    /// Burns every fraction of `id`, which must all be owned by the caller, and releases the
+    /// locked NFT to them.
+    ///
+    /// Fraction ids can only be burnt by `redeem`.
+    pub fn redeem(&mut self, id: &str) -> Result<(), Error> {
+        let redeemer = detail::get_immediate_caller_address()?;
+        let (nft_contract, token_id) = self
+            .read_fractionalized_nft(id)
+            .ok_or(Error::NftNotLocked)?;
+        if !self.read_nft_lock(nft_contract, token_id) {
+            return Err(Error::NftNotLocked);
+        }
+        let fractions = self.read_total_supply(id);
+        self.burn_tokens(redeemer, id, fractions)?;
+        self.write_nft_lock(nft_contract, token_id, false);
+        fractions::transfer_nft(nft_contract, redeemer, token_id);
+        Ok(())
+    }
+
+    /// Returns the offer identified by `offer_id`, if any.
+    pub fn offer(&self, offer_id: u64) -> Option<Offer> {
+        self.read_offer(offer_id)
+    }
+
+    /// Locks the `offered_amounts` of `offered_ids` owned by the caller into the contract, in
+    /// exchange for the `wanted_amounts` of `wanted_ids`, and returns the id of the new offer.
+    ///
+    /// If `taker` is set, only that address can accept the offer. The offer can be accepted until
+    /// `expiry`, which must be in the future, and cancelled by its maker at any time. Both bundles
+    /// must hold at least one token id.
+    pub fn create_offer(
+        &mut self,
+        taker: Option<Address>,
+        offered_ids: Vec<String>,
+        offered_amounts: Vec<U256>,
+        wanted_ids: Vec<String>,
+        wanted_amounts: Vec<U256>,
+        expiry: u64,
+    ) -> Result<u64, Error> {
+        let maker = detail::get_immediate_caller_address()?;
+        let this = detail::get_self_address()?;
+        if offered_ids.len() != offered_amounts.len() || wanted_ids.len() != wanted_amounts.len() {
+            return Err(Error::LengthMismatch);
+        }
+        if offered_ids.is_empty() || wanted_ids.is_empty() {
+            return Err(Error::EmptyOffer);
+        }
+        if u64::from(runtime::get_blocktime()) >= expiry {
+            return Err(Error::OfferExpired);
+        }
+        self.move_balances(maker, this, &offered_ids, &offered_amounts)?;
+        emit_transfer(maker, maker, this, &offered_ids, &offered_amounts);
+        let offer_id = escrow::read_offers_count();
+        let offer = Offer {
+            maker,
+            taker,
+            offered_ids,
+            offered_amounts,
+            wanted_ids,
+            wanted_amounts,
+            expiry,
+            open: true,
+        };
+        self.write_offer(offer_id, offer);
+        escrow::write_offers_count(offer_id + 1);
+        Ok(offer_id)
+    }
+
+    /// Swaps the tokens asked by the offer `offer_id` from the caller to its maker for the tokens
+    /// locked by the maker.
+    pub fn accept_offer(&mut self, offer_id: u64) -> Result<(), Error> {
+        let taker = detail::get_immediate_caller_address()?;
+        let this = detail::get_self_address()?;
+        let mut offer = self
+            .read_offer(offer_id)
+            .filter(|offer| offer.open)
+            .ok_or(Error::UnknownOffer)?;
+        if offer.taker.map_or(false, |expected| expected != taker) {
+            return Err(Error::PermissionDenied);
+        }
+        if u64::from(runtime::get_blocktime()) >= offer.expiry {
+            return Err(Error::OfferExpired);
+        }
+        self.move_balances(taker, offer.maker, &offer.wanted_ids, &offer.wanted_amounts)?;
+        self.move_balances(this, taker, &offer.offered_ids, &offer.offered_amounts)?;
+        emit_transfer(
+            taker,
+            taker,
+            offer.maker,
+            &offer.wanted_ids,
+            &offer.wanted_amounts,
+        );
+        emit_transfer(
+            taker,
+            this,
+            taker,
+            &offer.offered_ids,
+            &offer.offered_amounts,
+        );
+        offer.open = false;
+        self.write_offer(offer_id, offer);
+        Ok(())
+    }
+
+    /// Closes the offer `offer_id` made by the caller and returns the locked tokens to them.
+    ///
+    /// Escrowed tokens are always returned, even while the contract is paused or once their ids
+    /// have expired.
+    pub fn cancel_offer(&mut self, offer_id: u64) -> Result<(), Error> {
+        let maker = detail::get_immediate_caller_address()?;
+        let this = detail::get_self_address()?;
+        let mut offer = self
+            .read_offer(offer_id)
+            .filter(|offer| offer.open)
+            .ok_or(Error::UnknownOffer)?;
+        if offer.maker != maker {
+            return Err(Error::PermissionDenied);
+        }
+        for (id, amount) in batch::coalesce(&offer.offered_ids, &offer.offered_amounts)? {
+            self.transfer_balance(this, maker, id, amount)?;
+        }
+        emit_transfer(
+            maker,
+            this,
+            maker,
+            &offer.offered_ids,
+            &offer.offered_amounts,
+        );
+        offer.open = false;
+        self.write_offer(offer_id, offer);
+        Ok(())
+    }
+
+    /// Takes a snapshot of every balance and total supply, and returns its id.
+    ///
+    /// Snapshot ids start at one and increase by one with every snapshot. Only the owner of the
+    /// contract can take snapshots.
+    pub fn snapshot(&mut self) -> Result<u64, Error> {
+        self.only_owner()?;
+        let snapshot_id = self
+            .read_snapshot_id()
+            .checked_add(1)
+            .ok_or(Error::Overflow)?;
+        self.write_snapshot_id(snapshot_id);
+        Ok(snapshot_id)
+    }
+
+    /// Returns the balance of `account` at the time snapshot `snapshot_id` was taken.
+    pub fn balance_of_at(
+        &self,
+        account: Address,
+        id: &str,
+        snapshot_id: u64,
+    ) -> Result<U256, Error> {
+        if snapshot_id == 0 || snapshot_id > self.read_snapshot_id() {
+            return Err(Error::UnknownSnapshot);
+        }
+        let balance = snapshots::read_balance_at_from(
+            self.balance_snapshots_uref(),
+            account,
+            id,
+            snapshot_id,
+        )
+        .unwrap_or_else(|| self.read_balance(account, id));
+        Ok(balance)
+    }
+
+    /// Returns the total supply of `id` at the time snapshot `snapshot_id` was taken.
+    pub fn total_supply_at(&self, id: &str, snapshot_id: u64) -> Result<U256, Error> {
+        if snapshot_id == 0 || snapshot_id > self.read_snapshot_id() {
+            return Err(Error::UnknownSnapshot);
+        }
+        let total_supply = snapshots::read_total_supply_at_from(
+            self.total_supply_snapshots_uref(),
+            id,
+            snapshot_id,
+        )
+        .unwrap_or_else(|| self.read_total_supply(id));
+        Ok(total_supply)
+    }
+
+    /// Returns the user of the tokens of `id` rented out by `owner`, if the rental is active.
+    pub fn user_of(&self, owner: Address, id: &str) -> Option<Address> {
+        self.read_active_rental(owner, id).map(|rental| rental.user)
+    }
+
+    /// Returns the amount of tokens of `id` `user` can use, which is their balance minus the
+    /// tokens they rented out, plus the tokens rented to them.
+    pub fn usable_balance_of(&self, user: Address, id: &str) -> U256 {
+        let locked = self
+            .read_active_rental(user, id)
+            .map_or_else(U256::zero, |rental| rental.amount);
+        self.read_rented_from(user, id)
+            .into_iter()
+            .filter_map(|owner| self.read_active_rental(owner, id))
+            .filter(|rental| rental.user == user)
+            .fold(
+                self.read_balance(user, id).saturating_sub(locked),
+                |usable, rental| usable.saturating_add(rental.amount),
+            )
+    }
+
+    /// Rents `amount` tokens of `id` owned by `owner` out to `user` until `expires`.
+    ///
+    /// The rented tokens stay in the balance of `owner`, but can't be transferred until the rental
+    /// expires or is revoked by `user`. The caller must be `owner` or one of their operators, and
+    /// `owner` can't grant a new rental of `id` while another one is active.
+    pub fn set_user(
+        &mut self,
+        owner: Address,
+        id: &str,
+        amount: U256,
+        user: Address,
+        expires: u64,
+    ) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        if caller != owner && !self.read_operator(owner, caller) {
+            return Err(Error::PermissionDenied);
+        }
+        if self.read_active_rental(owner, id).is_some() {
+            return Err(Error::BalanceRented);
+        }
+        let blocktime = u64::from(runtime::get_blocktime());
+        if amount.is_zero() || expires <= blocktime {
+            return Err(Error::InvalidAmount);
+        }
+        if self.read_balance(owner, id) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        let rental = Rental {
+            user,
+            amount,
+            expires,
+        };
+        self.write_rental(owner, id, rental);
+        // Owners whose rental to `user` is over are dropped, so the list doesn't grow forever.
+        let mut owners: Vec<Address> = self
+            .read_rented_from(user, id)
+            .into_iter()
+            .filter(|lender| *lender != owner)
+            .filter(|lender| {
+                self.read_active_rental(*lender, id)
+                    .map_or(false, |rental| rental.user == user)
+            })
+            .collect();
+        owners.push(owner);
+        self.write_rented_from(user, id, owners);
+        Ok(())
+    }
+
+    /// Ends the rental of `id` granted by `owner` to the caller before it expires, unlocking the
+    /// rented tokens.
+    pub fn revoke_user(&mut self, owner: Address, id: &str) -> Result<(), Error> {
+        let user = detail::get_immediate_caller_address()?;
+        let mut rental = self
+            .read_active_rental(owner, id)
+            .filter(|rental| rental.user == user)
+            .ok_or(Error::NotRented)?;
+        rental.expires = runtime::get_blocktime().into();
+        self.write_rental(owner, id, rental);
+        Ok(())
+    }
+
+    /// Returns the blocktime at which `id` expires, if it expires.
+    pub fn expiry_of(&self, id: &str) -> Option<u64> {
+        self.read_expiry(id)
+    }
+
+    /// Mints the first `amount` tokens of `id`, which expire at the blocktime `expiry`.
+    ///
+    /// Once expired, balances of `id` are reported as zero and can no longer be transferred or
+    /// minted. Later mints of `id` keep the same expiry, which must be after the current blocktime.
+    /// # Security
+    /// This offers no security whatsoever, hence it is advised to NOT expose this method through a
+    /// public entry point.
+    pub fn mint_with_expiry(
+        &mut self,
+        to: Address,
+        id: &str,
+        amount: U256,
+        expiry: u64,
+    ) -> Result<(), Error> {
+        if self.read_expiry(id).is_some() || !self.read_total_supply(id).is_zero() {
+            return Err(Error::AlreadyMinted);
+        }
+        if expiry <= u64::from(runtime::get_blocktime()) {
+            return Err(Error::TokenExpired);
+        }
+        self.write_expiry(id, expiry);
+        self.mint(to, id, amount)
+    }
+
+    /// Burns the balances of `id` held by `accounts` once `id` expired, removing them from its
+    /// total supply.
+    pub fn sweep_expired(&mut self, id: &str, accounts: Vec<Address>) -> Result<(), Error> {
+        if !self.is_expired(id) {
+            return Err(Error::TokenNotExpired);
+        }
+        for account in accounts {
+            let balance = self.read_balance(account, id);
+            if !balance.is_zero() {
+                self.burn(account, id, balance)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the type of `id`, if it was created through [`ERC1155::create`].
+    pub fn token_type(&self, id: &str) -> Option<TokenType> {
+        self.read_token_type(id)
+    }
+
+    /// Returns the creator of `id`, if it was created through [`ERC1155::create`].
+    pub fn creator_of(&self, id: &str) -> Option<Address> {
+        self.read_token_type(id)
+            .map(|token_type| token_type.creator)
+    }
+
+    /// Returns the owner of the non-fungible `id`, if it is minted.
+    ///
+    /// Non-fungible ids are the items of a token type created as non-fungible, see
+    /// [`nft_token_id`].
+    pub fn owner_of(&self, id: &str) -> Option<Address> {
+        if !self.is_non_fungible(id) {
+            return None;
+        }
+        self.read_nft_owner(id)
+            .filter(|owner| self.read_balance(*owner, id) == U256::one())
+    }
+
+    /// Returns true if `id` was created through [`ERC1155::create`].
+    pub fn exists(&self, id: &str) -> bool {
+        self.read_token_type(id).is_some()
+    }
+
+    /// Registers the token type `id`, created by the caller, and mints its `initial_supply` to
+    /// them. Returns the id of the new token type.
+    ///
+    /// If `id` is `None`, the next free numeric id is assigned. Ids which were already created or
+    /// minted can't be created, nor ids containing the `#` separator of [`nft_token_id`]. Neither
+    /// can the empty id, which is the base type of the ids reserved by extensions.
+    ///
+    /// Non-fungible types must be created with no `initial_supply`, since their tokens are the
+    /// items minted under the ids of [`nft_token_id`].
+    pub fn create(
+        &mut self,
+        id: Option<String>,
+        initial_supply: U256,
+        uri: String,
+        fungible: bool,
+    ) -> Result<String, Error> {
+        let creator = detail::get_immediate_caller_address()?;
+        let id = match id {
+            Some(id) => id,
+            None => self.next_token_id()?,
+        };
+        self.check_reserved(&id)?;
+        if id.is_empty() || registry::is_item_token_id(&id) {
+            return Err(Error::InvalidTokenId);
+        }
+        if !self.is_free(&id) {
+            return Err(Error::AlreadyMinted);
+        }
+        if !fungible && !initial_supply.is_zero() {
+            return Err(Error::NonFungibleAmount);
+        }
+        let token_type = TokenType {
+            creator,
+            uri,
+            fungible,
+        };
+        self.write_token_type(&id, token_type);
+        self.mint(creator, &id, initial_supply)?;
+        Ok(id)
+    }
+
+    /// Grants or revokes permission to `minter` to mint tokens of `id`, according to `approved`.
+    ///
+    /// Only the creator of `id` can delegate its minting.
+    pub fn set_minter(&mut self, id: &str, minter: Address, approved: bool) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        let token_type = self.read_token_type(id).ok_or(Error::UnknownTokenType)?;
+        if token_type.creator != caller {
+            return Err(Error::PermissionDenied);
+        }
+        self.write_minter(id, minter, approved);
+        Ok(())
+    }
+
+    /// Returns an error unless the immediate caller can mint tokens of `id`.
+    ///
+    /// Created ids, and the items of created non-fungible types, can only be minted by their
+    /// creator and the minters they delegated to. Other ids can only be minted by the contract
+    /// itself, i.e. through an [`Action::Mint`] approved by the multisig admin.
+    pub fn only_minter(&self, id: &str) -> Result<(), Error> {
+        let caller = detail::get_immediate_caller_address()?;
+        self.check_minter(caller, id)
+    }
+
+    /// Returns the signers of the multisig admin.
+    pub fn signers(&self) -> Vec<Address> {
+        multisig::read_signers()
+    }
+
+    /// Returns the amount of signer approvals needed to execute a multisig proposal.
+    pub fn threshold(&self) -> u32 {
+        multisig::read_threshold()
+    }
+
+    /// Returns true if token transfers are paused.
+    pub fn is_paused(&self) -> bool {
+        pause::read_paused()
+    }
+
+    /// Returns the multisig proposal identified by `proposal_id`, if any.
+    pub fn proposal(&self, proposal_id: u64) -> Option<Proposal> {
+        self.read_proposal(proposal_id)
+    }
+
+    /// Proposes to execute `action` through the multisig admin, and returns the id of the new
+    /// proposal.
+    ///
+    /// Only signers can propose, and their proposal counts as approved by them. The proposal can
+    /// be approved and executed until `expiry`.
+    pub fn propose(&mut self, action: Action, expiry: u64) -> Result<u64, Error> {
+        let proposer = self.only_signer()?;
+        let proposal_id = multisig::read_proposals_count();
+        let proposal = Proposal {
+            action,
+            proposer,
+            approvals: vec![proposer],
+            expiry,
+            executed: false,
+        };
+        self.write_proposal(proposal_id, proposal);
+        multisig::write_proposals_count(proposal_id + 1);
+        events::emit(&Event::ProposalCreated {
+            proposal_id,
+            proposer,
+        });
+        Ok(proposal_id)
+    }
+
+    /// Approves the proposal `proposal_id` as the calling signer.
+    pub fn approve(&mut self, proposal_id: u64) -> Result<(), Error> {
+        let signer = self.only_signer()?;
+        let mut proposal = self.read_pending_proposal(proposal_id)?;
+        if !proposal.approvals.contains(&signer) {
+            proposal.approvals.push(signer);
+            self.write_proposal(proposal_id, proposal);
+            events::emit(&Event::ProposalApproved {
+                proposal_id,
+                signer,
+            });
+        }
+        Ok(())
+    }
+
+    /// Executes the action of the proposal `proposal_id`, once approved by as many current signers
+    /// as the threshold.
+    ///
+    /// When the timelock is installed, the action is queued with its minimum delay and only
+    /// applied by [`ERC1155::execute_operation`], and the id of the queued operation is returned.
+    /// Only signers can execute proposals, and every proposal is executed at most once.
+    pub fn execute(&mut self, proposal_id: u64) -> Result<Option<u64>, Error> {
+        self.only_signer()?;
+        let mut proposal = self.read_pending_proposal(proposal_id)?;
+        let signers = multisig::read_signers();
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|signer| signers.contains(signer))
+            .count();
+        if approvals < multisig::read_threshold() as usize {
+            return Err(Error::InsufficientApprovals);
+        }
+        let action = proposal.action.clone();
+        proposal.executed = true;
+        self.write_proposal(proposal_id, proposal);
+        let operation_id = if timelock::is_installed() {
+            let min_delay = timelock::read_min_delay();
+            Some(self.schedule_operation(Operation::Action(action), min_delay)?)
+        } else {
+            self.execute_action(action)?;
+            None
+        };
+        events::emit(&Event::ProposalExecuted { proposal_id });
+        Ok(operation_id)
+    }
+
+    /// Returns the minimum delay between queueing and executing a timelocked operation.
+    pub fn min_delay(&self) -> u64 {
+        timelock::read_min_delay()
+    }
+
+    /// Returns the timelocked operation identified by `operation_id`, if any.
+    pub fn queued_operation(&self, operation_id: u64) -> Option<QueuedOperation> {
+        self.read_operation(operation_id)
+    }
+
+    /// Returns the maximum total supply of `id`, if it is capped.
+    pub fn supply_cap(&self, id: &str) -> Option<U256> {
+        self.read_supply_cap(id)
+    }
+
+    /// Queues `operation` to be executed once `delay` elapsed, and returns the id of the queued
+    /// operation.
+    ///
+    /// Only the owner of the contract can queue operations, with a delay no shorter than the
+    /// minimum delay. Actions can only be queued by executing a multisig proposal.
+    pub fn queue_operation(&mut self, operation: Operation, delay: u64) -> Result<u64, Error> {
+        self.only_owner()?;
+        if let Operation::Action(_) = operation {
+            return Err(Error::PermissionDenied);
+        }
+        self.schedule_operation(operation, delay)
+    }
+
+    /// Cancels the queued operation `operation_id`.
+    ///
+    /// Actions approved by the multisig admin can be cancelled by any signer, and other operations
+    /// by the owner of the contract.
+    pub fn cancel_operation(&mut self, operation_id: u64) -> Result<(), Error> {
+        let mut queued_operation = self.read_pending_operation(operation_id)?;
+        self.only_operation_admin(&queued_operation.operation)?;
+        queued_operation.pending = false;
+        self.write_operation(operation_id, queued_operation);
+        events::emit(&Event::OperationCancelled { operation_id });
+        Ok(())
+    }
+
+    /// Executes the queued operation `operation_id`, once its eta is reached.
+    ///
+    /// Actions approved by the multisig admin can be executed by any signer, and other operations
+    /// by the owner of the contract. Every operation is executed at most once.
+    pub fn execute_operation(&mut self, operation_id: u64) -> Result<(), Error> {
+        let mut queued_operation = self.read_pending_operation(operation_id)?;
+        self.only_operation_admin(&queued_operation.operation)?;
+        if u64::from(runtime::get_blocktime()) < queued_operation.eta {
+            return Err(Error::OperationNotReady);
+        }
+        let operation = queued_operation.operation.clone();
+        queued_operation.pending = false;
+        self.write_operation(operation_id, queued_operation);
+        match operation {
+            Operation::Action(action) => self.execute_action(action)?,
+            Operation::SetMinDelay(min_delay) => timelock::write_min_delay(min_delay),
+            Operation::TransferOwnership(new_owner) => self.start_ownership_transfer(new_owner)?,
+            Operation::SetSupplyCap { id, cap } => self.write_supply_cap(&id, cap),
+        }
+        events::emit(&Event::OperationExecuted { operation_id });
+        Ok(())
+    }
+
     /// Installs the ERC1155 contract with a custom set of entry points.
     ///
     /// # Warning
@@ -254,32 +1942,209 @@ impl ERC1155 {
         contract_key_name: &str,
         entry_points: EntryPoints,
     ) -> Result<ERC1155, Error> {
-        let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
-        let operators_uref = storage::new_dictionary(OPERATORS_KEY_NAME).unwrap_or_revert();
-        let total_supply_uref = storage::new_dictionary(TOTAL_SUPPLY_KEY_NAME).unwrap_or_revert();
+        ERC1155::install_custom_with_config(uri, contract_key_name, entry_points, Config::default())
+    }
+
+    /// Installs the ERC1155 contract with a custom set of entry points, and the settings of
+    /// `config`.
+    ///
+    /// Extensions only get their named keys, and the sales extension its treasury purse, when at
+    /// least one of their entry points is part of `entry_points`.
+    ///
+    /// # Warning
+    ///
+    /// See [`ERC1155::install_custom`].
+    #[doc(hidden)]
+    pub fn install_custom_with_config(
+        uri: String,
+        contract_key_name: &str,
+        entry_points: EntryPoints,
+        config: Config,
+    ) -> Result<ERC1155, Error> {
+        let installer = Address::from(runtime::get_caller());
+        let signers = if config.signers.is_empty() {
+            vec![installer]
+        } else {
+            config.signers
+        };
+        let threshold = config.threshold.max(1);
+        if threshold as usize > signers.len() {
+            return Err(Error::InvalidThreshold);
+        }
+
+        // Extensions only get their storage if at least one of their entry points is installed.
+        let installs = |names: &[&str]| names.iter().any(|name| entry_points.has_entry_point(name));
 
         let mut named_keys = NamedKeys::new();
 
         let uri_key = {
-            let uri_uref = storage::new_uref(uri).into_read();
+            let uri_uref = storage::new_uref(uri);
             Key::from(uri_uref)
         };
-        let balances_dictionary_key = {
-            runtime::remove_key(BALANCES_KEY_NAME);
-            Key::from(balances_uref)
+        let owner_key = {
+            let owner_uref = storage::new_uref(Some(installer));
+            Key::from(owner_uref)
         };
-        let operators_dictionary_key = {
-            runtime::remove_key(OPERATORS_KEY_NAME);
-            Key::from(operators_uref)
+        let pending_owner_key = {
+            let pending_owner_uref = storage::new_uref(Option::<Address>::None);
+            Key::from(pending_owner_uref)
         };
-        let total_supply_key = {
-            runtime::remove_key(TOTAL_SUPPLY_KEY_NAME);
-            Key::from(total_supply_uref)
+        let caller_policy_key = {
+            let caller_policy_uref = storage::new_uref(config.caller_policy);
+            Key::from(caller_policy_uref)
         };
+        let balances_uref = new_dictionary(&mut named_keys, BALANCES_KEY_NAME);
+        let operators_uref = new_dictionary(&mut named_keys, OPERATORS_KEY_NAME);
+        let total_supply_uref = new_dictionary(&mut named_keys, TOTAL_SUPPLY_KEY_NAME);
         named_keys.insert(URI_KEY_NAME.to_string(), uri_key);
-        named_keys.insert(BALANCES_KEY_NAME.to_string(), balances_dictionary_key);
-        named_keys.insert(OPERATORS_KEY_NAME.to_string(), operators_dictionary_key);
-        named_keys.insert(TOTAL_SUPPLY_KEY_NAME.to_string(), total_supply_key);
+        named_keys.insert(OWNER_KEY_NAME.to_string(), owner_key);
+        named_keys.insert(PENDING_OWNER_KEY_NAME.to_string(), pending_owner_key);
+        named_keys.insert(CALLER_POLICY_KEY_NAME.to_string(), caller_policy_key);
+
+        if installs(&[SET_CLAIM_ROOT_ENTRY_POINT_NAME, CLAIM_ENTRY_POINT_NAME]) {
+            new_dictionary(&mut named_keys, CLAIM_ROOTS_KEY_NAME);
+            new_dictionary(&mut named_keys, CLAIMED_KEY_NAME);
+        }
+
+        if installs(&[
+            SET_SALE_ENTRY_POINT_NAME,
+            PURCHASE_ENTRY_POINT_NAME,
+            WITHDRAW_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, SALES_KEY_NAME);
+            new_dictionary(&mut named_keys, PURCHASES_KEY_NAME);
+            let treasury_key = {
+                let treasury_purse = system::create_purse();
+                Key::from(treasury_purse)
+            };
+            named_keys.insert(TREASURY_KEY_NAME.to_string(), treasury_key);
+        }
+
+        if installs(&[WRAP_ENTRY_POINT_NAME, UNWRAP_ENTRY_POINT_NAME]) {
+            new_dictionary(&mut named_keys, WRAPPED_TOKENS_KEY_NAME);
+        }
+
+        if installs(&[FRACTIONALIZE_ENTRY_POINT_NAME, REDEEM_ENTRY_POINT_NAME]) {
+            new_dictionary(&mut named_keys, FRACTIONS_KEY_NAME);
+            new_dictionary(&mut named_keys, NFT_LOCKS_KEY_NAME);
+        }
+
+        if installs(&[
+            CREATE_OFFER_ENTRY_POINT_NAME,
+            ACCEPT_OFFER_ENTRY_POINT_NAME,
+            CANCEL_OFFER_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, OFFERS_KEY_NAME);
+            let offers_count_key = {
+                let offers_count_uref = storage::new_uref(0u64);
+                Key::from(offers_count_uref)
+            };
+            named_keys.insert(OFFERS_COUNT_KEY_NAME.to_string(), offers_count_key);
+        }
+
+        let snapshot_id_uref = if installs(&[
+            SNAPSHOT_ENTRY_POINT_NAME,
+            BALANCE_OF_AT_ENTRY_POINT_NAME,
+            TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, BALANCE_SNAPSHOTS_KEY_NAME);
+            new_dictionary(&mut named_keys, TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME);
+            let snapshot_id_uref = storage::new_uref(0u64);
+            named_keys.insert(
+                SNAPSHOT_ID_KEY_NAME.to_string(),
+                Key::from(snapshot_id_uref),
+            );
+            Some(snapshot_id_uref)
+        } else {
+            None
+        };
+
+        if installs(&[
+            SET_USER_ENTRY_POINT_NAME,
+            REVOKE_USER_ENTRY_POINT_NAME,
+            USER_OF_ENTRY_POINT_NAME,
+            USABLE_BALANCE_OF_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, RENTALS_KEY_NAME);
+            new_dictionary(&mut named_keys, RENTED_FROM_KEY_NAME);
+        }
+
+        if installs(&[
+            MINT_WITH_EXPIRY_ENTRY_POINT_NAME,
+            SWEEP_EXPIRED_ENTRY_POINT_NAME,
+            EXPIRY_OF_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, EXPIRIES_KEY_NAME);
+        }
+
+        let (token_types_uref, nft_owners_uref) = if installs(&[
+            CREATE_ENTRY_POINT_NAME,
+            SET_MINTER_ENTRY_POINT_NAME,
+            CREATOR_OF_ENTRY_POINT_NAME,
+            EXISTS_ENTRY_POINT_NAME,
+            OWNER_OF_ENTRY_POINT_NAME,
+        ]) {
+            let token_types_uref = new_dictionary(&mut named_keys, TOKEN_TYPES_KEY_NAME);
+            new_dictionary(&mut named_keys, MINTERS_KEY_NAME);
+            let nft_owners_uref = new_dictionary(&mut named_keys, NFT_OWNERS_KEY_NAME);
+            let last_token_id_key = {
+                let last_token_id_uref = storage::new_uref(0u64);
+                Key::from(last_token_id_uref)
+            };
+            named_keys.insert(LAST_TOKEN_ID_KEY_NAME.to_string(), last_token_id_key);
+            (Some(token_types_uref), Some(nft_owners_uref))
+        } else {
+            (None, None)
+        };
+
+        if installs(&[
+            PROPOSE_ENTRY_POINT_NAME,
+            APPROVE_ENTRY_POINT_NAME,
+            EXECUTE_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, PROPOSALS_KEY_NAME);
+            let signers_key = {
+                let signers_uref = storage::new_uref(signers);
+                Key::from(signers_uref)
+            };
+            let threshold_key = {
+                let threshold_uref = storage::new_uref(threshold);
+                Key::from(threshold_uref)
+            };
+            let proposals_count_key = {
+                let proposals_count_uref = storage::new_uref(0u64);
+                Key::from(proposals_count_uref)
+            };
+            let paused_key = {
+                let paused_uref = storage::new_uref(false);
+                Key::from(paused_uref)
+            };
+            named_keys.insert(SIGNERS_KEY_NAME.to_string(), signers_key);
+            named_keys.insert(THRESHOLD_KEY_NAME.to_string(), threshold_key);
+            named_keys.insert(PROPOSALS_COUNT_KEY_NAME.to_string(), proposals_count_key);
+            named_keys.insert(PAUSED_KEY_NAME.to_string(), paused_key);
+        }
+
+        if installs(&[
+            QUEUE_OPERATION_ENTRY_POINT_NAME,
+            CANCEL_OPERATION_ENTRY_POINT_NAME,
+            EXECUTE_OPERATION_ENTRY_POINT_NAME,
+            MIN_DELAY_ENTRY_POINT_NAME,
+            SUPPLY_CAP_ENTRY_POINT_NAME,
+        ]) {
+            new_dictionary(&mut named_keys, OPERATIONS_KEY_NAME);
+            new_dictionary(&mut named_keys, SUPPLY_CAPS_KEY_NAME);
+            let operations_count_key = {
+                let operations_count_uref = storage::new_uref(0u64);
+                Key::from(operations_count_uref)
+            };
+            let min_delay_key = {
+                let min_delay_uref = storage::new_uref(config.min_delay);
+                Key::from(min_delay_uref)
+            };
+            named_keys.insert(OPERATIONS_COUNT_KEY_NAME.to_string(), operations_count_key);
+            named_keys.insert(MIN_DELAY_KEY_NAME.to_string(), min_delay_key);
+        }
 
         let (contract_hash, _version) =
             storage::new_locked_contract(entry_points, Some(named_keys), None, None);
@@ -291,6 +2156,41 @@ impl ERC1155 {
             balances_uref,
             operators_uref,
             total_supply_uref,
+            snapshot_id_uref,
+            token_types_uref,
+            nft_owners_uref,
         ))
     }
 }
+
+/// Creates the dictionary `name` and adds it to `named_keys`.
+///
+/// The dictionary is removed from the named keys of the installing account, as it belongs to the
+/// contract.
+/// Emits the transfer event of tokens moved by the escrow: a single transfer for a bundle of one
+/// id, and a batch transfer otherwise.
+fn emit_transfer(operator: Address, from: Address, to: Address, ids: &[String], amounts: &[U256]) {
+    match (ids, amounts) {
+        ([id], [amount]) => events::emit(&Event::TransferSingle {
+            operator,
+            from,
+            to,
+            id: id.clone(),
+            amount: *amount,
+        }),
+        _ => events::emit(&Event::TransferBatch {
+            operator,
+            from,
+            to,
+            ids: ids.to_vec(),
+            amounts: amounts.to_vec(),
+        }),
+    }
+}
+
+fn new_dictionary(named_keys: &mut NamedKeys, name: &str) -> URef {
+    let dictionary_uref = storage::new_dictionary(name).unwrap_or_revert();
+    runtime::remove_key(name);
+    named_keys.insert(name.to_string(), Key::from(dictionary_uref));
+    dictionary_uref
+}
@@ -0,0 +1,129 @@
+//! Implementation of the Merkle proofs used by airdrop claims.
+//!
+//! Leaves are the blake2b hash of the serialized `(Address, id, amount)` triple, and every inner
+//! node hashes its two children in sorted order, so proofs don't need to carry sibling positions.
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use alloc::{string::String, vec};
+
+use casper_types::{
+    bytesrepr::{self, ToBytes},
+    U256,
+};
+
+use crate::Address;
+
+/// Returns the leaf hash of `amount` tokens of `id` claimable by `account`.
+pub fn leaf_hash<H>(
+    account: Address,
+    id: &str,
+    amount: U256,
+    hash: H,
+) -> Result<[u8; 32], bytesrepr::Error>
+where
+    H: Fn(&[u8]) -> [u8; 32],
+{
+    let mut preimage = Vec::new();
+    preimage.append(&mut account.to_bytes()?);
+    preimage.append(&mut id.to_bytes()?);
+    preimage.append(&mut amount.to_bytes()?);
+    Ok(hash(&preimage))
+}
+
+/// Hashes two sibling nodes into their parent node.
+pub fn hash_pair<H>(left: [u8; 32], right: [u8; 32], hash: H) -> [u8; 32]
+where
+    H: Fn(&[u8]) -> [u8; 32],
+{
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(&first);
+    preimage[32..].copy_from_slice(&second);
+    hash(&preimage)
+}
+
+/// Returns true if `proof` links `leaf` to `root`.
+pub fn verify<H>(root: [u8; 32], leaf: [u8; 32], proof: &[[u8; 32]], hash: H) -> bool
+where
+    H: Fn(&[u8]) -> [u8; 32],
+{
+    let computed_root = proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(node, *sibling, &hash));
+    computed_root == root
+}
+
+/// Computes a blake2b-256 hash off-chain, matching the one computed by the contract runtime.
+#[cfg(feature = "std")]
+pub fn blake2b(bytes: &[u8]) -> [u8; 32] {
+    use blake2::{
+        digest::{Update, VariableOutput},
+        VarBlake2b,
+    };
+
+    let mut result = [0u8; 32];
+    let mut hasher = VarBlake2b::new(32).expect("32 is a valid blake2b output size");
+    hasher.update(bytes);
+    hasher.finalize_variable(|hash| result.copy_from_slice(hash));
+    result
+}
+
+/// A Merkle tree of claim leaves, used off-chain to compute campaign roots and proofs.
+#[cfg(feature = "std")]
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+#[cfg(feature = "std")]
+impl MerkleTree {
+    /// Builds a tree out of `(account, id, amount)` claims.
+    pub fn new(claims: &[(Address, String, U256)]) -> Result<Self, bytesrepr::Error> {
+        let leaves = claims
+            .iter()
+            .map(|(account, id, amount)| leaf_hash(*account, id, *amount, blake2b))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_leaves(leaves))
+    }
+
+    /// Builds a tree out of already hashed leaves.
+    pub fn from_leaves(leaves: Vec<[u8; 32]>) -> Self {
+        let mut layers = vec![leaves];
+        while let Some(layer) = layers.last().filter(|layer| layer.len() > 1) {
+            let next_layer = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(*left, *right, blake2b),
+                    // The last node of an odd layer is promoted as is.
+                    _ => pair[0],
+                })
+                .collect();
+            layers.push(next_layer);
+        }
+        Self { layers }
+    }
+
+    /// Returns the root of the tree, or `None` if the tree has no leaves.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.layers.last().and_then(|layer| layer.first()).copied()
+    }
+
+    /// Returns the proof of the leaf at `index`, or `None` if it is out of bounds.
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.layers[0].len() {
+            return None;
+        }
+        let mut proof = Vec::new();
+        let mut index = index;
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
@@ -0,0 +1,262 @@
+//! Implementation of the multi-signature admin.
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{
+        PROPOSALS_COUNT_KEY_NAME, PROPOSALS_KEY_NAME, SIGNERS_KEY_NAME, THRESHOLD_KEY_NAME,
+    },
+    detail, Address,
+};
+
+pub(crate) const ACTION_MINT_TAG: u8 = 0;
+pub(crate) const ACTION_BURN_TAG: u8 = 1;
+pub(crate) const ACTION_SET_SIGNER_TAG: u8 = 2;
+pub(crate) const ACTION_SET_THRESHOLD_TAG: u8 = 3;
+pub(crate) const ACTION_SET_PAUSED_TAG: u8 = 4;
+pub(crate) const ACTION_SET_URI_TAG: u8 = 5;
+
+/// A privileged action applied through the timelock once enough signers approved it.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Action {
+    /// Mints `amount` tokens of `id` to `to`.
+    Mint {
+        /// Recipient of the minted tokens.
+        to: Address,
+        /// Token id to mint.
+        id: String,
+        /// Amount of tokens to mint.
+        amount: U256,
+    },
+    /// Burns `amount` tokens of `id` from `owner`, which can't be the contract itself, nor leave less
+    /// than its rented balance.
+    Burn {
+        /// Owner of the burnt tokens.
+        owner: Address,
+        /// Token id to burn.
+        id: String,
+        /// Amount of tokens to burn.
+        amount: U256,
+    },
+    /// Adds `signer` to the signers if `approved`, or removes it otherwise.
+    SetSigner {
+        /// Signer to add or remove.
+        signer: Address,
+        /// Whether `signer` is added or removed.
+        approved: bool,
+    },
+    /// Changes the amount of approvals needed to execute a proposal.
+    SetThreshold(u32),
+    /// Pauses or unpauses token transfers.
+    SetPaused(bool),
+    /// Changes the URI of the token.
+    SetUri(String),
+}
+
+impl CLTyped for Action {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Action {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        match self {
+            Action::Mint { to, id, amount } => {
+                result.push(ACTION_MINT_TAG);
+                result.append(&mut to.to_bytes()?);
+                result.append(&mut id.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+            Action::Burn { owner, id, amount } => {
+                result.push(ACTION_BURN_TAG);
+                result.append(&mut owner.to_bytes()?);
+                result.append(&mut id.to_bytes()?);
+                result.append(&mut amount.to_bytes()?);
+            }
+            Action::SetSigner { signer, approved } => {
+                result.push(ACTION_SET_SIGNER_TAG);
+                result.append(&mut signer.to_bytes()?);
+                result.append(&mut approved.to_bytes()?);
+            }
+            Action::SetThreshold(threshold) => {
+                result.push(ACTION_SET_THRESHOLD_TAG);
+                result.append(&mut threshold.to_bytes()?);
+            }
+            Action::SetPaused(paused) => {
+                result.push(ACTION_SET_PAUSED_TAG);
+                result.append(&mut paused.to_bytes()?);
+            }
+            Action::SetUri(uri) => {
+                result.push(ACTION_SET_URI_TAG);
+                result.append(&mut uri.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            Action::Mint { to, id, amount } => {
+                to.serialized_length() + id.serialized_length() + amount.serialized_length()
+            }
+            Action::Burn { owner, id, amount } => {
+                owner.serialized_length() + id.serialized_length() + amount.serialized_length()
+            }
+            Action::SetSigner { signer, approved } => {
+                signer.serialized_length() + approved.serialized_length()
+            }
+            Action::SetThreshold(threshold) => threshold.serialized_length(),
+            Action::SetPaused(paused) => paused.serialized_length(),
+            Action::SetUri(uri) => uri.serialized_length(),
+        }
+    }
+}
+
+impl FromBytes for Action {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            ACTION_MINT_TAG => {
+                let (to, remainder) = Address::from_bytes(remainder)?;
+                let (id, remainder) = String::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((Action::Mint { to, id, amount }, remainder))
+            }
+            ACTION_BURN_TAG => {
+                let (owner, remainder) = Address::from_bytes(remainder)?;
+                let (id, remainder) = String::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((Action::Burn { owner, id, amount }, remainder))
+            }
+            ACTION_SET_SIGNER_TAG => {
+                let (signer, remainder) = Address::from_bytes(remainder)?;
+                let (approved, remainder) = bool::from_bytes(remainder)?;
+                Ok((Action::SetSigner { signer, approved }, remainder))
+            }
+            ACTION_SET_THRESHOLD_TAG => {
+                let (threshold, remainder) = u32::from_bytes(remainder)?;
+                Ok((Action::SetThreshold(threshold), remainder))
+            }
+            ACTION_SET_PAUSED_TAG => {
+                let (paused, remainder) = bool::from_bytes(remainder)?;
+                Ok((Action::SetPaused(paused), remainder))
+            }
+            ACTION_SET_URI_TAG => {
+                let (uri, remainder) = String::from_bytes(remainder)?;
+                Ok((Action::SetUri(uri), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// A proposal to execute an [`Action`], pending the approval of the signers.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Proposal {
+    /// Action executed by the proposal.
+    pub action: Action,
+    /// Signer which created the proposal.
+    pub proposer: Address,
+    /// Signers which approved the proposal.
+    pub approvals: Vec<Address>,
+    /// Blocktime from which the proposal can no longer be approved or executed.
+    pub expiry: u64,
+    /// Whether the proposal was already executed.
+    pub executed: bool,
+}
+
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.action.to_bytes()?);
+        result.append(&mut self.proposer.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        result.append(&mut self.expiry.to_bytes()?);
+        result.append(&mut self.executed.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.action.serialized_length()
+            + self.proposer.serialized_length()
+            + self.approvals.serialized_length()
+            + self.expiry.serialized_length()
+            + self.executed.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (action, remainder) = Action::from_bytes(bytes)?;
+        let (proposer, remainder) = Address::from_bytes(remainder)?;
+        let (approvals, remainder) = Vec::<Address>::from_bytes(remainder)?;
+        let (expiry, remainder) = u64::from_bytes(remainder)?;
+        let (executed, remainder) = bool::from_bytes(remainder)?;
+        let proposal = Proposal {
+            action,
+            proposer,
+            approvals,
+            expiry,
+            executed,
+        };
+        Ok((proposal, remainder))
+    }
+}
+
+/// Get Proposals uref of contract context.
+pub(crate) fn proposals_uref() -> URef {
+    detail::get_uref(PROPOSALS_KEY_NAME)
+}
+
+/// Reads a proposal from a dictionary.
+pub(crate) fn read_proposal_from(proposals_uref: URef, proposal_id: u64) -> Option<Proposal> {
+    storage::dictionary_get(proposals_uref, &proposal_id.to_string()).unwrap_or_revert()
+}
+
+/// Writes a proposal into a dictionary.
+pub(crate) fn write_proposal_to(proposals_uref: URef, proposal_id: u64, proposal: Proposal) {
+    storage::dictionary_put(proposals_uref, &proposal_id.to_string(), proposal);
+}
+
+/// Reads the amount of proposals created so far.
+pub(crate) fn read_proposals_count() -> u64 {
+    detail::read_from(PROPOSALS_COUNT_KEY_NAME)
+}
+
+/// Writes the amount of proposals created so far.
+pub(crate) fn write_proposals_count(proposals_count: u64) {
+    storage::write(detail::get_uref(PROPOSALS_COUNT_KEY_NAME), proposals_count);
+}
+
+/// Reads the signers of the multisig admin.
+pub(crate) fn read_signers() -> Vec<Address> {
+    detail::read_from(SIGNERS_KEY_NAME)
+}
+
+/// Writes the signers of the multisig admin.
+pub(crate) fn write_signers(signers: Vec<Address>) {
+    storage::write(detail::get_uref(SIGNERS_KEY_NAME), signers);
+}
+
+/// Reads the amount of approvals needed to execute a proposal.
+pub(crate) fn read_threshold() -> u32 {
+    detail::read_from(THRESHOLD_KEY_NAME)
+}
+
+/// Writes the amount of approvals needed to execute a proposal.
+pub(crate) fn write_threshold(threshold: u32) {
+    storage::write(detail::get_uref(THRESHOLD_KEY_NAME), threshold);
+}
@@ -0,0 +1,27 @@
+//! Implementation of the contract owner.
+use casper_contract::contract_api::storage;
+
+use crate::{
+    constants::{OWNER_KEY_NAME, PENDING_OWNER_KEY_NAME},
+    detail, Address,
+};
+
+/// Reads the owner of the contract, or `None` if ownership was renounced.
+pub(crate) fn read_owner() -> Option<Address> {
+    detail::read_from(OWNER_KEY_NAME)
+}
+
+/// Writes the owner of the contract.
+pub(crate) fn write_owner(owner: Option<Address>) {
+    storage::write(detail::get_uref(OWNER_KEY_NAME), owner);
+}
+
+/// Reads the address which can accept the ownership of the contract, if any.
+pub(crate) fn read_pending_owner() -> Option<Address> {
+    detail::read_from(PENDING_OWNER_KEY_NAME)
+}
+
+/// Writes the address which can accept the ownership of the contract.
+pub(crate) fn write_pending_owner(pending_owner: Option<Address>) {
+    storage::write(detail::get_uref(PENDING_OWNER_KEY_NAME), pending_owner);
+}
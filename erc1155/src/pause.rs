@@ -0,0 +1,16 @@
+//! Implementation of the pause of token transfers.
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+
+use crate::{constants::PAUSED_KEY_NAME, detail};
+
+/// Reads whether token transfers are paused, which they never are without the multisig admin.
+pub(crate) fn read_paused() -> bool {
+    detail::get_optional_uref(PAUSED_KEY_NAME).map_or(false, |uref| {
+        storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+    })
+}
+
+/// Writes whether token transfers are paused.
+pub(crate) fn write_paused(paused: bool) {
+    storage::write(detail::get_uref(PAUSED_KEY_NAME), paused);
+}
@@ -0,0 +1,175 @@
+//! Implementation of the registry of token types.
+use alloc::vec::Vec;
+use alloc::{format, string::String};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{
+        LAST_TOKEN_ID_KEY_NAME, MINTERS_KEY_NAME, NFT_OWNERS_KEY_NAME, TOKEN_TYPES_KEY_NAME,
+    },
+    detail, Address,
+};
+
+/// Separator between the base id and the index of a non-fungible item id.
+const NFT_INDEX_SEPARATOR: char = '#';
+
+/// A token type registered by its creator.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct TokenType {
+    /// Address which created the token type, and can mint more tokens of it.
+    pub creator: Address,
+    /// URI of the token type metadata.
+    pub uri: String,
+    /// Whether tokens of the type are fungible.
+    pub fungible: bool,
+}
+
+impl CLTyped for TokenType {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TokenType {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.creator.to_bytes()?);
+        result.append(&mut self.uri.to_bytes()?);
+        result.append(&mut self.fungible.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.creator.serialized_length()
+            + self.uri.serialized_length()
+            + self.fungible.serialized_length()
+    }
+}
+
+impl FromBytes for TokenType {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (creator, remainder) = Address::from_bytes(bytes)?;
+        let (uri, remainder) = String::from_bytes(remainder)?;
+        let (fungible, remainder) = bool::from_bytes(remainder)?;
+        let token_type = TokenType {
+            creator,
+            uri,
+            fungible,
+        };
+        Ok((token_type, remainder))
+    }
+}
+
+/// Creates a dictionary item key for a token type.
+#[inline]
+fn make_token_type_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Creates a dictionary item key for a minter of a token type.
+#[inline]
+fn make_minter_item_key(id: &str, minter: Address) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut id.to_bytes().unwrap_or_revert());
+    preimage.append(&mut minter.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    base64::encode(&key_bytes)
+}
+
+/// Get token types uref of contract context, if the extension is installed.
+pub(crate) fn token_types_uref() -> Option<URef> {
+    detail::get_optional_uref(TOKEN_TYPES_KEY_NAME)
+}
+
+/// Get minters uref of contract context.
+pub(crate) fn minters_uref() -> URef {
+    detail::get_uref(MINTERS_KEY_NAME)
+}
+
+/// Reads the type of a token id from a dictionary.
+pub(crate) fn read_token_type_from(token_types_uref: URef, id: &str) -> Option<TokenType> {
+    let dictionary_item_key = make_token_type_item_key(id);
+    storage::dictionary_get(token_types_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the type of a token id into a dictionary.
+pub(crate) fn write_token_type_to(token_types_uref: URef, id: &str, token_type: TokenType) {
+    let dictionary_item_key = make_token_type_item_key(id);
+    storage::dictionary_put(token_types_uref, &dictionary_item_key, token_type);
+}
+
+/// Reads whether a minter was delegated the minting of a token id.
+pub(crate) fn read_minter_from(minters_uref: URef, id: &str, minter: Address) -> bool {
+    let dictionary_item_key = make_minter_item_key(id, minter);
+    storage::dictionary_get(minters_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes whether a minter was delegated the minting of a token id.
+pub(crate) fn write_minter_to(minters_uref: URef, id: &str, minter: Address, approved: bool) {
+    let dictionary_item_key = make_minter_item_key(id, minter);
+    storage::dictionary_put(minters_uref, &dictionary_item_key, approved);
+}
+
+/// Reads the last numeric id assigned to a token type.
+pub(crate) fn read_last_token_id() -> u64 {
+    detail::read_from(LAST_TOKEN_ID_KEY_NAME)
+}
+
+/// Writes the last numeric id assigned to a token type.
+pub(crate) fn write_last_token_id(last_token_id: u64) {
+    storage::write(detail::get_uref(LAST_TOKEN_ID_KEY_NAME), last_token_id);
+}
+
+/// Returns the id of the non-fungible item `index` of the token type `base`.
+///
+/// Items of a non-fungible type use `#` to separate the base id from the index, e.g. `sword#42`.
+pub fn nft_token_id(base: &str, index: U256) -> String {
+    format!("{}{}{}", base, NFT_INDEX_SEPARATOR, index)
+}
+
+/// Returns the base type of `id`, which is `id` itself unless it is an item id built by
+/// [`nft_token_id`].
+pub fn base_token_id(id: &str) -> &str {
+    id.split(NFT_INDEX_SEPARATOR).next().unwrap_or(id)
+}
+
+/// Returns true if `id` is an item id built by [`nft_token_id`], which can't be created as a token
+/// type of its own.
+pub(crate) fn is_item_token_id(id: &str) -> bool {
+    id.contains(NFT_INDEX_SEPARATOR)
+}
+
+/// Creates a dictionary item key for the owner of a non-fungible token id.
+#[inline]
+fn make_nft_owner_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Get NFT owners uref of contract context.
+pub(crate) fn nft_owners_uref() -> URef {
+    detail::get_uref(NFT_OWNERS_KEY_NAME)
+}
+
+/// Reads the last recorded owner of a non-fungible token id.
+pub(crate) fn read_nft_owner_from(nft_owners_uref: URef, id: &str) -> Option<Address> {
+    let dictionary_item_key = make_nft_owner_item_key(id);
+    storage::dictionary_get(nft_owners_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the owner of a non-fungible token id.
+pub(crate) fn write_nft_owner_to(nft_owners_uref: URef, id: &str, owner: Address) {
+    let dictionary_item_key = make_nft_owner_item_key(id);
+    storage::dictionary_put(nft_owners_uref, &dictionary_item_key, owner);
+}
@@ -0,0 +1,126 @@
+//! Implementation of rentals, granting temporary user rights on token balances.
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{RENTALS_KEY_NAME, RENTED_FROM_KEY_NAME},
+    detail, Address,
+};
+
+/// Temporary user rights granted by the owner of a balance.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Rental {
+    /// Address allowed to use the rented tokens.
+    pub user: Address,
+    /// Amount of tokens rented out, and locked in the balance of the owner.
+    pub amount: U256,
+    /// Blocktime at which the rights of the user end.
+    pub expires: u64,
+}
+
+impl Rental {
+    /// Returns true if the user still holds the rights at `blocktime`.
+    pub fn is_active(&self, blocktime: u64) -> bool {
+        blocktime < self.expires
+    }
+}
+
+impl CLTyped for Rental {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Rental {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.user.to_bytes()?);
+        result.append(&mut self.amount.to_bytes()?);
+        result.append(&mut self.expires.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.user.serialized_length()
+            + self.amount.serialized_length()
+            + self.expires.serialized_length()
+    }
+}
+
+impl FromBytes for Rental {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (user, remainder) = Address::from_bytes(bytes)?;
+        let (amount, remainder) = U256::from_bytes(remainder)?;
+        let (expires, remainder) = u64::from_bytes(remainder)?;
+        let rental = Rental {
+            user,
+            amount,
+            expires,
+        };
+        Ok((rental, remainder))
+    }
+}
+
+/// Creates a dictionary item key for a token id held by an account.
+#[inline]
+fn make_dictionary_item_key(account: Address, id: &str) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut id.to_bytes().unwrap_or_revert());
+    preimage.append(&mut account.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    base64::encode(&key_bytes)
+}
+
+/// Get rentals uref of contract context, if the extension is installed.
+pub(crate) fn rentals_uref() -> Option<URef> {
+    detail::get_optional_uref(RENTALS_KEY_NAME)
+}
+
+/// Get rented from uref of contract context.
+pub(crate) fn rented_from_uref() -> URef {
+    detail::get_uref(RENTED_FROM_KEY_NAME)
+}
+
+/// Reads the rental of a token id granted by an owner.
+pub(crate) fn read_rental_from(rentals_uref: URef, owner: Address, id: &str) -> Option<Rental> {
+    let dictionary_item_key = make_dictionary_item_key(owner, id);
+    storage::dictionary_get(rentals_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the rental of a token id granted by an owner.
+pub(crate) fn write_rental_to(rentals_uref: URef, owner: Address, id: &str, rental: Rental) {
+    let dictionary_item_key = make_dictionary_item_key(owner, id);
+    storage::dictionary_put(rentals_uref, &dictionary_item_key, rental);
+}
+
+/// Reads the owners which rented a token id to a user.
+pub(crate) fn read_rented_from_from(
+    rented_from_uref: URef,
+    user: Address,
+    id: &str,
+) -> Vec<Address> {
+    let dictionary_item_key = make_dictionary_item_key(user, id);
+    storage::dictionary_get(rented_from_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the owners which rented a token id to a user.
+pub(crate) fn write_rented_from_to(
+    rented_from_uref: URef,
+    user: Address,
+    id: &str,
+    owners: Vec<Address>,
+) {
+    let dictionary_item_key = make_dictionary_item_key(user, id);
+    storage::dictionary_put(rented_from_uref, &dictionary_item_key, owners);
+}
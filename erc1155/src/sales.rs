@@ -0,0 +1,161 @@
+//! Implementation of primary sales.
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256, U512,
+};
+
+use crate::{
+    constants::{PURCHASES_KEY_NAME, SALES_KEY_NAME, TREASURY_KEY_NAME},
+    detail, Address,
+};
+
+/// Primary sale configuration of a token id.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Sale {
+    /// Price of a single token in motes.
+    pub price: U512,
+    /// Blocktime from which the sale is open.
+    pub start_time: u64,
+    /// Blocktime at which the sale closes.
+    pub end_time: u64,
+    /// Maximum amount of tokens a single wallet can purchase, or zero for no limit.
+    pub wallet_limit: U256,
+    /// Amount of tokens put on sale.
+    pub supply: U256,
+    /// Amount of tokens sold so far.
+    pub sold: U256,
+    /// Address which configured the sale, and must still be able to mint the tokens it sells.
+    pub seller: Address,
+}
+
+impl Sale {
+    /// Returns true if the sale is open at `blocktime`.
+    pub fn is_open(&self, blocktime: u64) -> bool {
+        self.start_time <= blocktime && blocktime < self.end_time
+    }
+
+    /// Returns the price in motes of `amount` tokens.
+    pub fn cost(&self, amount: U256) -> Option<U512> {
+        let mut amount_bytes = [0u8; 32];
+        amount.to_little_endian(&mut amount_bytes);
+        self.price
+            .checked_mul(U512::from_little_endian(&amount_bytes))
+    }
+}
+
+impl CLTyped for Sale {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Sale {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.price.to_bytes()?);
+        result.append(&mut self.start_time.to_bytes()?);
+        result.append(&mut self.end_time.to_bytes()?);
+        result.append(&mut self.wallet_limit.to_bytes()?);
+        result.append(&mut self.supply.to_bytes()?);
+        result.append(&mut self.sold.to_bytes()?);
+        result.append(&mut self.seller.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.price.serialized_length()
+            + self.start_time.serialized_length()
+            + self.end_time.serialized_length()
+            + self.wallet_limit.serialized_length()
+            + self.supply.serialized_length()
+            + self.sold.serialized_length()
+            + self.seller.serialized_length()
+    }
+}
+
+impl FromBytes for Sale {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (price, remainder) = U512::from_bytes(bytes)?;
+        let (start_time, remainder) = u64::from_bytes(remainder)?;
+        let (end_time, remainder) = u64::from_bytes(remainder)?;
+        let (wallet_limit, remainder) = U256::from_bytes(remainder)?;
+        let (supply, remainder) = U256::from_bytes(remainder)?;
+        let (sold, remainder) = U256::from_bytes(remainder)?;
+        let (seller, remainder) = Address::from_bytes(remainder)?;
+        let sale = Sale {
+            price,
+            start_time,
+            end_time,
+            wallet_limit,
+            supply,
+            sold,
+            seller,
+        };
+        Ok((sale, remainder))
+    }
+}
+
+/// Creates a dictionary item key for the sale of a token id.
+#[inline]
+fn make_sale_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Creates a dictionary item key for the purchases of an account.
+#[inline]
+fn make_purchase_item_key(id: &str, account: Address) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut id.to_bytes().unwrap_or_revert());
+    preimage.append(&mut account.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    base64::encode(&key_bytes)
+}
+
+/// Get Sales uref of contract context.
+pub(crate) fn sales_uref() -> URef {
+    detail::get_uref(SALES_KEY_NAME)
+}
+
+/// Get Purchases uref of contract context.
+pub(crate) fn purchases_uref() -> URef {
+    detail::get_uref(PURCHASES_KEY_NAME)
+}
+
+/// Get the treasury purse of contract context.
+pub(crate) fn treasury_purse() -> URef {
+    detail::get_uref(TREASURY_KEY_NAME)
+}
+
+/// Reads the sale of a token id from a dictionary.
+pub(crate) fn read_sale_from(sales_uref: URef, id: &str) -> Option<Sale> {
+    let dictionary_item_key = make_sale_item_key(id);
+    storage::dictionary_get(sales_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the sale of a token id into a dictionary.
+pub(crate) fn write_sale_to(sales_uref: URef, id: &str, sale: Sale) {
+    let dictionary_item_key = make_sale_item_key(id);
+    storage::dictionary_put(sales_uref, &dictionary_item_key, sale);
+}
+
+/// Reads the amount of a token id purchased by an account.
+pub(crate) fn read_purchased_from(purchases_uref: URef, account: Address, id: &str) -> U256 {
+    let dictionary_item_key = make_purchase_item_key(id, account);
+    storage::dictionary_get(purchases_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the amount of a token id purchased by an account.
+pub(crate) fn write_purchased_to(purchases_uref: URef, account: Address, id: &str, amount: U256) {
+    let dictionary_item_key = make_purchase_item_key(id, account);
+    storage::dictionary_put(purchases_uref, &dictionary_item_key, amount);
+}
@@ -0,0 +1,271 @@
+//! Implementation of a JSON schema of the contract entry points, for off-chain clients.
+//!
+//! The schema lists every entry point with its parameters, return type, access and type, using the
+//! JSON representation of [`CLType`] from `casper-types`. It is built from an [`EntryPoints`] set,
+//! so it can describe [`entry_points::default`](crate::entry_points::default), the default set
+//! extended with any extension, or a custom set passed to `install_custom`.
+//!
+//! Runtime arguments of type `Any` name their encoding, which is listed with the entry points.
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+use casper_types::{CLType, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{ACTION_RUNTIME_ARG_NAME, OPERATION_RUNTIME_ARG_NAME},
+    multisig::{
+        ACTION_BURN_TAG, ACTION_MINT_TAG, ACTION_SET_PAUSED_TAG, ACTION_SET_SIGNER_TAG,
+        ACTION_SET_THRESHOLD_TAG, ACTION_SET_URI_TAG,
+    },
+    timelock::{
+        OPERATION_ACTION_TAG, OPERATION_SET_MIN_DELAY_TAG, OPERATION_SET_SUPPLY_CAP_TAG,
+        OPERATION_TRANSFER_OWNERSHIP_TAG,
+    },
+};
+
+const ACTION_TYPE_NAME: &str = "Action";
+const OPERATION_TYPE_NAME: &str = "Operation";
+
+/// Schema of a set of entry points.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct Schema {
+    /// Entry points of the set, sorted by name.
+    pub entry_points: Vec<EntryPointSchema>,
+    /// Encodings of the runtime arguments of type `Any` of the set, sorted by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<TypeSchema>,
+}
+
+/// Schema of a single entry point.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct EntryPointSchema {
+    /// Name of the entry point.
+    pub name: String,
+    /// Runtime arguments of the entry point, in declaration order.
+    pub parameters: Vec<ParameterSchema>,
+    /// Type of the value returned by the entry point.
+    pub ret: CLType,
+    /// Access of the entry point.
+    pub access: EntryPointAccess,
+    /// Whether the entry point runs in the context of the contract or of the caller.
+    pub entry_point_type: EntryPointType,
+}
+
+/// Schema of a runtime argument of an entry point.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct ParameterSchema {
+    /// Name of the runtime argument.
+    pub name: String,
+    /// Type of the runtime argument.
+    pub cl_type: CLType,
+    /// Name of the encoding of the runtime argument in [`Schema::types`], if its type is `Any`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// Schema of a type passed as `Any`, serialized as the tag of a variant followed by the fields of
+/// the variant, in order.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct TypeSchema {
+    /// Name of the type.
+    pub name: String,
+    /// Variants of the type, sorted by tag.
+    pub variants: Vec<VariantSchema>,
+}
+
+/// Schema of a variant of a type passed as `Any`.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct VariantSchema {
+    /// Byte preceding the fields of the variant.
+    pub tag: u8,
+    /// Name of the variant.
+    pub name: String,
+    /// Fields of the variant, in serialization order.
+    pub fields: Vec<ParameterSchema>,
+}
+
+impl ParameterSchema {
+    fn new(name: &str, cl_type: CLType) -> Self {
+        let encoding = encoding(name, &cl_type).map(String::from);
+        ParameterSchema {
+            name: name.into(),
+            cl_type,
+            encoding,
+        }
+    }
+}
+
+impl VariantSchema {
+    fn new(tag: u8, name: &str, fields: Vec<ParameterSchema>) -> Self {
+        VariantSchema {
+            tag,
+            name: name.into(),
+            fields,
+        }
+    }
+}
+
+/// Returns the name of the encoding of the runtime argument `name` of type `cl_type`, if it is
+/// passed as `Any`.
+pub fn encoding(name: &str, cl_type: &CLType) -> Option<&'static str> {
+    match (cl_type, name) {
+        (CLType::Any, ACTION_RUNTIME_ARG_NAME) => Some(ACTION_TYPE_NAME),
+        (CLType::Any, OPERATION_RUNTIME_ARG_NAME) => Some(OPERATION_TYPE_NAME),
+        _ => None,
+    }
+}
+
+/// Returns the schema of the type `name` passed as `Any`, if known.
+pub fn type_schema(name: &str) -> Option<TypeSchema> {
+    let field = ParameterSchema::new;
+    let variants = match name {
+        ACTION_TYPE_NAME => vec![
+            VariantSchema::new(
+                ACTION_MINT_TAG,
+                "Mint",
+                vec![
+                    field("to", CLType::Key),
+                    field("id", CLType::String),
+                    field("amount", CLType::U256),
+                ],
+            ),
+            VariantSchema::new(
+                ACTION_BURN_TAG,
+                "Burn",
+                vec![
+                    field("owner", CLType::Key),
+                    field("id", CLType::String),
+                    field("amount", CLType::U256),
+                ],
+            ),
+            VariantSchema::new(
+                ACTION_SET_SIGNER_TAG,
+                "SetSigner",
+                vec![
+                    field("signer", CLType::Key),
+                    field("approved", CLType::Bool),
+                ],
+            ),
+            VariantSchema::new(
+                ACTION_SET_THRESHOLD_TAG,
+                "SetThreshold",
+                vec![field("threshold", CLType::U32)],
+            ),
+            VariantSchema::new(
+                ACTION_SET_PAUSED_TAG,
+                "SetPaused",
+                vec![field("paused", CLType::Bool)],
+            ),
+            VariantSchema::new(
+                ACTION_SET_URI_TAG,
+                "SetUri",
+                vec![field("uri", CLType::String)],
+            ),
+        ],
+        OPERATION_TYPE_NAME => vec![
+            VariantSchema::new(
+                OPERATION_ACTION_TAG,
+                "Action",
+                vec![field(ACTION_RUNTIME_ARG_NAME, CLType::Any)],
+            ),
+            VariantSchema::new(
+                OPERATION_SET_MIN_DELAY_TAG,
+                "SetMinDelay",
+                vec![field("min_delay", CLType::U64)],
+            ),
+            VariantSchema::new(
+                OPERATION_TRANSFER_OWNERSHIP_TAG,
+                "TransferOwnership",
+                vec![field("new_owner", CLType::Key)],
+            ),
+            VariantSchema::new(
+                OPERATION_SET_SUPPLY_CAP_TAG,
+                "SetSupplyCap",
+                vec![
+                    field("id", CLType::String),
+                    field("cap", CLType::Option(Box::new(CLType::U256))),
+                ],
+            ),
+        ],
+        _ => return None,
+    };
+    Some(TypeSchema {
+        name: name.into(),
+        variants,
+    })
+}
+
+/// Adds the schema of the encoding of every parameter in `parameters` to `types`, including the
+/// encodings of their fields.
+fn collect_types(parameters: &[ParameterSchema], types: &mut Vec<TypeSchema>) {
+    for encoding in parameters
+        .iter()
+        .filter_map(|parameter| parameter.encoding.as_ref())
+    {
+        if types.iter().any(|known| &known.name == encoding) {
+            continue;
+        }
+        if let Some(type_schema) = type_schema(encoding) {
+            types.push(type_schema.clone());
+            for variant in &type_schema.variants {
+                collect_types(&variant.fields, types);
+            }
+        }
+    }
+}
+
+impl From<&EntryPoint> for EntryPointSchema {
+    fn from(entry_point: &EntryPoint) -> Self {
+        EntryPointSchema {
+            name: entry_point.name().into(),
+            parameters: entry_point
+                .args()
+                .iter()
+                .map(|parameter| {
+                    ParameterSchema::new(parameter.name(), parameter.cl_type().clone())
+                })
+                .collect(),
+            ret: entry_point.ret().clone(),
+            access: entry_point.access().clone(),
+            entry_point_type: entry_point.entry_point_type(),
+        }
+    }
+}
+
+impl From<&EntryPoints> for Schema {
+    fn from(entry_points: &EntryPoints) -> Self {
+        let mut entry_points: Vec<EntryPointSchema> = entry_points
+            .clone()
+            .take_entry_points()
+            .iter()
+            .map(EntryPointSchema::from)
+            .collect();
+        entry_points.sort_by(|left, right| left.name.cmp(&right.name));
+        let mut types = Vec::new();
+        for entry_point in &entry_points {
+            collect_types(&entry_point.parameters, &mut types);
+        }
+        types.sort_by(|left, right| left.name.cmp(&right.name));
+        Schema {
+            entry_points,
+            types,
+        }
+    }
+}
+
+impl Schema {
+    /// Returns the schema as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("schema should serialize to JSON")
+    }
+
+    /// Parses a schema from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Returns the schema of `entry_points` as pretty-printed JSON.
+pub fn to_json(entry_points: &EntryPoints) -> String {
+    Schema::from(entry_points).to_json()
+}
@@ -0,0 +1,175 @@
+//! Implementation of balance and total supply snapshots.
+//!
+//! Values are checkpointed lazily: the first write of a balance or total supply after a snapshot
+//! records the value it held when the snapshot was taken. Every checkpoint is stored under its own
+//! dictionary item, next to a counter of the checkpoints recorded for that value, so lookups can
+//! binary search them.
+use alloc::string::String;
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, URef, U256};
+
+use crate::{
+    constants::{
+        BALANCE_SNAPSHOTS_KEY_NAME, SNAPSHOT_ID_KEY_NAME, TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+    },
+    detail, Address,
+};
+
+/// Creates the preimage identifying the checkpoints of a balance.
+#[inline]
+fn make_balance_preimage(account: Address, id: &str) -> Vec<u8> {
+    let mut preimage = Vec::new();
+    preimage.append(&mut id.to_bytes().unwrap_or_revert());
+    preimage.append(&mut account.to_bytes().unwrap_or_revert());
+    preimage
+}
+
+/// Creates the preimage identifying the checkpoints of a total supply.
+#[inline]
+fn make_total_supply_preimage(id: &str) -> Vec<u8> {
+    id.to_bytes().unwrap_or_revert()
+}
+
+/// Creates a dictionary item key for the amount of checkpoints of a value.
+#[inline]
+fn make_count_item_key(preimage: &[u8]) -> String {
+    let key_bytes = runtime::blake2b(preimage);
+    base64::encode(&key_bytes)
+}
+
+/// Creates a dictionary item key for a single checkpoint of a value.
+#[inline]
+fn make_checkpoint_item_key(preimage: &[u8], index: u64) -> String {
+    let mut preimage = preimage.to_vec();
+    preimage.append(&mut index.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    base64::encode(&key_bytes)
+}
+
+fn read_count(uref: URef, preimage: &[u8]) -> u64 {
+    let dictionary_item_key = make_count_item_key(preimage);
+    storage::dictionary_get(uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn read_checkpoint(uref: URef, preimage: &[u8], index: u64) -> (u64, U256) {
+    let dictionary_item_key = make_checkpoint_item_key(preimage, index);
+    storage::dictionary_get(uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Records `value` as the value at `snapshot_id`, unless a checkpoint already exists for it.
+fn record_checkpoint(uref: URef, preimage: &[u8], snapshot_id: u64, value: U256) {
+    let count = read_count(uref, preimage);
+    if count > 0 && read_checkpoint(uref, preimage, count - 1).0 >= snapshot_id {
+        return;
+    }
+    let checkpoint_item_key = make_checkpoint_item_key(preimage, count);
+    storage::dictionary_put(uref, &checkpoint_item_key, (snapshot_id, value));
+    let count_item_key = make_count_item_key(preimage);
+    storage::dictionary_put(uref, &count_item_key, count + 1);
+}
+
+/// Returns the value of the first checkpoint recorded at or after `snapshot_id`.
+///
+/// `None` means the value did not change since the snapshot was taken.
+fn find_checkpoint(uref: URef, preimage: &[u8], snapshot_id: u64) -> Option<U256> {
+    let count = read_count(uref, preimage);
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if read_checkpoint(uref, preimage, middle).0 < snapshot_id {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    if low == count {
+        None
+    } else {
+        Some(read_checkpoint(uref, preimage, low).1)
+    }
+}
+
+/// Get the snapshot id uref of contract context, if the extension is installed.
+pub(crate) fn snapshot_id_uref() -> Option<URef> {
+    detail::get_optional_uref(SNAPSHOT_ID_KEY_NAME)
+}
+
+/// Get balance snapshots uref of contract context.
+pub(crate) fn balance_snapshots_uref() -> URef {
+    detail::get_uref(BALANCE_SNAPSHOTS_KEY_NAME)
+}
+
+/// Get total supply snapshots uref of contract context.
+pub(crate) fn total_supply_snapshots_uref() -> URef {
+    detail::get_uref(TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME)
+}
+
+/// Reads the id of the latest snapshot, or zero if no snapshot was taken.
+pub(crate) fn read_snapshot_id_from(snapshot_id_uref: URef) -> u64 {
+    storage::read(snapshot_id_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Writes the id of the latest snapshot.
+pub(crate) fn write_snapshot_id_to(snapshot_id_uref: URef, snapshot_id: u64) {
+    storage::write(snapshot_id_uref, snapshot_id);
+}
+
+/// Records the balance of an account at a snapshot, before it gets overwritten.
+pub(crate) fn record_balance_to(
+    balance_snapshots_uref: URef,
+    account: Address,
+    id: &str,
+    snapshot_id: u64,
+    balance: U256,
+) {
+    let preimage = make_balance_preimage(account, id);
+    record_checkpoint(balance_snapshots_uref, &preimage, snapshot_id, balance);
+}
+
+/// Reads the balance of an account at a snapshot, or `None` if it did not change since then.
+pub(crate) fn read_balance_at_from(
+    balance_snapshots_uref: URef,
+    account: Address,
+    id: &str,
+    snapshot_id: u64,
+) -> Option<U256> {
+    let preimage = make_balance_preimage(account, id);
+    find_checkpoint(balance_snapshots_uref, &preimage, snapshot_id)
+}
+
+/// Records the total supply of a token id at a snapshot, before it gets overwritten.
+pub(crate) fn record_total_supply_to(
+    total_supply_snapshots_uref: URef,
+    id: &str,
+    snapshot_id: u64,
+    total_supply: U256,
+) {
+    let preimage = make_total_supply_preimage(id);
+    record_checkpoint(
+        total_supply_snapshots_uref,
+        &preimage,
+        snapshot_id,
+        total_supply,
+    );
+}
+
+/// Reads the total supply of a token id at a snapshot, or `None` if it did not change since then.
+pub(crate) fn read_total_supply_at_from(
+    total_supply_snapshots_uref: URef,
+    id: &str,
+    snapshot_id: u64,
+) -> Option<U256> {
+    let preimage = make_total_supply_preimage(id);
+    find_checkpoint(total_supply_snapshots_uref, &preimage, snapshot_id)
+}
@@ -0,0 +1,238 @@
+//! Implementation of the timelock of administrative operations.
+//!
+//! The contract is installed as a locked contract package, hence it can't be upgraded and there
+//! is no upgrade operation to timelock.
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef, U256,
+};
+
+use crate::{
+    constants::{
+        MIN_DELAY_KEY_NAME, OPERATIONS_COUNT_KEY_NAME, OPERATIONS_KEY_NAME, SUPPLY_CAPS_KEY_NAME,
+    },
+    detail,
+    multisig::Action,
+    Address,
+};
+
+pub(crate) const OPERATION_ACTION_TAG: u8 = 0;
+pub(crate) const OPERATION_SET_MIN_DELAY_TAG: u8 = 1;
+pub(crate) const OPERATION_TRANSFER_OWNERSHIP_TAG: u8 = 2;
+pub(crate) const OPERATION_SET_SUPPLY_CAP_TAG: u8 = 3;
+
+/// An administrative operation which can only be executed once its delay elapsed.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Operation {
+    /// Performs an action approved through the multisig admin. Only the multisig admin can queue
+    /// it, by executing a proposal.
+    Action(Action),
+    /// Changes the minimum delay between queueing and executing an operation.
+    SetMinDelay(u64),
+    /// Starts to transfer the ownership of the contract to an address, which becomes the owner
+    /// once it accepts the ownership.
+    TransferOwnership(Address),
+    /// Limits the total supply of a token id, or lifts its limit when `cap` is `None`.
+    SetSupplyCap {
+        /// Token id to limit.
+        id: String,
+        /// Maximum total supply of the token id.
+        cap: Option<U256>,
+    },
+}
+
+impl CLTyped for Operation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Operation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        match self {
+            Operation::Action(action) => {
+                result.push(OPERATION_ACTION_TAG);
+                result.append(&mut action.to_bytes()?);
+            }
+            Operation::SetMinDelay(min_delay) => {
+                result.push(OPERATION_SET_MIN_DELAY_TAG);
+                result.append(&mut min_delay.to_bytes()?);
+            }
+            Operation::TransferOwnership(new_owner) => {
+                result.push(OPERATION_TRANSFER_OWNERSHIP_TAG);
+                result.append(&mut new_owner.to_bytes()?);
+            }
+            Operation::SetSupplyCap { id, cap } => {
+                result.push(OPERATION_SET_SUPPLY_CAP_TAG);
+                result.append(&mut id.to_bytes()?);
+                result.append(&mut cap.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            Operation::Action(action) => action.serialized_length(),
+            Operation::SetMinDelay(min_delay) => min_delay.serialized_length(),
+            Operation::TransferOwnership(new_owner) => new_owner.serialized_length(),
+            Operation::SetSupplyCap { id, cap } => id.serialized_length() + cap.serialized_length(),
+        }
+    }
+}
+
+impl FromBytes for Operation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            OPERATION_ACTION_TAG => {
+                let (action, remainder) = Action::from_bytes(remainder)?;
+                Ok((Operation::Action(action), remainder))
+            }
+            OPERATION_SET_MIN_DELAY_TAG => {
+                let (min_delay, remainder) = u64::from_bytes(remainder)?;
+                Ok((Operation::SetMinDelay(min_delay), remainder))
+            }
+            OPERATION_TRANSFER_OWNERSHIP_TAG => {
+                let (new_owner, remainder) = Address::from_bytes(remainder)?;
+                Ok((Operation::TransferOwnership(new_owner), remainder))
+            }
+            OPERATION_SET_SUPPLY_CAP_TAG => {
+                let (id, remainder) = String::from_bytes(remainder)?;
+                let (cap, remainder) = Option::<U256>::from_bytes(remainder)?;
+                Ok((Operation::SetSupplyCap { id, cap }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// An [`Operation`] queued by the owner of the contract, or by the multisig admin.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct QueuedOperation {
+    /// Operation to execute.
+    pub operation: Operation,
+    /// Blocktime from which the operation can be executed.
+    pub eta: u64,
+    /// Whether the operation can still be executed or cancelled.
+    pub pending: bool,
+}
+
+impl CLTyped for QueuedOperation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for QueuedOperation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.operation.to_bytes()?);
+        result.append(&mut self.eta.to_bytes()?);
+        result.append(&mut self.pending.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.operation.serialized_length()
+            + self.eta.serialized_length()
+            + self.pending.serialized_length()
+    }
+}
+
+impl FromBytes for QueuedOperation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (operation, remainder) = Operation::from_bytes(bytes)?;
+        let (eta, remainder) = u64::from_bytes(remainder)?;
+        let (pending, remainder) = bool::from_bytes(remainder)?;
+        let queued_operation = QueuedOperation {
+            operation,
+            eta,
+            pending,
+        };
+        Ok((queued_operation, remainder))
+    }
+}
+
+/// Returns whether the timelock is installed in the contract context.
+pub(crate) fn is_installed() -> bool {
+    detail::get_optional_uref(OPERATIONS_KEY_NAME).is_some()
+}
+
+/// Get Operations uref of contract context.
+pub(crate) fn operations_uref() -> URef {
+    detail::get_uref(OPERATIONS_KEY_NAME)
+}
+
+/// Reads a queued operation from a dictionary.
+pub(crate) fn read_operation_from(
+    operations_uref: URef,
+    operation_id: u64,
+) -> Option<QueuedOperation> {
+    storage::dictionary_get(operations_uref, &operation_id.to_string()).unwrap_or_revert()
+}
+
+/// Writes a queued operation into a dictionary.
+pub(crate) fn write_operation_to(
+    operations_uref: URef,
+    operation_id: u64,
+    queued_operation: QueuedOperation,
+) {
+    storage::dictionary_put(operations_uref, &operation_id.to_string(), queued_operation);
+}
+
+/// Reads the amount of operations queued so far.
+pub(crate) fn read_operations_count() -> u64 {
+    detail::read_from(OPERATIONS_COUNT_KEY_NAME)
+}
+
+/// Writes the amount of operations queued so far.
+pub(crate) fn write_operations_count(operations_count: u64) {
+    storage::write(
+        detail::get_uref(OPERATIONS_COUNT_KEY_NAME),
+        operations_count,
+    );
+}
+
+/// Reads the minimum delay between queueing and executing an operation.
+pub(crate) fn read_min_delay() -> u64 {
+    detail::read_from(MIN_DELAY_KEY_NAME)
+}
+
+/// Writes the minimum delay between queueing and executing an operation.
+pub(crate) fn write_min_delay(min_delay: u64) {
+    storage::write(detail::get_uref(MIN_DELAY_KEY_NAME), min_delay);
+}
+
+/// Creates a dictionary item key for the supply cap of a token id.
+#[inline]
+fn make_dictionary_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Get supply caps uref of contract context, if the timelock is installed.
+pub(crate) fn supply_caps_uref() -> Option<URef> {
+    detail::get_optional_uref(SUPPLY_CAPS_KEY_NAME)
+}
+
+/// Reads the supply cap of a token id, if it has one.
+pub(crate) fn read_supply_cap_from(supply_caps_uref: URef, id: &str) -> Option<U256> {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_get::<Option<U256>>(supply_caps_uref, &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Writes the supply cap of a token id.
+pub(crate) fn write_supply_cap_to(supply_caps_uref: URef, id: &str, cap: Option<U256>) {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_put(supply_caps_uref, &dictionary_item_key, cap);
+}
@@ -0,0 +1,121 @@
+//! Implementation of ERC20 wrapping.
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, runtime_args, ContractHash, RuntimeArgs, URef, U256};
+
+use crate::{constants::WRAPPED_TOKENS_KEY_NAME, detail, Address};
+
+/// Prefix of the token ids which wrap ERC20 tokens.
+///
+/// It starts with the `#` separator of non-fungible item ids, which ids created through
+/// [`crate::ERC1155::create`] can't contain, so that wrapped ids never clash with user ids.
+const WRAPPED_TOKEN_ID_PREFIX: &str = "#erc20-";
+/// Name of the ERC20 `balance_of` entry point.
+const ERC20_BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+/// Name of the ERC20 `transfer` entry point.
+const ERC20_TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+/// Name of the ERC20 `transfer_from` entry point.
+const ERC20_TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+/// Name of the ERC20 `address` runtime argument.
+const ERC20_ADDRESS_RUNTIME_ARG_NAME: &str = "address";
+/// Name of the ERC20 `owner` runtime argument.
+const ERC20_OWNER_RUNTIME_ARG_NAME: &str = "owner";
+/// Name of the ERC20 `recipient` runtime argument.
+const ERC20_RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
+/// Name of the ERC20 `amount` runtime argument.
+const ERC20_AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+
+/// Returns the token id which wraps the tokens of `erc20_contract`.
+///
+/// The contract hash is encoded as unpadded URL-safe base64 to keep the id short enough to be used
+/// within dictionary item keys.
+pub fn wrapped_token_id(erc20_contract: ContractHash) -> String {
+    let mut id = String::from(WRAPPED_TOKEN_ID_PREFIX);
+    id.push_str(&base64::encode_config(
+        erc20_contract.value(),
+        base64::URL_SAFE_NO_PAD,
+    ));
+    id
+}
+
+/// Returns true if `id` is within the namespace of the ids returned by [`wrapped_token_id`], which
+/// only [`crate::ERC1155::wrap`] can mint and [`crate::ERC1155::unwrap`] can burn.
+pub(crate) fn is_wrapped_token_id(id: &str) -> bool {
+    id.starts_with(WRAPPED_TOKEN_ID_PREFIX)
+}
+
+/// Creates a dictionary item key for a wrapped token id.
+#[inline]
+fn make_dictionary_item_key(id: &str) -> String {
+    let key_bytes = runtime::blake2b(id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+/// Get Wrapped tokens uref of contract context.
+pub(crate) fn wrapped_tokens_uref() -> URef {
+    detail::get_uref(WRAPPED_TOKENS_KEY_NAME)
+}
+
+/// Reads the ERC20 contract wrapped by a token id.
+pub(crate) fn read_wrapped_token_from(wrapped_tokens_uref: URef, id: &str) -> Option<ContractHash> {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_get(wrapped_tokens_uref, &dictionary_item_key).unwrap_or_revert()
+}
+
+/// Writes the ERC20 contract wrapped by a token id.
+pub(crate) fn write_wrapped_token_to(
+    wrapped_tokens_uref: URef,
+    id: &str,
+    erc20_contract: ContractHash,
+) {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_put(wrapped_tokens_uref, &dictionary_item_key, erc20_contract);
+}
+
+/// Returns the ERC20 balance of `address`.
+pub(crate) fn balance_of(erc20_contract: ContractHash, address: Address) -> U256 {
+    let balance_of_args = runtime_args! {
+        ERC20_ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    runtime::call_contract(
+        erc20_contract,
+        ERC20_BALANCE_OF_ENTRY_POINT_NAME,
+        balance_of_args,
+    )
+}
+
+/// Moves `amount` ERC20 tokens from `owner` to `recipient`, spending the allowance given by `owner`
+/// to the calling contract.
+pub(crate) fn transfer_from(
+    erc20_contract: ContractHash,
+    owner: Address,
+    recipient: Address,
+    amount: U256,
+) {
+    let transfer_from_args = runtime_args! {
+        ERC20_OWNER_RUNTIME_ARG_NAME => owner,
+        ERC20_RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        ERC20_AMOUNT_RUNTIME_ARG_NAME => amount,
+    };
+    runtime::call_contract::<()>(
+        erc20_contract,
+        ERC20_TRANSFER_FROM_ENTRY_POINT_NAME,
+        transfer_from_args,
+    );
+}
+
+/// Moves `amount` ERC20 tokens from the calling contract to `recipient`.
+pub(crate) fn transfer(erc20_contract: ContractHash, recipient: Address, amount: U256) {
+    let transfer_args = runtime_args! {
+        ERC20_RECIPIENT_RUNTIME_ARG_NAME => recipient,
+        ERC20_AMOUNT_RUNTIME_ARG_NAME => amount,
+    };
+    runtime::call_contract::<()>(
+        erc20_contract,
+        ERC20_TRANSFER_ENTRY_POINT_NAME,
+        transfer_args,
+    );
+}
@@ -0,0 +1,51 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+
+use alloc::string::String;
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc1155::constants::{
+    AMOUNT_RUNTIME_ARG_NAME, PURCHASE_ENTRY_POINT_NAME, PURSE_RUNTIME_ARG_NAME,
+    TOKEN_ID_RUNTIME_ARG_NAME,
+};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U256, U512};
+
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const PAYMENT_AMOUNT_RUNTIME_ARG_NAME: &str = "payment_amount";
+
+/// Session code which pays for a purchase of ERC1155 tokens out of the main purse of the caller.
+///
+/// The payment is moved into a temporary purse handed over to the `purchase` entry point, and the
+/// change left in it once the tokens are paid for is moved back into the main purse.
+#[no_mangle]
+pub extern "C" fn call() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let payment_amount: U512 = runtime::get_named_arg(PAYMENT_AMOUNT_RUNTIME_ARG_NAME);
+
+    let main_purse = account::get_main_purse();
+    let payment_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(main_purse, payment_purse, payment_amount, None)
+        .unwrap_or_revert();
+
+    let purchase_args = runtime_args! {
+        TOKEN_ID_RUNTIME_ARG_NAME => id,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+        PURSE_RUNTIME_ARG_NAME => payment_purse,
+    };
+    runtime::call_contract::<()>(token_contract, PURCHASE_ENTRY_POINT_NAME, purchase_args);
+
+    let change = system::get_purse_balance(payment_purse).unwrap_or_revert();
+    if !change.is_zero() {
+        system::transfer_from_purse_to_purse(payment_purse, main_purse, change, None)
+            .unwrap_or_revert();
+    }
+}
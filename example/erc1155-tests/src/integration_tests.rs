@@ -1,10 +1,38 @@
 #[cfg(test)]
+mod model;
+#[cfg(test)]
+mod model_tests;
+#[cfg(test)]
 mod test_fixture;
 
 #[cfg(test)]
 mod tests {
     use crate::test_fixture::{Sender, TestFixture};
-    use casper_types::{Key, U256};
+    use casper_erc1155::{
+        merkle::MerkleTree, nft_token_id, Action, Address, CallerPolicy, Error, Operation,
+    };
+    use casper_types::{Key, U256, U512};
+
+    fn airdrop_tree(fixture: &TestFixture) -> MerkleTree {
+        MerkleTree::new(&[
+            (
+                Address::from(fixture.ali),
+                String::from("1"),
+                U256::from(10),
+            ),
+            (
+                Address::from(fixture.bob),
+                String::from("1"),
+                U256::from(20),
+            ),
+            (
+                Address::from(fixture.joe),
+                String::from("2"),
+                U256::from(30),
+            ),
+        ])
+        .unwrap()
+    }
 
     #[test]
     fn should_install() {
@@ -15,6 +43,8 @@ mod tests {
     #[test]
     fn should_read_total_supply() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        fixture.create_ids(&["2"], Sender(fixture.bob));
 
         let id_1 = "1";
         let mint_amount_1 = U256::from(42);
@@ -47,6 +77,7 @@ mod tests {
     #[test]
     fn should_read_balance() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
         let id = "1";
         let total_supply = U256::from(100);
         fixture.mint(
@@ -81,6 +112,7 @@ mod tests {
     #[test]
     fn should_read_balance_batch() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1", "2"], Sender(fixture.ali));
 
         let token_id_1 = "1";
         let token_id_2 = "2";
@@ -126,6 +158,7 @@ mod tests {
     #[test]
     fn should_is_approval_for_all() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
         let id = "1";
         let mint_amount = U256::from(100);
         let transfer_amount = U256::from(35);
@@ -184,6 +217,7 @@ mod tests {
     #[test]
     fn should_mint_and_read_total_supply() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
         let id = "1";
         let mint_amount = U256::from(100);
 
@@ -199,25 +233,26 @@ mod tests {
     #[test]
     fn should_burn_and_read_total_supply() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
         let id = "1";
         let mint_amount = U256::from(100);
 
-        fixture.mint(Key::from(fixture.joe), id, mint_amount, Sender(fixture.ali));
+        fixture.mint(Key::from(fixture.ali), id, mint_amount, Sender(fixture.ali));
 
         assert_eq!(
-            fixture.balance_of(Key::from(fixture.joe), id),
+            fixture.balance_of(Key::from(fixture.ali), id),
             Some(mint_amount)
         );
         assert_eq!(fixture.total_supply(id), Some(mint_amount));
 
         fixture.burn(
-            Key::from(fixture.joe),
+            Key::from(fixture.ali),
             id,
             mint_amount - U256::from(1),
             Sender(fixture.ali),
         );
         assert_eq!(
-            fixture.balance_of(Key::from(fixture.joe), id),
+            fixture.balance_of(Key::from(fixture.ali), id),
             Some(U256::from(1))
         );
         assert_eq!(fixture.total_supply(id), Some(U256::from(1)));
@@ -226,6 +261,7 @@ mod tests {
     #[test]
     fn should_safe_transfer_from() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
         let mint_amount = U256::from(10000);
         let id = "1";
         fixture.mint(Key::from(fixture.ali), id, mint_amount, Sender(fixture.ali));
@@ -255,6 +291,7 @@ mod tests {
     #[test]
     fn should_safe_batch_transfer_from() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1", "2"], Sender(fixture.ali));
         let mint_amount = U256::from(10000);
         let id_1 = "1";
         let id_2 = "2";
@@ -310,6 +347,7 @@ mod tests {
     #[test]
     fn should_not_safe_transfer_from_operator() {
         let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
         let mint_amount = U256::from(10000);
         let transfer_amount = U256::from(20);
         let id = "1";
@@ -348,6 +386,1583 @@ mod tests {
             Sender(fixture.ali),
         );
     }
+
+    #[test]
+    fn should_claim_airdrop_with_valid_proof() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let tree = airdrop_tree(&fixture);
+        let campaign = "season-1";
+
+        fixture.set_claim_root(campaign, tree.root().unwrap(), Sender(fixture.ali));
+        fixture.claim(
+            campaign,
+            "1",
+            U256::from(20),
+            tree.proof(1).unwrap(),
+            Sender(fixture.bob),
+        );
+
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.bob), "1"),
+            Some(U256::from(20))
+        );
+        assert_eq!(fixture.total_supply("1"), Some(U256::from(20)));
+    }
+
+    #[test]
+    fn should_not_claim_airdrop_twice() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let tree = airdrop_tree(&fixture);
+        let campaign = "season-1";
+
+        fixture.set_claim_root(campaign, tree.root().unwrap(), Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::AlreadyClaimed, |fixture| {
+            for _ in 0..2 {
+                fixture.claim(
+                    campaign,
+                    "1",
+                    U256::from(20),
+                    tree.proof(1).unwrap(),
+                    Sender(fixture.bob),
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn should_not_claim_airdrop_with_invalid_proof() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let tree = airdrop_tree(&fixture);
+        let campaign = "season-1";
+
+        fixture.set_claim_root(campaign, tree.root().unwrap(), Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::InvalidProof, |fixture| {
+            fixture.claim(
+                campaign,
+                "1",
+                U256::from(200),
+                tree.proof(1).unwrap(),
+                Sender(fixture.bob),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_claim_airdrop_of_id_the_registrar_cannot_mint() {
+        let mut fixture = TestFixture::install_contract();
+        let tree = airdrop_tree(&fixture);
+        let campaign = "season-1";
+        fixture.create(Some("1"), U256::zero(), "", true, Sender(fixture.bob));
+
+        fixture.set_claim_root(campaign, tree.root().unwrap(), Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.claim(
+                campaign,
+                "1",
+                U256::from(20),
+                tree.proof(1).unwrap(),
+                Sender(fixture.bob),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_set_claim_root_if_not_owner() {
+        let mut fixture = TestFixture::install_contract();
+        let tree = airdrop_tree(&fixture);
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.set_claim_root("season-1", tree.root().unwrap(), Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_purchase_on_sale() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        let price = U512::from(1_000_000_000u64);
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.set_sale(
+            id,
+            price,
+            0,
+            u64::MAX,
+            U256::from(5),
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+        fixture.purchase(id, U256::from(3), price * 4, Sender(fixture.bob));
+
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.bob), id),
+            Some(U256::from(3))
+        );
+        assert_eq!(fixture.total_supply(id), Some(U256::from(3)));
+
+        fixture.withdraw(price * 3, Sender(fixture.ali));
+    }
+
+    #[test]
+    fn should_not_purchase_with_insufficient_payment() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        let price = U512::from(1_000_000_000u64);
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.set_sale(
+            id,
+            price,
+            0,
+            u64::MAX,
+            U256::zero(),
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+        fixture.assert_reverts_with(Error::InsufficientPayment, |fixture| {
+            fixture.purchase(id, U256::from(3), price * 2, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_purchase_when_sold_out() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        let price = U512::from(1_000_000_000u64);
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.set_sale(
+            id,
+            price,
+            0,
+            u64::MAX,
+            U256::zero(),
+            U256::from(2),
+            Sender(fixture.ali),
+        );
+        fixture.purchase(id, U256::from(2), price * 2, Sender(fixture.bob));
+        fixture.assert_reverts_with(Error::SoldOut, |fixture| {
+            fixture.purchase(id, U256::from(1), price, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_purchase_over_wallet_limit() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        let price = U512::from(1_000_000_000u64);
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.set_sale(
+            id,
+            price,
+            0,
+            u64::MAX,
+            U256::from(2),
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+        fixture.purchase(id, U256::from(2), price * 2, Sender(fixture.bob));
+        fixture.assert_reverts_with(Error::PurchaseLimitExceeded, |fixture| {
+            fixture.purchase(id, U256::from(1), price, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_purchase_before_sale_starts() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        let price = U512::from(1_000_000_000u64);
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.set_sale(
+            id,
+            price,
+            u64::MAX - 1,
+            u64::MAX,
+            U256::zero(),
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+        fixture.assert_reverts_with(Error::SaleNotActive, |fixture| {
+            fixture.purchase(id, U256::from(1), price, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_set_sale_of_uncreated_id() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.assert_reverts_with(Error::UnknownTokenType, |fixture| {
+            fixture.set_sale(
+                "1",
+                U512::from(1_000_000_000u64),
+                0,
+                u64::MAX,
+                U256::zero(),
+                U256::from(10),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_set_sale_of_id_created_by_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.bob));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.set_sale(
+                id,
+                U512::from(1_000_000_000u64),
+                0,
+                u64::MAX,
+                U256::zero(),
+                U256::from(10),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_set_sale_closing_before_it_opens() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::InvalidSale, |fixture| {
+            fixture.set_sale(
+                id,
+                U512::from(1_000_000_000u64),
+                100,
+                100,
+                U256::zero(),
+                U256::from(10),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_set_sale_with_zero_price() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::InvalidSale, |fixture| {
+            fixture.set_sale(
+                id,
+                U512::zero(),
+                0,
+                u64::MAX,
+                U256::zero(),
+                U256::from(10),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_set_sale_with_zero_supply() {
+        let mut fixture = TestFixture::install_contract();
+        let id = "1";
+        fixture.create(Some(id), U256::zero(), "", true, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::InvalidSale, |fixture| {
+            fixture.set_sale(
+                id,
+                U512::from(1_000_000_000u64),
+                0,
+                u64::MAX,
+                U256::zero(),
+                U256::zero(),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_unwrap_unknown_token() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let id = "1";
+        fixture.mint(
+            Key::from(fixture.ali),
+            id,
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+
+        fixture.assert_reverts_with(Error::UnknownWrappedToken, |fixture| {
+            fixture.unwrap(id, U256::from(10), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_mint_wrapped_token_id() {
+        let mut fixture = TestFixture::install_contract();
+        let action = Action::Mint {
+            to: Address::from(fixture.ali),
+            id: String::from("#erc20-AAAA"),
+            amount: U256::from(10),
+        };
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::ReservedTokenId, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_create_wrapped_token_id() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.assert_reverts_with(Error::ReservedTokenId, |fixture| {
+            fixture.create(
+                Some("#erc20-AAAA"),
+                U256::from(10),
+                "",
+                true,
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_mint_fraction_token_id() {
+        let mut fixture = TestFixture::install_contract();
+        let action = Action::Mint {
+            to: Address::from(fixture.ali),
+            id: String::from("#nft-AAAA"),
+            amount: U256::from(10),
+        };
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::ReservedTokenId, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_burn_wrapped_token_id() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.assert_reverts_with(Error::ReservedTokenId, |fixture| {
+            fixture.burn(
+                Key::from(fixture.ali),
+                "#erc20-AAAA",
+                U256::zero(),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_redeem_unknown_fractions() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let id = "1";
+        fixture.mint(
+            Key::from(fixture.ali),
+            id,
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+
+        fixture.assert_reverts_with(Error::NftNotLocked, |fixture| {
+            fixture.redeem(id, Sender(fixture.ali));
+        });
+    }
+
+    fn offer_fixture() -> TestFixture {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1", "2"], Sender(fixture.ali));
+        fixture.mint(
+            Key::from(fixture.ali),
+            "1",
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+        fixture.mint(
+            Key::from(fixture.bob),
+            "2",
+            U256::from(5),
+            Sender(fixture.ali),
+        );
+        fixture.create_offer(
+            Some(Key::from(fixture.bob)),
+            vec![String::from("1")],
+            vec![U256::from(4)],
+            vec![String::from("2")],
+            vec![U256::from(2)],
+            u64::MAX,
+            Sender(fixture.ali),
+        );
+        fixture
+    }
+
+    #[test]
+    fn should_accept_offer() {
+        let mut fixture = offer_fixture();
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.ali), "1"),
+            Some(U256::from(6))
+        );
+
+        fixture.accept_offer(0, Sender(fixture.bob));
+
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.bob), "1"),
+            Some(U256::from(4))
+        );
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.bob), "2"),
+            Some(U256::from(3))
+        );
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.ali), "2"),
+            Some(U256::from(2))
+        );
+    }
+
+    #[test]
+    fn should_cancel_offer() {
+        let mut fixture = offer_fixture();
+
+        fixture.cancel_offer(0, Sender(fixture.ali));
+
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.ali), "1"),
+            Some(U256::from(10))
+        );
+    }
+
+    #[test]
+    fn should_cancel_offer_when_paused() {
+        let mut fixture = offer_fixture();
+        fixture.propose(Action::SetPaused(true), u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+
+        fixture.cancel_offer(0, Sender(fixture.ali));
+
+        assert_eq!(
+            fixture.balance_of(Key::from(fixture.ali), "1"),
+            Some(U256::from(10))
+        );
+    }
+
+    #[test]
+    fn should_not_accept_offer_restricted_to_another_taker() {
+        let mut fixture = offer_fixture();
+        fixture.mint(
+            Key::from(fixture.ali),
+            "2",
+            U256::from(5),
+            Sender(fixture.ali),
+        );
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.accept_offer(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_accept_cancelled_offer() {
+        let mut fixture = offer_fixture();
+
+        fixture.cancel_offer(0, Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::UnknownOffer, |fixture| {
+            fixture.accept_offer(0, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_accept_expired_offer() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        fixture.mint(
+            Key::from(fixture.ali),
+            "1",
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+        fixture.create_offer(
+            None,
+            vec![String::from("1")],
+            vec![U256::from(4)],
+            vec![String::from("1")],
+            vec![U256::from(1)],
+            100,
+            Sender(fixture.ali),
+        );
+
+        fixture.blocktime = 100;
+        fixture.assert_reverts_with(Error::OfferExpired, |fixture| {
+            fixture.accept_offer(0, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_create_offer_expiring_in_the_past() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        fixture.mint(
+            Key::from(fixture.ali),
+            "1",
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+
+        fixture.blocktime = 100;
+        fixture.assert_reverts_with(Error::OfferExpired, |fixture| {
+            fixture.create_offer(
+                None,
+                vec![String::from("1")],
+                vec![U256::from(4)],
+                vec![String::from("1")],
+                vec![U256::from(1)],
+                100,
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_create_offer_without_wanted_tokens() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        fixture.mint(
+            Key::from(fixture.ali),
+            "1",
+            U256::from(10),
+            Sender(fixture.ali),
+        );
+
+        fixture.assert_reverts_with(Error::EmptyOffer, |fixture| {
+            fixture.create_offer(
+                None,
+                vec![String::from("1")],
+                vec![U256::from(4)],
+                vec![],
+                vec![],
+                u64::MAX,
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_create_offer_without_offered_tokens() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::EmptyOffer, |fixture| {
+            fixture.create_offer(
+                None,
+                vec![],
+                vec![],
+                vec![String::from("1")],
+                vec![U256::from(1)],
+                u64::MAX,
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_read_balances_at_snapshots() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "1", U256::from(10), Sender(fixture.ali));
+        fixture.snapshot(Sender(fixture.ali));
+        fixture.safe_transfer_from(ali, bob, "1", U256::from(4), Sender(fixture.ali));
+        fixture.snapshot(Sender(fixture.ali));
+        fixture.safe_transfer_from(ali, bob, "1", U256::from(1), Sender(fixture.ali));
+        fixture.mint(bob, "1", U256::from(5), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of_at(ali, "1", 1), Some(U256::from(10)));
+        assert_eq!(fixture.balance_of_at(bob, "1", 1), Some(U256::zero()));
+        assert_eq!(fixture.balance_of_at(ali, "1", 2), Some(U256::from(6)));
+        assert_eq!(fixture.balance_of_at(bob, "1", 2), Some(U256::from(4)));
+        assert_eq!(fixture.balance_of(ali, "1"), Some(U256::from(5)));
+        assert_eq!(fixture.balance_of(bob, "1"), Some(U256::from(10)));
+        assert_eq!(fixture.total_supply_at("1", 1), Some(U256::from(10)));
+        assert_eq!(fixture.total_supply_at("1", 2), Some(U256::from(10)));
+        assert_eq!(fixture.total_supply("1"), Some(U256::from(15)));
+    }
+
+    #[test]
+    fn should_not_snapshot_if_not_owner() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.snapshot(Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_read_balance_at_unknown_snapshot() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let ali = Key::from(fixture.ali);
+        fixture.mint(ali, "1", U256::from(10), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::UnknownSnapshot, |fixture| {
+            fixture.call_balance_of_at(ali, "1", 1, Sender(fixture.ali));
+        });
+    }
+
+    fn rental_fixture() -> TestFixture {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "1", U256::from(10), Sender(fixture.ali));
+        fixture.set_user(ali, "1", U256::from(4), bob, u64::MAX, Sender(fixture.ali));
+        fixture
+    }
+
+    #[test]
+    fn should_rent_balance_to_user() {
+        let mut fixture = rental_fixture();
+        let (ali, joe) = (Key::from(fixture.ali), Key::from(fixture.joe));
+
+        fixture.safe_transfer_from(ali, joe, "1", U256::from(6), Sender(fixture.ali));
+
+        let rental = fixture.rental(ali, "1").unwrap();
+        assert_eq!(rental.user, Address::from(fixture.bob));
+        assert_eq!(rental.amount, U256::from(4));
+        assert_eq!(fixture.balance_of(ali, "1"), Some(U256::from(4)));
+    }
+
+    #[test]
+    fn should_not_transfer_rented_balance() {
+        let mut fixture = rental_fixture();
+        let (ali, joe) = (Key::from(fixture.ali), Key::from(fixture.joe));
+
+        fixture.assert_reverts_with(Error::BalanceRented, |fixture| {
+            fixture.safe_transfer_from(ali, joe, "1", U256::from(7), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_burn_rented_balance() {
+        let mut fixture = rental_fixture();
+        let ali = Key::from(fixture.ali);
+
+        fixture.assert_reverts_with(Error::BalanceRented, |fixture| {
+            fixture.burn(ali, "1", U256::from(7), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_burn_rented_balance_through_multisig() {
+        let mut fixture = rental_fixture();
+        let action = Action::Burn {
+            owner: Address::from(fixture.ali),
+            id: String::from("1"),
+            amount: U256::from(7),
+        };
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.assert_reverts_with(Error::BalanceRented, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_burn_balance_left_by_rental() {
+        let mut fixture = rental_fixture();
+        let ali = Key::from(fixture.ali);
+
+        fixture.burn(ali, "1", U256::from(6), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of(ali, "1"), Some(U256::from(4)));
+        assert_eq!(fixture.total_supply("1"), Some(U256::from(4)));
+    }
+
+    #[test]
+    fn should_transfer_balance_after_user_revokes() {
+        let mut fixture = rental_fixture();
+        let (ali, joe) = (Key::from(fixture.ali), Key::from(fixture.joe));
+
+        fixture.revoke_user(ali, "1", Sender(fixture.bob));
+        fixture.safe_transfer_from(ali, joe, "1", U256::from(10), Sender(fixture.ali));
+
+        assert_eq!(fixture.balance_of(joe, "1"), Some(U256::from(10)));
+    }
+
+    #[test]
+    fn should_not_revoke_rental_of_another_user() {
+        let mut fixture = rental_fixture();
+        let ali = Key::from(fixture.ali);
+
+        fixture.assert_reverts_with(Error::NotRented, |fixture| {
+            fixture.revoke_user(ali, "1", Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_set_user_if_not_owner_or_operator() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["1"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "1", U256::from(10), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.set_user(ali, "1", U256::from(4), bob, u64::MAX, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_sweep_expired_balances() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["ticket"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint_with_expiry(ali, "ticket", U256::from(10), 1_000, Sender(fixture.ali));
+        fixture.mint(bob, "ticket", U256::from(5), Sender(fixture.ali));
+        fixture.blocktime = 1_000;
+
+        fixture.sweep_expired("ticket", vec![ali, bob], Sender(fixture.bob));
+
+        assert_eq!(fixture.balance_of(ali, "ticket"), Some(U256::zero()));
+        assert_eq!(fixture.balance_of(bob, "ticket"), Some(U256::zero()));
+        assert_eq!(fixture.total_supply("ticket"), Some(U256::zero()));
+    }
+
+    #[test]
+    fn should_not_transfer_expired_token() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["ticket"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint_with_expiry(ali, "ticket", U256::from(10), 1_000, Sender(fixture.ali));
+        fixture.blocktime = 1_000;
+
+        fixture.assert_reverts_with(Error::TokenExpired, |fixture| {
+            fixture.safe_transfer_from(ali, bob, "ticket", U256::from(1), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_mint_expired_token() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["ticket"], Sender(fixture.ali));
+        let ali = Key::from(fixture.ali);
+        fixture.mint_with_expiry(ali, "ticket", U256::from(10), 1_000, Sender(fixture.ali));
+        fixture.blocktime = 1_000;
+
+        fixture.assert_reverts_with(Error::TokenExpired, |fixture| {
+            fixture.mint(ali, "ticket", U256::from(1), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_mint_with_past_expiry() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["ticket"], Sender(fixture.ali));
+        let ali = Key::from(fixture.ali);
+        fixture.blocktime = 1_000;
+
+        fixture.assert_reverts_with(Error::TokenExpired, |fixture| {
+            fixture.mint_with_expiry(ali, "ticket", U256::from(10), 1_000, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_mint_with_expiry_if_not_minter() {
+        let mut fixture = TestFixture::install_contract();
+        let ali = Key::from(fixture.ali);
+        fixture.create(Some("ticket"), U256::zero(), "", true, Sender(fixture.bob));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.mint_with_expiry(ali, "ticket", U256::from(10), u64::MAX, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_sweep_token_before_expiry() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["ticket"], Sender(fixture.ali));
+        let ali = Key::from(fixture.ali);
+        fixture.mint_with_expiry(ali, "ticket", U256::from(10), u64::MAX, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::TokenNotExpired, |fixture| {
+            fixture.sweep_expired("ticket", vec![ali], Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_set_expiry_of_minted_token() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["ticket"], Sender(fixture.ali));
+        let ali = Key::from(fixture.ali);
+        fixture.mint(ali, "ticket", U256::from(10), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::AlreadyMinted, |fixture| {
+            fixture.mint_with_expiry(ali, "ticket", U256::from(10), u64::MAX, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_create_token_types_with_auto_ids() {
+        let mut fixture = TestFixture::install_contract();
+        let bob = Key::from(fixture.bob);
+        let action = Action::Mint {
+            to: Address::from(fixture.ali),
+            id: String::from("1"),
+            amount: U256::one(),
+        };
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+
+        fixture.create(
+            None,
+            U256::from(100),
+            "ipfs://sword",
+            true,
+            Sender(fixture.bob),
+        );
+        fixture.create(
+            Some("shield"),
+            U256::zero(),
+            "ipfs://shield",
+            false,
+            Sender(fixture.ali),
+        );
+
+        let sword = fixture.token_type("2").unwrap();
+        assert_eq!(sword.creator, Address::from(fixture.bob));
+        assert_eq!(sword.uri, "ipfs://sword");
+        assert!(sword.fungible);
+        assert_eq!(fixture.balance_of(bob, "2"), Some(U256::from(100)));
+        let shield = fixture.token_type("shield").unwrap();
+        assert_eq!(shield.creator, Address::from(fixture.ali));
+        assert!(!shield.fungible);
+        assert!(fixture.token_type("1").is_none());
+    }
+
+    #[test]
+    fn should_mint_created_token_as_delegated_minter() {
+        let mut fixture = TestFixture::install_contract();
+        let (ali, joe) = (Key::from(fixture.ali), Key::from(fixture.joe));
+        fixture.create(
+            Some("gem"),
+            U256::from(10),
+            "ipfs://gem",
+            true,
+            Sender(fixture.bob),
+        );
+
+        fixture.set_minter("gem", ali, true, Sender(fixture.bob));
+        fixture.mint(joe, "gem", U256::from(5), Sender(fixture.ali));
+
+        assert_eq!(fixture.total_supply("gem"), Some(U256::from(15)));
+    }
+
+    #[test]
+    fn should_not_mint_created_token_if_not_minter() {
+        let mut fixture = TestFixture::install_contract();
+        let ali = Key::from(fixture.ali);
+        fixture.create(
+            Some("gem"),
+            U256::from(10),
+            "ipfs://gem",
+            true,
+            Sender(fixture.bob),
+        );
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.mint(ali, "gem", U256::from(5), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_mint_uncreated_token_outside_multisig() {
+        let mut fixture = TestFixture::install_contract();
+        let ali = Key::from(fixture.ali);
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.mint(ali, "gem", U256::from(5), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_burn_tokens_of_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        let bob = Key::from(fixture.bob);
+        fixture.create_ids(&["gem"], Sender(fixture.bob));
+        fixture.mint(bob, "gem", U256::from(5), Sender(fixture.bob));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.burn(bob, "gem", U256::from(5), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_burn_batch_of_another_account() {
+        let mut fixture = TestFixture::install_contract();
+        let bob = Key::from(fixture.bob);
+        fixture.create_ids(&["gem"], Sender(fixture.bob));
+        fixture.mint(bob, "gem", U256::from(5), Sender(fixture.bob));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.burn_batch(
+                bob,
+                vec![String::from("gem")],
+                vec![U256::from(5)],
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_create_existing_token() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create(
+            Some("gem"),
+            U256::from(10),
+            "ipfs://gem",
+            true,
+            Sender(fixture.bob),
+        );
+
+        fixture.assert_reverts_with(Error::AlreadyMinted, |fixture| {
+            fixture.create(
+                Some("gem"),
+                U256::from(10),
+                "ipfs://gem",
+                true,
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_create_non_fungible_type_with_initial_supply() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.assert_reverts_with(Error::NonFungibleAmount, |fixture| {
+            fixture.create(
+                Some("sword"),
+                U256::one(),
+                "ipfs://sword",
+                false,
+                Sender(fixture.bob),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_create_item_token_id() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create(
+            Some("sword"),
+            U256::zero(),
+            "ipfs://sword",
+            false,
+            Sender(fixture.bob),
+        );
+        let id = nft_token_id("sword", U256::from(7));
+
+        fixture.assert_reverts_with(Error::InvalidTokenId, |fixture| {
+            fixture.create(
+                Some(id.as_str()),
+                U256::one(),
+                "",
+                false,
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_mint_item_of_token_type_if_not_minter() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create(
+            Some("sword"),
+            U256::zero(),
+            "ipfs://sword",
+            false,
+            Sender(fixture.bob),
+        );
+        let id = nft_token_id("sword", U256::from(7));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.mint(
+                Key::from(fixture.ali),
+                &id,
+                U256::one(),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    fn nft_fixture() -> (TestFixture, String) {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create(
+            Some("sword"),
+            U256::zero(),
+            "ipfs://sword",
+            false,
+            Sender(fixture.ali),
+        );
+        let id = nft_token_id("sword", U256::from(7));
+        fixture.mint(
+            Key::from(fixture.ali),
+            &id,
+            U256::one(),
+            Sender(fixture.ali),
+        );
+        (fixture, id)
+    }
+
+    #[test]
+    fn should_track_owner_of_nft() {
+        let (mut fixture, id) = nft_fixture();
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        assert_eq!(fixture.nft_owner(&id), Some(ali));
+
+        fixture.safe_transfer_from(ali, bob, &id, U256::one(), Sender(fixture.ali));
+
+        assert_eq!(fixture.nft_owner(&id), Some(bob));
+        assert_eq!(fixture.balance_of(bob, &id), Some(U256::one()));
+    }
+
+    #[test]
+    fn should_not_mint_nft_twice() {
+        let (mut fixture, id) = nft_fixture();
+
+        fixture.assert_reverts_with(Error::NonFungibleAmount, |fixture| {
+            fixture.mint(
+                Key::from(fixture.bob),
+                &id,
+                U256::one(),
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_transfer_more_than_one_nft() {
+        let (mut fixture, id) = nft_fixture();
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+
+        fixture.assert_reverts_with(Error::NonFungibleAmount, |fixture| {
+            fixture.safe_transfer_from(ali, bob, &id, U256::from(2), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_execute_approved_proposal() {
+        let mut fixture = TestFixture::install_contract();
+        let joe = Key::from(fixture.joe);
+        let action = Action::Mint {
+            to: Address::from(fixture.joe),
+            id: String::from("gem"),
+            amount: U256::from(5),
+        };
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+
+        fixture.execute(0, Sender(fixture.bob));
+        fixture.execute_operation(0, Sender(fixture.bob));
+
+        assert_eq!(fixture.balance_of(joe, "gem"), Some(U256::from(5)));
+        assert!(fixture.proposal(0).unwrap().executed);
+    }
+
+    #[test]
+    fn should_not_execute_mint_of_created_id_without_minter_role() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create(Some("gem"), U256::zero(), "", true, Sender(fixture.ali));
+        let action = Action::Mint {
+            to: Address::from(fixture.joe),
+            id: String::from("gem"),
+            amount: U256::from(5),
+        };
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+
+        fixture.execute(0, Sender(fixture.bob));
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_execute_proposal_without_enough_approvals() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.propose(Action::SetPaused(true), u64::MAX, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::InsufficientApprovals, |fixture| {
+            fixture.execute(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_approve_expired_proposal() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.propose(Action::SetPaused(true), 0, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::ProposalExpired, |fixture| {
+            fixture.approve(0, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_set_uri_through_proposal() {
+        let mut fixture = TestFixture::install_contract();
+        let action = Action::SetUri(String::from("ipfs://new-uri"));
+        fixture.propose(action, u64::MAX, Sender(fixture.bob));
+        fixture.approve(0, Sender(fixture.ali));
+
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+
+        assert_eq!(fixture.uri(), "ipfs://new-uri");
+    }
+
+    #[test]
+    fn should_not_transfer_when_paused() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "gem", U256::from(5), Sender(fixture.ali));
+        fixture.propose(Action::SetPaused(true), u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+        fixture.execute(0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::Paused, |fixture| {
+            fixture.safe_transfer_from(ali, bob, "gem", U256::one(), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_apply_proposal_before_min_delay() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.queue_operation(Operation::SetMinDelay(100), 0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+        fixture.propose(Action::SetPaused(true), u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+        fixture.execute(0, Sender(fixture.ali));
+        assert_eq!(fixture.queued_operation(1).unwrap().eta, 100);
+
+        fixture.assert_reverts_with(Error::OperationNotReady, |fixture| {
+            fixture.execute_operation(1, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_apply_proposal_once_min_delay_elapsed() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.queue_operation(Operation::SetMinDelay(100), 0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+        let action = Action::SetUri(String::from("ipfs://new-uri"));
+        fixture.propose(action, u64::MAX, Sender(fixture.ali));
+        fixture.approve(0, Sender(fixture.bob));
+        fixture.execute(0, Sender(fixture.ali));
+        assert_eq!(fixture.uri(), TestFixture::URI);
+
+        fixture.blocktime = 100;
+        fixture.execute_operation(1, Sender(fixture.bob));
+
+        assert_eq!(fixture.uri(), "ipfs://new-uri");
+        assert!(!fixture.queued_operation(1).unwrap().pending);
+    }
+
+    #[test]
+    fn should_not_queue_action_as_owner() {
+        let mut fixture = TestFixture::install_contract();
+        let operation = Operation::Action(Action::SetUri(String::from("ipfs://new-uri")));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.queue_operation(operation, 0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_execute_operation_after_delay() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.queue_operation(Operation::SetMinDelay(100), 1_000, Sender(fixture.ali));
+        assert_eq!(fixture.queued_operation(0).unwrap().eta, 1_000);
+
+        fixture.blocktime = 1_000;
+        fixture.execute_operation(0, Sender(fixture.ali));
+
+        assert_eq!(fixture.min_delay(), 100);
+        assert!(!fixture.queued_operation(0).unwrap().pending);
+    }
+
+    #[test]
+    fn should_not_execute_operation_before_eta() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.queue_operation(Operation::SetMinDelay(100), 1_000, Sender(fixture.ali));
+
+        fixture.blocktime = 999;
+        fixture.assert_reverts_with(Error::OperationNotReady, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_execute_cancelled_operation() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.queue_operation(Operation::SetMinDelay(100), 0, Sender(fixture.ali));
+        fixture.cancel_operation(0, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::UnknownOperation, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_queue_operation_below_min_delay() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.queue_operation(Operation::SetMinDelay(100), 0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+        assert_eq!(fixture.min_delay(), 100);
+
+        fixture.assert_reverts_with(Error::DelayTooShort, |fixture| {
+            fixture.queue_operation(Operation::SetMinDelay(0), 99, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_queue_operation_as_non_owner() {
+        let mut fixture = TestFixture::install_contract();
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.queue_operation(Operation::SetMinDelay(100), 0, Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_mint_above_supply_cap() {
+        let mut fixture = TestFixture::install_contract();
+        let ali = Key::from(fixture.ali);
+        let operation = Operation::SetSupplyCap {
+            id: String::from("gem"),
+            cap: Some(U256::from(5)),
+        };
+        fixture.queue_operation(operation, 0, Sender(fixture.ali));
+        fixture.execute_operation(0, Sender(fixture.ali));
+        assert_eq!(fixture.supply_cap("gem"), Some(U256::from(5)));
+        fixture.create(Some("gem"), U256::from(4), "", true, Sender(fixture.ali));
+        fixture.mint(ali, "gem", U256::one(), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::SupplyCapExceeded, |fixture| {
+            fixture.mint(ali, "gem", U256::one(), Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_transfer_ownership_once_accepted() {
+        let mut fixture = TestFixture::install_contract();
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.transfer_ownership(bob, Sender(fixture.ali));
+        assert_eq!(fixture.owner(), Some(ali));
+        assert_eq!(fixture.pending_owner(), Some(bob));
+
+        fixture.accept_ownership(Sender(fixture.bob));
+
+        assert_eq!(fixture.owner(), Some(bob));
+        assert_eq!(fixture.pending_owner(), None);
+    }
+
+    #[test]
+    fn should_not_accept_ownership_as_other_account() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.transfer_ownership(Key::from(fixture.bob), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.accept_ownership(Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_accept_cancelled_ownership_transfer() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.transfer_ownership(Key::from(fixture.bob), Sender(fixture.ali));
+        fixture.cancel_ownership_transfer(Sender(fixture.ali));
+        assert_eq!(fixture.pending_owner(), None);
+
+        fixture.assert_reverts_with(Error::NoPendingOwner, |fixture| {
+            fixture.accept_ownership(Sender(fixture.bob));
+        });
+    }
+
+    #[test]
+    fn should_not_transfer_ownership_before_queued_delay() {
+        let mut fixture = TestFixture::install_contract();
+        let operation = Operation::TransferOwnership(Address::from(fixture.bob));
+        fixture.queue_operation(operation, 100, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::OperationNotReady, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_transfer_ownership_through_timelock() {
+        let mut fixture = TestFixture::install_contract();
+        let bob = Key::from(fixture.bob);
+        let operation = Operation::TransferOwnership(Address::from(fixture.bob));
+        fixture.queue_operation(operation, 100, Sender(fixture.ali));
+        assert_eq!(fixture.pending_owner(), None);
+        fixture.blocktime += 100;
+
+        fixture.execute_operation(0, Sender(fixture.ali));
+        assert_eq!(fixture.pending_owner(), Some(bob));
+        fixture.accept_ownership(Sender(fixture.bob));
+
+        assert_eq!(fixture.owner(), Some(bob));
+    }
+
+    #[test]
+    fn should_not_execute_cancelled_timelocked_ownership_transfer() {
+        let mut fixture = TestFixture::install_contract();
+        let operation = Operation::TransferOwnership(Address::from(fixture.bob));
+        fixture.queue_operation(operation, 0, Sender(fixture.ali));
+        fixture.cancel_operation(0, Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::UnknownOperation, |fixture| {
+            fixture.execute_operation(0, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_not_act_as_owner_after_renouncing() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.renounce_ownership(Sender(fixture.ali));
+        assert_eq!(fixture.owner(), None);
+
+        fixture.assert_reverts_with(Error::PermissionDenied, |fixture| {
+            fixture.snapshot(Sender(fixture.ali));
+        });
+    }
+
+    fn caller_policy_fixture(caller_policy: CallerPolicy) -> TestFixture {
+        let mut fixture = TestFixture::install_contract_with_caller_policy(caller_policy);
+        fixture.install_test_call();
+        assert_eq!(fixture.caller_policy(), caller_policy);
+        fixture
+    }
+
+    #[test]
+    fn should_resolve_immediate_caller() {
+        let mut fixture = caller_policy_fixture(CallerPolicy::ImmediateCaller);
+        let (ali, joe) = (Key::from(fixture.ali), Key::from(fixture.joe));
+        let test_call = fixture.test_call_package();
+
+        fixture.set_approval_for_all_through_contract(joe, true, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(test_call, joe), Some(true));
+        assert_eq!(fixture.is_approval_for_all(ali, joe), None);
+
+        fixture.set_approval_for_all_through_nested_contracts(joe, false, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(test_call, joe), Some(false));
+
+        fixture.set_approval_for_all_through_stored_session(joe, true, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(ali, joe), Some(true));
+    }
+
+    #[test]
+    fn should_resolve_originating_account() {
+        let mut fixture = caller_policy_fixture(CallerPolicy::OriginatingAccount);
+        let (ali, joe) = (Key::from(fixture.ali), Key::from(fixture.joe));
+        let test_call = fixture.test_call_package();
+
+        fixture.set_approval_for_all_through_contract(joe, true, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(ali, joe), Some(true));
+
+        fixture.set_approval_for_all_through_nested_contracts(joe, false, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(ali, joe), Some(false));
+
+        fixture.set_approval_for_all_through_stored_session(joe, true, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(ali, joe), Some(true));
+        assert_eq!(fixture.is_approval_for_all(test_call, joe), None);
+    }
+
+    #[test]
+    fn should_resolve_contract_callers_when_rejecting_stored_sessions() {
+        let mut fixture = caller_policy_fixture(CallerPolicy::RejectStoredSessions);
+        let joe = Key::from(fixture.joe);
+        let test_call = fixture.test_call_package();
+
+        fixture.set_approval_for_all_through_contract(joe, true, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(test_call, joe), Some(true));
+
+        fixture.set_approval_for_all_through_nested_contracts(joe, false, Sender(fixture.ali));
+        assert_eq!(fixture.is_approval_for_all(test_call, joe), Some(false));
+    }
+
+    #[test]
+    fn should_reject_stored_session_caller() {
+        let mut fixture = caller_policy_fixture(CallerPolicy::RejectStoredSessions);
+        let joe = Key::from(fixture.joe);
+
+        fixture.assert_reverts_with(Error::InvalidContext, |fixture| {
+            fixture.set_approval_for_all_through_stored_session(joe, true, Sender(fixture.ali));
+        });
+    }
+
+    #[test]
+    fn should_transfer_to_many_recipients() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem", "gold"], Sender(fixture.ali));
+        let (ali, bob, joe) = (
+            Key::from(fixture.ali),
+            Key::from(fixture.bob),
+            Key::from(fixture.joe),
+        );
+        fixture.mint(ali, "gem", U256::from(10), Sender(fixture.ali));
+        fixture.mint(ali, "gold", U256::from(10), Sender(fixture.ali));
+
+        fixture.safe_multi_transfer_from(
+            ali,
+            vec![bob, joe, bob],
+            vec![
+                String::from("gem"),
+                String::from("gem"),
+                String::from("gold"),
+            ],
+            vec![U256::from(3), U256::from(4), U256::from(5)],
+            Sender(fixture.ali),
+        );
+
+        assert_eq!(fixture.balance_of(ali, "gem"), Some(U256::from(3)));
+        assert_eq!(fixture.balance_of(ali, "gold"), Some(U256::from(5)));
+        assert_eq!(fixture.balance_of(bob, "gem"), Some(U256::from(3)));
+        assert_eq!(fixture.balance_of(bob, "gold"), Some(U256::from(5)));
+        assert_eq!(fixture.balance_of(joe, "gem"), Some(U256::from(4)));
+    }
+
+    #[test]
+    fn should_not_transfer_to_many_recipients_above_aggregate_balance() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem"], Sender(fixture.ali));
+        let (ali, bob, joe) = (
+            Key::from(fixture.ali),
+            Key::from(fixture.bob),
+            Key::from(fixture.joe),
+        );
+        fixture.mint(ali, "gem", U256::from(10), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::InsufficientBalance, |fixture| {
+            fixture.safe_multi_transfer_from(
+                ali,
+                vec![bob, joe],
+                vec![String::from("gem"), String::from("gem")],
+                vec![U256::from(6), U256::from(6)],
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_not_transfer_to_many_recipients_with_length_mismatch() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "gem", U256::from(10), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::LengthMismatch, |fixture| {
+            fixture.safe_multi_transfer_from(
+                ali,
+                vec![bob],
+                vec![String::from("gem"), String::from("gem")],
+                vec![U256::from(1), U256::from(1)],
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_batch_transfer_repeated_ids() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem", "gold"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "gem", U256::from(10), Sender(fixture.ali));
+        fixture.mint(ali, "gold", U256::from(10), Sender(fixture.ali));
+
+        fixture.safe_batch_transfer_from(
+            ali,
+            bob,
+            vec![
+                String::from("gem"),
+                String::from("gold"),
+                String::from("gem"),
+            ],
+            vec![U256::from(2), U256::from(4), U256::from(3)],
+            Sender(fixture.ali),
+        );
+
+        assert_eq!(fixture.balance_of(ali, "gem"), Some(U256::from(5)));
+        assert_eq!(fixture.balance_of(bob, "gem"), Some(U256::from(5)));
+        assert_eq!(fixture.balance_of(ali, "gold"), Some(U256::from(6)));
+        assert_eq!(fixture.balance_of(bob, "gold"), Some(U256::from(4)));
+    }
+
+    #[test]
+    fn should_not_batch_transfer_repeated_ids_above_balance() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem"], Sender(fixture.ali));
+        let (ali, bob) = (Key::from(fixture.ali), Key::from(fixture.bob));
+        fixture.mint(ali, "gem", U256::from(10), Sender(fixture.ali));
+
+        fixture.assert_reverts_with(Error::InsufficientBalance, |fixture| {
+            fixture.safe_batch_transfer_from(
+                ali,
+                bob,
+                vec![String::from("gem"), String::from("gem")],
+                vec![U256::from(6), U256::from(5)],
+                Sender(fixture.ali),
+            );
+        });
+    }
+
+    #[test]
+    fn should_mint_and_burn_batch_with_repeated_ids() {
+        let mut fixture = TestFixture::install_contract();
+        fixture.create_ids(&["gem", "gold"], Sender(fixture.ali));
+        let ali = Key::from(fixture.ali);
+        let ids = vec![
+            String::from("gem"),
+            String::from("gold"),
+            String::from("gem"),
+        ];
+
+        fixture.mint_batch(
+            ali,
+            ids.clone(),
+            vec![U256::from(2), U256::from(4), U256::from(3)],
+            Sender(fixture.ali),
+        );
+        assert_eq!(fixture.balance_of(ali, "gem"), Some(U256::from(5)));
+        assert_eq!(fixture.total_supply("gem"), Some(U256::from(5)));
+        assert_eq!(fixture.total_supply("gold"), Some(U256::from(4)));
+
+        fixture.burn_batch(
+            ali,
+            ids,
+            vec![U256::from(1), U256::from(4), U256::from(1)],
+            Sender(fixture.ali),
+        );
+        assert_eq!(fixture.balance_of(ali, "gem"), Some(U256::from(3)));
+        assert_eq!(fixture.total_supply("gem"), Some(U256::from(3)));
+        assert_eq!(fixture.total_supply("gold"), Some(U256::zero()));
+    }
 }
 fn main() {
     panic!("Execute \"cargo test\" to test the contract, not \"cargo run\".");
@@ -0,0 +1,191 @@
+//! A pure-Rust reference model of the balances, total supplies, operators and creators of the
+//! example token.
+use std::collections::{BTreeMap, BTreeSet};
+
+use casper_erc1155::Error;
+use casper_types::{Key, U256};
+
+/// An operation run against both the model and the contract.
+#[derive(Clone, Debug)]
+pub enum Op {
+    Mint {
+        sender: usize,
+        to: usize,
+        id: String,
+        amount: U256,
+    },
+    Burn {
+        sender: usize,
+        owner: usize,
+        id: String,
+        amount: U256,
+    },
+    Transfer {
+        sender: usize,
+        from: usize,
+        to: usize,
+        id: String,
+        amount: U256,
+    },
+    BatchTransfer {
+        sender: usize,
+        from: usize,
+        to: usize,
+        ids: Vec<String>,
+        amounts: Vec<U256>,
+    },
+    SetApproval {
+        sender: usize,
+        operator: usize,
+        approved: bool,
+    },
+    /// Sets an operator of the test call contract, through the contract itself.
+    SetApprovalThroughContract {
+        sender: usize,
+        operator: usize,
+        approved: bool,
+    },
+}
+
+/// Expected state of the token.
+#[derive(Clone, Debug, Default)]
+pub struct Model {
+    pub balances: BTreeMap<(Key, String), U256>,
+    pub total_supplies: BTreeMap<String, U256>,
+    pub operators: BTreeSet<(Key, Key)>,
+    /// Creator of every token type, the only account which can mint it.
+    pub creators: BTreeMap<String, Key>,
+}
+
+impl Model {
+    pub fn balance_of(&self, account: Key, id: &str) -> U256 {
+        self.balances
+            .get(&(account, id.to_string()))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn total_supply(&self, id: &str) -> U256 {
+        self.total_supplies.get(id).copied().unwrap_or_default()
+    }
+
+    pub fn is_approval_for_all(&self, account: Key, operator: Key) -> bool {
+        self.operators.contains(&(account, operator))
+    }
+
+    /// Applies `op`, where `keys` are the addresses of the indices used by operations. The last
+    /// key is the test call contract. Failed operations leave the model untouched, like reverted
+    /// deploys.
+    pub fn apply(&mut self, op: &Op, keys: &[Key]) -> Result<(), Error> {
+        let mut next = self.clone();
+        next.apply_in_place(op, keys)?;
+        *self = next;
+        Ok(())
+    }
+
+    fn apply_in_place(&mut self, op: &Op, keys: &[Key]) -> Result<(), Error> {
+        match op {
+            Op::Mint {
+                sender,
+                to,
+                id,
+                amount,
+            } => {
+                if self.creators.get(id) != Some(&keys[*sender]) {
+                    return Err(Error::PermissionDenied);
+                }
+                let total_supply = self.total_supplies.entry(id.clone()).or_default();
+                *total_supply = total_supply.checked_add(*amount).ok_or(Error::Overflow)?;
+                self.credit(keys[*to], id, *amount)
+            }
+            Op::Burn {
+                sender,
+                owner,
+                id,
+                amount,
+            } => {
+                if !self.can_transfer(keys[*sender], keys[*owner]) {
+                    return Err(Error::PermissionDenied);
+                }
+                self.debit(keys[*owner], id, *amount)?;
+                let total_supply = self.total_supplies.entry(id.clone()).or_default();
+                *total_supply = total_supply.checked_sub(*amount).ok_or(Error::Overflow)?;
+                Ok(())
+            }
+            Op::Transfer {
+                sender,
+                from,
+                to,
+                id,
+                amount,
+            } => {
+                let (sender, from, to) = (keys[*sender], keys[*from], keys[*to]);
+                if !self.can_transfer(sender, from) || amount.is_zero() || from == to {
+                    return Ok(());
+                }
+                self.debit(from, id, *amount)?;
+                self.credit(to, id, *amount)
+            }
+            Op::BatchTransfer {
+                sender,
+                from,
+                to,
+                ids,
+                amounts,
+            } => {
+                let (sender, from, to) = (keys[*sender], keys[*from], keys[*to]);
+                if !self.can_transfer(sender, from) || from == to {
+                    return Ok(());
+                }
+                if ids.len() != amounts.len() {
+                    return Err(Error::LengthMismatch);
+                }
+                for (id, amount) in ids.iter().zip(amounts) {
+                    self.debit(from, id, *amount)?;
+                    self.credit(to, id, *amount)?;
+                }
+                Ok(())
+            }
+            Op::SetApproval {
+                sender,
+                operator,
+                approved,
+            } => {
+                self.set_operator(keys[*sender], keys[*operator], *approved);
+                Ok(())
+            }
+            Op::SetApprovalThroughContract {
+                operator, approved, ..
+            } => {
+                self.set_operator(keys[keys.len() - 1], keys[*operator], *approved);
+                Ok(())
+            }
+        }
+    }
+
+    fn can_transfer(&self, spender: Key, owner: Key) -> bool {
+        spender == owner || self.is_approval_for_all(owner, spender)
+    }
+
+    fn set_operator(&mut self, owner: Key, operator: Key, approved: bool) {
+        if approved {
+            self.operators.insert((owner, operator));
+        } else {
+            self.operators.remove(&(owner, operator));
+        }
+    }
+
+    fn credit(&mut self, account: Key, id: &str, amount: U256) -> Result<(), Error> {
+        let balance = self.balances.entry((account, id.to_string())).or_default();
+        *balance = balance.checked_add(amount).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+
+    fn debit(&mut self, account: Key, id: &str, amount: U256) -> Result<(), Error> {
+        let balance = self.balances.entry((account, id.to_string())).or_default();
+        *balance = balance
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientBalance)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use casper_types::{ApiError, Key, U256};
+use proptest::prelude::*;
+
+use crate::{
+    model::{Model, Op},
+    test_fixture::{Sender, TestFixture},
+};
+
+const IDS: &[&str] = &["gold", "silver"];
+/// Gold is created by Ali and silver by Bob.
+const CREATORS: &[(&str, usize)] = &[("gold", 0), ("silver", 1)];
+/// Ali and Bob are the funded accounts which can send deploys.
+const SENDERS: usize = 2;
+/// Ali, Bob, Joe and the test call contract.
+const HOLDERS: usize = 4;
+
+fn id() -> impl Strategy<Value = String> {
+    prop::sample::select(IDS).prop_map(String::from)
+}
+
+fn amount() -> impl Strategy<Value = U256> {
+    (0u64..20).prop_map(U256::from)
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let sender = 0..SENDERS;
+    let holder = || 0..HOLDERS;
+    prop_oneof![
+        (sender.clone(), holder(), id(), 1u64..50).prop_map(|(sender, to, id, amount)| Op::Mint {
+            sender,
+            to,
+            id,
+            amount: U256::from(amount),
+        }),
+        (sender.clone(), holder(), id(), amount()).prop_map(|(sender, owner, id, amount)| {
+            Op::Burn {
+                sender,
+                owner,
+                id,
+                amount,
+            }
+        }),
+        (sender.clone(), holder(), holder(), id(), amount()).prop_map(
+            |(sender, from, to, id, amount)| Op::Transfer {
+                sender,
+                from,
+                to,
+                id,
+                amount,
+            }
+        ),
+        (
+            sender.clone(),
+            holder(),
+            holder(),
+            prop::collection::vec((id(), amount()), 1..4),
+            prop::bool::weighted(0.1),
+        )
+            .prop_map(|(sender, from, to, transfers, mismatch)| {
+                let (ids, mut amounts): (Vec<String>, Vec<U256>) = transfers.into_iter().unzip();
+                if mismatch {
+                    amounts.pop();
+                }
+                Op::BatchTransfer {
+                    sender,
+                    from,
+                    to,
+                    ids,
+                    amounts,
+                }
+            }),
+        (sender.clone(), holder(), any::<bool>()).prop_map(|(sender, operator, approved)| {
+            Op::SetApproval {
+                sender,
+                operator,
+                approved,
+            }
+        }),
+        (sender, holder(), any::<bool>()).prop_map(|(sender, operator, approved)| {
+            Op::SetApprovalThroughContract {
+                sender,
+                operator,
+                approved,
+            }
+        }),
+    ]
+}
+
+/// Runs `op` against the contract.
+fn run(fixture: &mut TestFixture, op: &Op, keys: &[Key]) -> Result<(), String> {
+    let sender = |index: usize| Sender(*keys[index].as_account().unwrap());
+    fixture.try_call(|fixture| match op.clone() {
+        Op::Mint {
+            sender: from,
+            to,
+            id,
+            amount,
+        } => fixture.mint(keys[to], &id, amount, sender(from)),
+        Op::Burn {
+            sender: from,
+            owner,
+            id,
+            amount,
+        } => fixture.burn(keys[owner], &id, amount, sender(from)),
+        Op::Transfer {
+            sender: spender,
+            from,
+            to,
+            id,
+            amount,
+        } => fixture.safe_transfer_from(keys[from], keys[to], &id, amount, sender(spender)),
+        Op::BatchTransfer {
+            sender: spender,
+            from,
+            to,
+            ids,
+            amounts,
+        } => fixture.safe_batch_transfer_from(keys[from], keys[to], ids, amounts, sender(spender)),
+        Op::SetApproval {
+            sender: owner,
+            operator,
+            approved,
+        } => fixture.set_approval_for_all(keys[operator], approved, sender(owner)),
+        Op::SetApprovalThroughContract {
+            sender: from,
+            operator,
+            approved,
+        } => fixture.set_approval_for_all_through_contract(keys[operator], approved, sender(from)),
+    })
+}
+
+/// Asserts that the contract state matches the model.
+fn assert_state(fixture: &TestFixture, model: &Model, keys: &[Key]) {
+    for id in IDS {
+        let total_supply = fixture.total_supply(id).unwrap_or_default();
+        assert_eq!(
+            total_supply,
+            model.total_supply(id),
+            "total supply of {}",
+            id
+        );
+
+        let mut balances = U256::zero();
+        for account in keys {
+            let balance = fixture.balance_of(*account, id).unwrap_or_default();
+            assert_eq!(
+                balance,
+                model.balance_of(*account, id),
+                "balance of {} {}",
+                account,
+                id
+            );
+            balances += balance;
+        }
+        assert_eq!(balances, total_supply, "balances of {} add up", id);
+    }
+    for account in keys {
+        for operator in keys {
+            assert_eq!(
+                fixture
+                    .is_approval_for_all(*account, *operator)
+                    .unwrap_or_default(),
+                model.is_approval_for_all(*account, *operator),
+                "{} approved {}",
+                account,
+                operator
+            );
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn contract_should_match_reference_model(ops in prop::collection::vec(op(), 1..12)) {
+        let mut fixture = TestFixture::install_contract();
+        fixture.install_test_call();
+        let keys = vec![
+            Key::from(fixture.ali),
+            Key::from(fixture.bob),
+            Key::from(fixture.joe),
+            fixture.test_call_package(),
+        ];
+        let mut model = Model::default();
+        for (id, creator) in CREATORS {
+            let account = *keys[*creator].as_account().unwrap();
+            fixture.create_ids(&[*id], Sender(account));
+            model.creators.insert(id.to_string(), keys[*creator]);
+        }
+
+        for op in &ops {
+            let expected = model.apply(op, &keys);
+            let actual = run(&mut fixture, op, &keys);
+            match (expected, actual) {
+                (Ok(()), Ok(())) => {}
+                (Err(error), Err(message)) => {
+                    let expected = format!("{:?}", ApiError::from(error));
+                    prop_assert!(
+                        message.contains(&expected),
+                        "{:?} failed with `{}` instead of {}",
+                        op,
+                        message,
+                        expected
+                    );
+                }
+                (Ok(()), Err(message)) => {
+                    prop_assert!(false, "{:?} failed with `{}`", op, message)
+                }
+                (Err(error), Ok(())) => prop_assert!(
+                    false,
+                    "{:?} succeeded instead of failing with {:?}",
+                    op,
+                    ApiError::from(error)
+                ),
+            }
+        }
+        assert_state(&fixture, &model, &keys);
+    }
+}
@@ -1,17 +1,29 @@
+use std::panic::{self, AssertUnwindSafe};
+
 use blake2::{
     digest::{Update, VariableOutput},
     VarBlake2b,
 };
 use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
-use casper_erc1155::constants as consts;
+use casper_erc1155::{
+    constants as consts, Action, CallerPolicy, Error, Operation, Proposal, QueuedOperation, Rental,
+    TokenType,
+};
 use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
-    runtime_args, AsymmetricType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs, U256, U512,
+    runtime_args, ApiError, AsymmetricType, CLTyped, ContractHash, Key, PublicKey, RuntimeArgs,
+    U256, U512,
 };
 
 const CONTRACT_ERC1155_TOKEN: &str = "erc1155_token.wasm";
+const CONTRACT_ERC1155_PURCHASE: &str = "erc1155_purchase.wasm";
+const CONTRACT_ERC1155_TEST_CALL: &str = "erc1155_test_call.wasm";
 const CONTRACT_KEY_NAME: &str = "erc1155_token_contract";
+const TEST_CALL_KEY_NAME: &str = "erc1155_test_call";
+const TEST_CALL_CONTRACT_KEY_NAME: &str = "erc1155_test_call_contract";
+const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
+const TEST_CALL_CONTRACT_RUNTIME_ARG_NAME: &str = "test_call_contract";
 
 fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
     let mut hasher = VarBlake2b::new(32).unwrap();
@@ -27,11 +39,17 @@ pub struct TestFixture {
     pub ali: AccountHash,
     pub bob: AccountHash,
     pub joe: AccountHash,
+    /// Blocktime of the deploys sent by the fixture.
+    pub blocktime: u64,
 }
 impl TestFixture {
     pub const URI: &'static str = "https://myuri-example.com";
 
     pub fn install_contract() -> TestFixture {
+        TestFixture::install_contract_with_caller_policy(CallerPolicy::ImmediateCaller)
+    }
+
+    pub fn install_contract_with_caller_policy(caller_policy: CallerPolicy) -> TestFixture {
         let ali = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
         let bob = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
         let joe = PublicKey::ed25519_from_bytes([9u8; 32]).unwrap();
@@ -44,6 +62,13 @@ impl TestFixture {
         let session_code = Code::from(CONTRACT_ERC1155_TOKEN);
         let session_args = runtime_args! {
           consts::URI_RUNTIME_ARG_NAME => TestFixture::URI,
+          consts::SIGNERS_RUNTIME_ARG_NAME => vec![
+              Key::from(ali.to_account_hash()),
+              Key::from(bob.to_account_hash()),
+          ],
+          consts::THRESHOLD_RUNTIME_ARG_NAME => 2u32,
+          consts::MIN_DELAY_RUNTIME_ARG_NAME => 0u64,
+          consts::CALLER_POLICY_RUNTIME_ARG_NAME => caller_policy,
         };
 
         let session = SessionBuilder::new(session_code, session_args)
@@ -57,6 +82,7 @@ impl TestFixture {
             ali: ali.to_account_hash(),
             bob: bob.to_account_hash(),
             joe: joe.to_account_hash(),
+            blocktime: 0,
         }
     }
 
@@ -88,12 +114,42 @@ impl TestFixture {
         }
     }
 
+    /// Runs `call`, returning the panic message of a failed deploy instead of propagating it.
+    ///
+    /// Failed deploys are not committed, so the fixture can keep being used afterwards.
+    pub fn try_call<F: FnOnce(&mut TestFixture)>(&mut self, call: F) -> Result<(), String> {
+        panic::catch_unwind(AssertUnwindSafe(|| call(self))).map_err(|payload| {
+            payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| {
+                    payload
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    /// Runs `call`, and asserts that it reverted with `error`.
+    pub fn assert_reverts_with<F: FnOnce(&mut TestFixture)>(&mut self, error: Error, call: F) {
+        let message = self.try_call(call).expect_err("call should revert");
+        let api_error = format!("{:?}", ApiError::from(error));
+        assert!(
+            message.contains(&api_error),
+            "expected {}, got {}",
+            api_error,
+            message
+        );
+    }
+
     fn call(&mut self, sender: Sender, method: &str, args: RuntimeArgs) {
         let Sender(address) = sender;
         let code = Code::Hash(self.contract_hash().value(), method.to_string());
         let session = SessionBuilder::new(code, args)
             .with_address(address)
             .with_authorization_keys(&[address])
+            .with_block_time(self.blocktime)
             .build();
         self.context.run(session);
     }
@@ -232,6 +288,26 @@ impl TestFixture {
         );
     }
 
+    pub fn safe_multi_transfer_from(
+        &mut self,
+        from: Key,
+        recipients: Vec<Key>,
+        ids: Vec<String>,
+        amounts: Vec<U256>,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::FROM_RUNTIME_ARG_NAME => from,
+                consts::RECIPIENTS_RUNTIME_ARG_NAME => recipients,
+                consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+                consts::AMOUNTS_RUNTIME_ARG_NAME => amounts
+            },
+        );
+    }
+
     pub fn mint(&mut self, to: Key, id: &str, amount: U256, sender: Sender) {
         self.call(
             sender,
@@ -244,6 +320,30 @@ impl TestFixture {
         );
     }
 
+    pub fn mint_batch(&mut self, to: Key, ids: Vec<String>, amounts: Vec<U256>, sender: Sender) {
+        self.call(
+            sender,
+            consts::MINT_BATCH_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+                consts::AMOUNTS_RUNTIME_ARG_NAME => amounts
+            },
+        );
+    }
+
+    pub fn burn_batch(&mut self, owner: Key, ids: Vec<String>, amounts: Vec<U256>, sender: Sender) {
+        self.call(
+            sender,
+            consts::BURN_BATCH_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OWNER_RUNTIME_ARG_NAME => owner,
+                consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+                consts::AMOUNTS_RUNTIME_ARG_NAME => amounts
+            },
+        );
+    }
+
     pub fn burn(&mut self, owner: Key, id: &str, amount: U256, sender: Sender) {
         self.call(
             sender,
@@ -255,4 +355,624 @@ impl TestFixture {
             },
         );
     }
+
+    pub fn set_claim_root(&mut self, campaign: &str, root: [u8; 32], sender: Sender) {
+        self.call(
+            sender,
+            consts::SET_CLAIM_ROOT_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::CAMPAIGN_RUNTIME_ARG_NAME => campaign,
+                consts::ROOT_RUNTIME_ARG_NAME => root
+            },
+        );
+    }
+
+    pub fn claim(
+        &mut self,
+        campaign: &str,
+        id: &str,
+        amount: U256,
+        proof: Vec<[u8; 32]>,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::CLAIM_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::CAMPAIGN_RUNTIME_ARG_NAME => campaign,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+                consts::PROOF_RUNTIME_ARG_NAME => proof
+            },
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_sale(
+        &mut self,
+        id: &str,
+        price: U512,
+        start_time: u64,
+        end_time: u64,
+        wallet_limit: U256,
+        supply: U256,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::SET_SALE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::PRICE_RUNTIME_ARG_NAME => price,
+                consts::START_TIME_RUNTIME_ARG_NAME => start_time,
+                consts::END_TIME_RUNTIME_ARG_NAME => end_time,
+                consts::WALLET_LIMIT_RUNTIME_ARG_NAME => wallet_limit,
+                consts::SUPPLY_RUNTIME_ARG_NAME => supply
+            },
+        );
+    }
+
+    pub fn purchase(&mut self, id: &str, amount: U256, payment_amount: U512, sender: Sender) {
+        let Sender(address) = sender;
+        let session_code = Code::from(CONTRACT_ERC1155_PURCHASE);
+        let session_args = runtime_args! {
+            "token_contract" => self.contract_hash(),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            "payment_amount" => payment_amount
+        };
+        let session = SessionBuilder::new(session_code, session_args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .with_block_time(self.blocktime)
+            .build();
+        self.context.run(session);
+    }
+
+    pub fn withdraw(&mut self, amount: U512, sender: Sender) {
+        self.call(
+            sender,
+            consts::WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn unwrap(&mut self, id: &str, amount: U256, sender: Sender) {
+        self.call(
+            sender,
+            consts::UNWRAP_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount
+            },
+        );
+    }
+
+    pub fn redeem(&mut self, id: &str, sender: Sender) {
+        self.call(
+            sender,
+            consts::REDEEM_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id
+            },
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_offer(
+        &mut self,
+        taker: Option<Key>,
+        offered_ids: Vec<String>,
+        offered_amounts: Vec<U256>,
+        wanted_ids: Vec<String>,
+        wanted_amounts: Vec<U256>,
+        expiry: u64,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::CREATE_OFFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TAKER_RUNTIME_ARG_NAME => taker,
+                consts::OFFERED_IDS_RUNTIME_ARG_NAME => offered_ids,
+                consts::OFFERED_AMOUNTS_RUNTIME_ARG_NAME => offered_amounts,
+                consts::WANTED_IDS_RUNTIME_ARG_NAME => wanted_ids,
+                consts::WANTED_AMOUNTS_RUNTIME_ARG_NAME => wanted_amounts,
+                consts::EXPIRY_RUNTIME_ARG_NAME => expiry
+            },
+        );
+    }
+
+    pub fn accept_offer(&mut self, offer_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::ACCEPT_OFFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OFFER_ID_RUNTIME_ARG_NAME => offer_id
+            },
+        );
+    }
+
+    pub fn cancel_offer(&mut self, offer_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::CANCEL_OFFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OFFER_ID_RUNTIME_ARG_NAME => offer_id
+            },
+        );
+    }
+
+    pub fn snapshot(&mut self, sender: Sender) {
+        self.call(sender, consts::SNAPSHOT_ENTRY_POINT_NAME, runtime_args! {});
+    }
+
+    /// Looks up the first checkpoint recorded at or after `snapshot_id` in the snapshots
+    /// `dictionary`, the same way the contract does.
+    fn find_checkpoint(&self, dictionary: &str, preimage: &[u8], snapshot_id: u64) -> Option<U256> {
+        let key = Key::Hash(self.contract_hash().value());
+        let count = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(dictionary.to_string()),
+                base64::encode(blake2b256(preimage)),
+            )
+            .map(|value| value.into_t::<u64>().unwrap())
+            .unwrap_or_default();
+        (0..count)
+            .map(|index| {
+                let mut checkpoint_preimage = preimage.to_vec();
+                checkpoint_preimage.append(&mut index.to_bytes().unwrap());
+                self.context
+                    .query_dictionary_item(
+                        key,
+                        Some(dictionary.to_string()),
+                        base64::encode(blake2b256(&checkpoint_preimage)),
+                    )
+                    .unwrap()
+                    .into_t::<(u64, U256)>()
+                    .unwrap()
+            })
+            .find(|(checkpoint_id, _)| *checkpoint_id >= snapshot_id)
+            .map(|(_, value)| value)
+    }
+
+    pub fn balance_of_at(&self, account: Key, id: &str, snapshot_id: u64) -> Option<U256> {
+        let mut preimage = Vec::new();
+        preimage.append(&mut id.to_bytes().unwrap());
+        preimage.append(&mut account.to_bytes().unwrap());
+        self.find_checkpoint(consts::BALANCE_SNAPSHOTS_KEY_NAME, &preimage, snapshot_id)
+            .or_else(|| self.balance_of(account, id))
+    }
+
+    pub fn total_supply_at(&self, id: &str, snapshot_id: u64) -> Option<U256> {
+        let preimage = id.to_bytes().unwrap();
+        self.find_checkpoint(
+            consts::TOTAL_SUPPLY_SNAPSHOTS_KEY_NAME,
+            &preimage,
+            snapshot_id,
+        )
+        .or_else(|| self.total_supply(id))
+    }
+
+    pub fn call_balance_of_at(&mut self, account: Key, id: &str, snapshot_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::BALANCE_OF_AT_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::ACCOUNT_RUNTIME_ARG_NAME => account,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id
+            },
+        );
+    }
+
+    pub fn rental(&self, owner: Key, id: &str) -> Option<Rental> {
+        let mut preimage = Vec::new();
+        preimage.append(&mut id.to_bytes().unwrap());
+        preimage.append(&mut owner.to_bytes().unwrap());
+        let rental_key = base64::encode(blake2b256(&preimage));
+
+        let key = Key::Hash(self.contract_hash().value());
+        let rental = self
+            .context
+            .query_dictionary_item(key, Some(consts::RENTALS_KEY_NAME.to_string()), rental_key)
+            .ok()?;
+
+        Some(rental.into_t::<Rental>().unwrap())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_user(
+        &mut self,
+        owner: Key,
+        id: &str,
+        amount: U256,
+        user: Key,
+        expires: u64,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::SET_USER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OWNER_RUNTIME_ARG_NAME => owner,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+                consts::USER_RUNTIME_ARG_NAME => user,
+                consts::EXPIRES_RUNTIME_ARG_NAME => expires
+            },
+        );
+    }
+
+    pub fn revoke_user(&mut self, owner: Key, id: &str, sender: Sender) {
+        self.call(
+            sender,
+            consts::REVOKE_USER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OWNER_RUNTIME_ARG_NAME => owner,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id
+            },
+        );
+    }
+
+    pub fn mint_with_expiry(
+        &mut self,
+        to: Key,
+        id: &str,
+        amount: U256,
+        expiry: u64,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::MINT_WITH_EXPIRY_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+                consts::EXPIRY_RUNTIME_ARG_NAME => expiry
+            },
+        );
+    }
+
+    pub fn sweep_expired(&mut self, id: &str, accounts: Vec<Key>, sender: Sender) {
+        self.call(
+            sender,
+            consts::SWEEP_EXPIRED_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::ACCOUNTS_RUNTIME_ARG_NAME => accounts
+            },
+        );
+    }
+
+    pub fn token_type(&self, id: &str) -> Option<TokenType> {
+        let token_type_key = base64::encode(blake2b256(&id.to_bytes().unwrap()));
+
+        let key = Key::Hash(self.contract_hash().value());
+        let token_type = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(consts::TOKEN_TYPES_KEY_NAME.to_string()),
+                token_type_key,
+            )
+            .ok()?;
+
+        Some(token_type.into_t::<TokenType>().unwrap())
+    }
+
+    /// Creates the fungible token types `ids` without initial supply, so that `sender` can mint
+    /// them.
+    pub fn create_ids(&mut self, ids: &[&str], sender: Sender) {
+        for &id in ids {
+            self.create(Some(id), U256::zero(), "", true, sender);
+        }
+    }
+
+    pub fn create(
+        id: Option<&str>,
+        initial_supply: U256,
+        uri: &str,
+        fungible: bool,
+        sender: Sender,
+    ) {
+        self.call(
+            sender,
+            consts::CREATE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id.map(String::from),
+                consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => initial_supply,
+                consts::URI_RUNTIME_ARG_NAME => uri,
+                consts::FUNGIBLE_RUNTIME_ARG_NAME => fungible
+            },
+        );
+    }
+
+    pub fn set_minter(&mut self, id: &str, minter: Key, approved: bool, sender: Sender) {
+        self.call(
+            sender,
+            consts::SET_MINTER_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::MINTER_RUNTIME_ARG_NAME => minter,
+                consts::APPROVED_RUNTIME_ARG_NAME => approved
+            },
+        );
+    }
+
+    pub fn nft_owner(&self, id: &str) -> Option<Key> {
+        let owner_key = base64::encode(blake2b256(&id.to_bytes().unwrap()));
+
+        let key = Key::Hash(self.contract_hash().value());
+        let owner = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(consts::NFT_OWNERS_KEY_NAME.to_string()),
+                owner_key,
+            )
+            .ok()?;
+
+        Some(owner.into_t::<Key>().unwrap())
+    }
+
+    pub fn proposal(&self, proposal_id: u64) -> Option<Proposal> {
+        let key = Key::Hash(self.contract_hash().value());
+        let proposal = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(consts::PROPOSALS_KEY_NAME.to_string()),
+                proposal_id.to_string(),
+            )
+            .ok()?;
+
+        Some(proposal.into_t::<Proposal>().unwrap())
+    }
+
+    pub fn propose(&mut self, action: Action, expiry: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::PROPOSE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::ACTION_RUNTIME_ARG_NAME => action,
+                consts::EXPIRY_RUNTIME_ARG_NAME => expiry
+            },
+        );
+    }
+
+    pub fn approve(&mut self, proposal_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::APPROVE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::PROPOSAL_ID_RUNTIME_ARG_NAME => proposal_id
+            },
+        );
+    }
+
+    pub fn execute(&mut self, proposal_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::EXECUTE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::PROPOSAL_ID_RUNTIME_ARG_NAME => proposal_id
+            },
+        );
+    }
+
+    pub fn min_delay(&self) -> u64 {
+        self.query_contract(consts::MIN_DELAY_KEY_NAME).unwrap()
+    }
+
+    pub fn queued_operation(&self, operation_id: u64) -> Option<QueuedOperation> {
+        let key = Key::Hash(self.contract_hash().value());
+        let queued_operation = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(consts::OPERATIONS_KEY_NAME.to_string()),
+                operation_id.to_string(),
+            )
+            .ok()?;
+
+        Some(queued_operation.into_t::<QueuedOperation>().unwrap())
+    }
+
+    pub fn supply_cap(&self, id: &str) -> Option<U256> {
+        let key_bytes = blake2b256(&id.to_bytes().unwrap());
+        let supply_cap_key = base64::encode(key_bytes);
+
+        let key = Key::Hash(self.contract_hash().value());
+        let supply_cap = self
+            .context
+            .query_dictionary_item(
+                key,
+                Some(consts::SUPPLY_CAPS_KEY_NAME.to_string()),
+                supply_cap_key,
+            )
+            .ok()?;
+
+        supply_cap.into_t::<Option<U256>>().unwrap()
+    }
+
+    pub fn queue_operation(&mut self, operation: Operation, delay: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::QUEUE_OPERATION_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OPERATION_RUNTIME_ARG_NAME => operation,
+                consts::DELAY_RUNTIME_ARG_NAME => delay
+            },
+        );
+    }
+
+    pub fn cancel_operation(&mut self, operation_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::CANCEL_OPERATION_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OPERATION_ID_RUNTIME_ARG_NAME => operation_id
+            },
+        );
+    }
+
+    pub fn execute_operation(&mut self, operation_id: u64, sender: Sender) {
+        self.call(
+            sender,
+            consts::EXECUTE_OPERATION_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::OPERATION_ID_RUNTIME_ARG_NAME => operation_id
+            },
+        );
+    }
+
+    pub fn owner(&self) -> Option<Key> {
+        self.query_contract(consts::OWNER_KEY_NAME).unwrap()
+    }
+
+    pub fn pending_owner(&self) -> Option<Key> {
+        self.query_contract(consts::PENDING_OWNER_KEY_NAME).unwrap()
+    }
+
+    pub fn transfer_ownership(&mut self, new_owner: Key, sender: Sender) {
+        self.call(
+            sender,
+            consts::TRANSFER_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::NEW_OWNER_RUNTIME_ARG_NAME => new_owner
+            },
+        );
+    }
+
+    pub fn accept_ownership(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            consts::ACCEPT_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn cancel_ownership_transfer(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            consts::CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn renounce_ownership(&mut self, sender: Sender) {
+        self.call(
+            sender,
+            consts::RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+    }
+
+    pub fn caller_policy(&self) -> CallerPolicy {
+        self.query_contract(consts::CALLER_POLICY_KEY_NAME).unwrap()
+    }
+
+    pub fn install_test_call(&mut self) {
+        let session_code = Code::from(CONTRACT_ERC1155_TEST_CALL);
+        let session = SessionBuilder::new(session_code, runtime_args! {})
+            .with_address(self.ali)
+            .with_authorization_keys(&[self.ali])
+            .build();
+        self.context.run(session);
+    }
+
+    fn test_call_named_key(&self, name: &str) -> [u8; 32] {
+        self.context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(name)
+            .unwrap()
+            .normalize()
+            .into_hash()
+            .unwrap()
+    }
+
+    /// Returns the address of the test call contract package, as seen by the token.
+    pub fn test_call_package(&self) -> Key {
+        Key::Hash(self.test_call_named_key(TEST_CALL_KEY_NAME))
+    }
+
+    fn call_test_call(&mut self, sender: Sender, method: &str, args: RuntimeArgs) {
+        let Sender(address) = sender;
+        let test_call_contract = self.test_call_named_key(TEST_CALL_CONTRACT_KEY_NAME);
+        let code = Code::Hash(test_call_contract, method.to_string());
+        let session = SessionBuilder::new(code, args)
+            .with_address(address)
+            .with_authorization_keys(&[address])
+            .with_block_time(self.blocktime)
+            .build();
+        self.context.run(session);
+    }
+
+    /// Sets an operator through session -> test call contract -> token.
+    pub fn set_approval_for_all_through_contract(
+        &mut self,
+        operator: Key,
+        approved: bool,
+        sender: Sender,
+    ) {
+        let token_contract = self.contract_hash();
+        self.call_test_call(
+            sender,
+            "set_approval_for_all_stored_contract",
+            runtime_args! {
+                TOKEN_CONTRACT_RUNTIME_ARG_NAME => token_contract,
+                consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                consts::APPROVED_RUNTIME_ARG_NAME => approved
+            },
+        );
+    }
+
+    /// Sets an operator through stored session -> token.
+    pub fn set_approval_for_all_through_stored_session(
+        &mut self,
+        operator: Key,
+        approved: bool,
+        sender: Sender,
+    ) {
+        let token_contract = self.contract_hash();
+        self.call_test_call(
+            sender,
+            "set_approval_for_all_stored_session",
+            runtime_args! {
+                TOKEN_CONTRACT_RUNTIME_ARG_NAME => token_contract,
+                consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                consts::APPROVED_RUNTIME_ARG_NAME => approved
+            },
+        );
+    }
+
+    /// Sets an operator through session -> test call contract -> test call contract -> token.
+    pub fn set_approval_for_all_through_nested_contracts(
+        &mut self,
+        operator: Key,
+        approved: bool,
+        sender: Sender,
+    ) {
+        let token_contract = self.contract_hash();
+        let test_call_contract =
+            ContractHash::new(self.test_call_named_key(TEST_CALL_CONTRACT_KEY_NAME));
+        self.call_test_call(
+            sender,
+            "set_approval_for_all_nested",
+            runtime_args! {
+                TOKEN_CONTRACT_RUNTIME_ARG_NAME => token_contract,
+                TEST_CALL_CONTRACT_RUNTIME_ARG_NAME => test_call_contract,
+                consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                consts::APPROVED_RUNTIME_ARG_NAME => approved
+            },
+        );
+    }
 }
@@ -6,18 +6,31 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 
 extern crate alloc;
 
-use alloc::{string::String, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
 use casper_erc1155::{
     constants::{
-        ACCOUNTS_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, AMOUNTS_RUNTIME_ARG_NAME,
-        AMOUNT_RUNTIME_ARG_NAME, APPROVED_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME,
-        OPERATOR_RUNTIME_ARG_NAME, OWNER_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
-        TOKEN_IDS_RUNTIME_ARG_NAME, TOKEN_ID_RUNTIME_ARG_NAME, URI_RUNTIME_ARG_NAME,
+        ACCOUNTS_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, ACTION_RUNTIME_ARG_NAME,
+        AMOUNTS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME, APPROVED_RUNTIME_ARG_NAME,
+        CALLER_POLICY_RUNTIME_ARG_NAME, CAMPAIGN_RUNTIME_ARG_NAME, DELAY_RUNTIME_ARG_NAME,
+        END_TIME_RUNTIME_ARG_NAME, ERC20_CONTRACT_RUNTIME_ARG_NAME, EXPIRES_RUNTIME_ARG_NAME,
+        EXPIRY_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME, FUNGIBLE_RUNTIME_ARG_NAME,
+        INITIAL_SUPPLY_RUNTIME_ARG_NAME, MINTER_RUNTIME_ARG_NAME, MIN_DELAY_RUNTIME_ARG_NAME,
+        NEW_OWNER_RUNTIME_ARG_NAME, NFT_CONTRACT_RUNTIME_ARG_NAME, NFT_TOKEN_ID_RUNTIME_ARG_NAME,
+        OFFERED_AMOUNTS_RUNTIME_ARG_NAME, OFFERED_IDS_RUNTIME_ARG_NAME, OFFER_ID_RUNTIME_ARG_NAME,
+        OPERATION_ID_RUNTIME_ARG_NAME, OPERATION_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME,
+        OWNER_RUNTIME_ARG_NAME, PRICE_RUNTIME_ARG_NAME, PROOF_RUNTIME_ARG_NAME,
+        PROPOSAL_ID_RUNTIME_ARG_NAME, PURSE_RUNTIME_ARG_NAME, RECIPIENTS_RUNTIME_ARG_NAME,
+        RECIPIENT_RUNTIME_ARG_NAME, ROOT_RUNTIME_ARG_NAME, SIGNERS_RUNTIME_ARG_NAME,
+        SNAPSHOT_ID_RUNTIME_ARG_NAME, START_TIME_RUNTIME_ARG_NAME, SUPPLY_RUNTIME_ARG_NAME,
+        TAKER_RUNTIME_ARG_NAME, THRESHOLD_RUNTIME_ARG_NAME, TOKEN_IDS_RUNTIME_ARG_NAME,
+        TOKEN_ID_RUNTIME_ARG_NAME, URI_RUNTIME_ARG_NAME, USER_RUNTIME_ARG_NAME,
+        WALLET_LIMIT_RUNTIME_ARG_NAME, WANTED_AMOUNTS_RUNTIME_ARG_NAME,
+        WANTED_IDS_RUNTIME_ARG_NAME,
     },
-    Address, ERC1155,
+    entry_points, Action, Address, Config, Operation, ERC1155,
 };
-use casper_types::{CLValue, U256};
+use casper_types::{CLValue, ContractHash, URef, U256, U512};
 
 #[no_mangle]
 pub extern "C" fn uri() {
@@ -87,12 +100,25 @@ pub extern "C" fn safe_batch_transfer_from() {
         .unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn safe_multi_transfer_from() {
+    let from: Address = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
+    let recipients: Vec<Address> = runtime::get_named_arg(RECIPIENTS_RUNTIME_ARG_NAME);
+    let ids: Vec<String> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .safe_multi_transfer_from(from, recipients, ids, amounts)
+        .unwrap_or_revert();
+}
+
 #[no_mangle]
 pub extern "C" fn mint() {
     let to: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
     let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
-    ERC1155::default().mint(to, &id, amount).unwrap_or_revert();
+    let mut token = ERC1155::default();
+    token.only_minter(&id).unwrap_or_revert();
+    token.mint(to, &id, amount).unwrap_or_revert();
 }
 
 #[no_mangle]
@@ -100,13 +126,431 @@ pub extern "C" fn burn() {
     let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
     let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let mut token = ERC1155::default();
+    token.only_holder_or_operator(owner).unwrap_or_revert();
+    token.burn(owner, &id, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn mint_batch() {
+    let to: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let ids: Vec<String> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    let mut token = ERC1155::default();
+    for id in &ids {
+        token.only_minter(id).unwrap_or_revert();
+    }
+    token.mint_batch(to, ids, amounts).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn burn_batch() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let ids: Vec<String> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    let mut token = ERC1155::default();
+    token.only_holder_or_operator(owner).unwrap_or_revert();
+    token.burn_batch(owner, ids, amounts).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_claim_root() {
+    let campaign: String = runtime::get_named_arg(CAMPAIGN_RUNTIME_ARG_NAME);
+    let root: [u8; 32] = runtime::get_named_arg(ROOT_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .set_claim_root(&campaign, root)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn claim() {
+    let campaign: String = runtime::get_named_arg(CAMPAIGN_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let proof: Vec<[u8; 32]> = runtime::get_named_arg(PROOF_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .claim(&campaign, &id, amount, proof)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_sale() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let start_time: u64 = runtime::get_named_arg(START_TIME_RUNTIME_ARG_NAME);
+    let end_time: u64 = runtime::get_named_arg(END_TIME_RUNTIME_ARG_NAME);
+    let wallet_limit: U256 = runtime::get_named_arg(WALLET_LIMIT_RUNTIME_ARG_NAME);
+    let supply: U256 = runtime::get_named_arg(SUPPLY_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .set_sale(&id, price, start_time, end_time, wallet_limit, supply)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn purchase() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let purse: URef = runtime::get_named_arg(PURSE_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .purchase(&id, amount, purse)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw() {
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC1155::default().withdraw(amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn wrap() {
+    let erc20_contract: ContractHash = runtime::get_named_arg(ERC20_CONTRACT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
     ERC1155::default()
-        .burn(owner, &id, amount)
+        .wrap(erc20_contract, amount)
         .unwrap_or_revert();
 }
 
+#[no_mangle]
+pub extern "C" fn unwrap() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC1155::default().unwrap(&id, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn fractionalize() {
+    let nft_contract: ContractHash = runtime::get_named_arg(NFT_CONTRACT_RUNTIME_ARG_NAME);
+    let token_id: U256 = runtime::get_named_arg(NFT_TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .fractionalize(nft_contract, token_id, amount)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn redeem() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    ERC1155::default().redeem(&id).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn fractionalized_nft() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let nft = ERC1155::default().fractionalized_nft(&id);
+    runtime::ret(CLValue::from_t(nft).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn is_nft_locked() {
+    let nft_contract: ContractHash = runtime::get_named_arg(NFT_CONTRACT_RUNTIME_ARG_NAME);
+    let token_id: U256 = runtime::get_named_arg(NFT_TOKEN_ID_RUNTIME_ARG_NAME);
+    let locked = ERC1155::default().is_nft_locked(nft_contract, token_id);
+    runtime::ret(CLValue::from_t(locked).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn create_offer() {
+    let taker: Option<Address> = runtime::get_named_arg(TAKER_RUNTIME_ARG_NAME);
+    let offered_ids: Vec<String> = runtime::get_named_arg(OFFERED_IDS_RUNTIME_ARG_NAME);
+    let offered_amounts: Vec<U256> = runtime::get_named_arg(OFFERED_AMOUNTS_RUNTIME_ARG_NAME);
+    let wanted_ids: Vec<String> = runtime::get_named_arg(WANTED_IDS_RUNTIME_ARG_NAME);
+    let wanted_amounts: Vec<U256> = runtime::get_named_arg(WANTED_AMOUNTS_RUNTIME_ARG_NAME);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY_RUNTIME_ARG_NAME);
+    let offer_id = ERC1155::default()
+        .create_offer(
+            taker,
+            offered_ids,
+            offered_amounts,
+            wanted_ids,
+            wanted_amounts,
+            expiry,
+        )
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(offer_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn accept_offer() {
+    let offer_id: u64 = runtime::get_named_arg(OFFER_ID_RUNTIME_ARG_NAME);
+    ERC1155::default().accept_offer(offer_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_offer() {
+    let offer_id: u64 = runtime::get_named_arg(OFFER_ID_RUNTIME_ARG_NAME);
+    ERC1155::default().cancel_offer(offer_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn snapshot() {
+    let snapshot_id = ERC1155::default().snapshot().unwrap_or_revert();
+    runtime::ret(CLValue::from_t(snapshot_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+    let account: Address = runtime::get_named_arg(ACCOUNT_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+    let balance = ERC1155::default()
+        .balance_of_at(account, &id, snapshot_id)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply_at() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+    let total_supply = ERC1155::default()
+        .total_supply_at(&id, snapshot_id)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_user() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let user: Address = runtime::get_named_arg(USER_RUNTIME_ARG_NAME);
+    let expires: u64 = runtime::get_named_arg(EXPIRES_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .set_user(owner, &id, amount, user, expires)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_user() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .revoke_user(owner, &id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn user_of() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let user = ERC1155::default().user_of(owner, &id);
+    runtime::ret(CLValue::from_t(user).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn usable_balance_of() {
+    let user: Address = runtime::get_named_arg(USER_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let usable_balance = ERC1155::default().usable_balance_of(user, &id);
+    runtime::ret(CLValue::from_t(usable_balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn mint_with_expiry() {
+    let to: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY_RUNTIME_ARG_NAME);
+    let mut token = ERC1155::default();
+    token.only_minter(&id).unwrap_or_revert();
+    token
+        .mint_with_expiry(to, &id, amount, expiry)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn sweep_expired() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let accounts: Vec<Address> = runtime::get_named_arg(ACCOUNTS_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .sweep_expired(&id, accounts)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn expiry_of() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let expiry = ERC1155::default().expiry_of(&id);
+    runtime::ret(CLValue::from_t(expiry).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn create() {
+    let id: Option<String> = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let initial_supply: U256 = runtime::get_named_arg(INITIAL_SUPPLY_RUNTIME_ARG_NAME);
+    let uri: String = runtime::get_named_arg(URI_RUNTIME_ARG_NAME);
+    let fungible: bool = runtime::get_named_arg(FUNGIBLE_RUNTIME_ARG_NAME);
+    let id = ERC1155::default()
+        .create(id, initial_supply, uri, fungible)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_minter() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let minter: Address = runtime::get_named_arg(MINTER_RUNTIME_ARG_NAME);
+    let approved: bool = runtime::get_named_arg(APPROVED_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .set_minter(&id, minter, approved)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn creator_of() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let creator = ERC1155::default().creator_of(&id);
+    runtime::ret(CLValue::from_t(creator).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn exists() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let exists = ERC1155::default().exists(&id);
+    runtime::ret(CLValue::from_t(exists).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn owner_of() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let owner = ERC1155::default().owner_of(&id);
+    runtime::ret(CLValue::from_t(owner).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn propose() {
+    let action: Action = runtime::get_named_arg(ACTION_RUNTIME_ARG_NAME);
+    let expiry: u64 = runtime::get_named_arg(EXPIRY_RUNTIME_ARG_NAME);
+    let proposal_id = ERC1155::default()
+        .propose(action, expiry)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(proposal_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID_RUNTIME_ARG_NAME);
+    ERC1155::default().approve(proposal_id).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn execute() {
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID_RUNTIME_ARG_NAME);
+    let operation_id = ERC1155::default().execute(proposal_id).unwrap_or_revert();
+    runtime::ret(CLValue::from_t(operation_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn queue_operation() {
+    let operation: Operation = runtime::get_named_arg(OPERATION_RUNTIME_ARG_NAME);
+    let delay: u64 = runtime::get_named_arg(DELAY_RUNTIME_ARG_NAME);
+    let operation_id = ERC1155::default()
+        .queue_operation(operation, delay)
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(operation_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_operation() {
+    let operation_id: u64 = runtime::get_named_arg(OPERATION_ID_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .cancel_operation(operation_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn execute_operation() {
+    let operation_id: u64 = runtime::get_named_arg(OPERATION_ID_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .execute_operation(operation_id)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn min_delay() {
+    let min_delay = ERC1155::default().min_delay();
+    runtime::ret(CLValue::from_t(min_delay).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn supply_cap() {
+    let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let supply_cap = ERC1155::default().supply_cap(&id);
+    runtime::ret(CLValue::from_t(supply_cap).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn caller_policy() {
+    let caller_policy = ERC1155::default().caller_policy();
+    runtime::ret(CLValue::from_t(caller_policy).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn owner() {
+    let owner = ERC1155::default().owner();
+    runtime::ret(CLValue::from_t(owner).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn pending_owner() {
+    let pending_owner = ERC1155::default().pending_owner();
+    runtime::ret(CLValue::from_t(pending_owner).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_ownership() {
+    let new_owner: Address = runtime::get_named_arg(NEW_OWNER_RUNTIME_ARG_NAME);
+    ERC1155::default()
+        .transfer_ownership(new_owner)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn accept_ownership() {
+    ERC1155::default().accept_ownership().unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_ownership_transfer() {
+    ERC1155::default()
+        .cancel_ownership_transfer()
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn renounce_ownership() {
+    ERC1155::default().renounce_ownership().unwrap_or_revert();
+}
+
 #[no_mangle]
 fn call() {
     let uri = runtime::get_named_arg(URI_RUNTIME_ARG_NAME);
-    let _token = ERC1155::install(uri).unwrap_or_revert();
+    let extensions = [
+        entry_points::claims(),
+        entry_points::sales(),
+        entry_points::wrapper(),
+        entry_points::fractions(),
+        entry_points::escrow(),
+        entry_points::snapshots(),
+        entry_points::rentals(),
+        entry_points::expiries(),
+        entry_points::registry(),
+        entry_points::multisig(),
+        entry_points::timelock(),
+        entry_points::ownership(),
+        vec![
+            entry_points::caller_policy(),
+            entry_points::safe_multi_transfer_from(),
+        ],
+    ]
+    .concat();
+    let config = Config {
+        signers: runtime::get_named_arg(SIGNERS_RUNTIME_ARG_NAME),
+        threshold: runtime::get_named_arg(THRESHOLD_RUNTIME_ARG_NAME),
+        min_delay: runtime::get_named_arg(MIN_DELAY_RUNTIME_ARG_NAME),
+        // See the security notes of `CallerPolicy::OriginatingAccount` before installing with it.
+        caller_policy: runtime::get_named_arg(CALLER_POLICY_RUNTIME_ARG_NAME),
+    };
+    let _token = ERC1155::install_with_config(uri, extensions, config).unwrap_or_revert();
 }
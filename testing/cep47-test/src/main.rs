@@ -0,0 +1,219 @@
+//! Minimal CEP-47 NFT exposing the entry points which the fractionalization extension of ERC1155
+//! calls.
+//!
+//! It only implements `owner_of`, `approve`, `transfer` and `transfer_from`, and shouldn't be used
+//! as a template for a real CEP-47 token.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc1155::{Address, Error};
+use casper_types::{
+    bytesrepr::ToBytes, system::CallStackElement, CLType, CLTyped, CLValue, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, URef, U256,
+};
+
+const OWNER_OF_ENTRY_POINT_NAME: &str = "owner_of";
+const APPROVE_ENTRY_POINT_NAME: &str = "approve";
+const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+const TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
+const TOKEN_IDS_RUNTIME_ARG_NAME: &str = "token_ids";
+const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const SENDER_RUNTIME_ARG_NAME: &str = "sender";
+const RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
+const OWNERS_KEY_NAME: &str = "owners";
+const ALLOWANCES_KEY_NAME: &str = "allowances";
+const CEP47_TEST_KEY: &str = "cep47_test";
+const CEP47_TEST_CONTRACT_KEY: &str = "cep47_test_contract";
+
+/// Amount of NFTs minted to the installing account, with the token ids `1` to `MINTED_COUNT`.
+const MINTED_COUNT: u64 = 3;
+
+/// Returns the address which called the current entry point.
+fn get_immediate_caller_address() -> Address {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.iter().nth_back(1).unwrap_or_revert() {
+        CallStackElement::Session { account_hash }
+        | CallStackElement::StoredSession { account_hash, .. } => Address::from(*account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Address::from(*contract_package_hash),
+    }
+}
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert()
+}
+
+fn make_dictionary_item_key(token_id: U256) -> String {
+    let key_bytes = runtime::blake2b(token_id.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+fn read_owner(token_id: U256) -> Option<Address> {
+    storage::dictionary_get(
+        get_uref(OWNERS_KEY_NAME),
+        &make_dictionary_item_key(token_id),
+    )
+    .unwrap_or_revert()
+}
+
+fn write_owner(token_id: U256, owner: Address) {
+    storage::dictionary_put(
+        get_uref(OWNERS_KEY_NAME),
+        &make_dictionary_item_key(token_id),
+        owner,
+    );
+}
+
+fn read_allowance(token_id: U256) -> Option<Address> {
+    storage::dictionary_get(
+        get_uref(ALLOWANCES_KEY_NAME),
+        &make_dictionary_item_key(token_id),
+    )
+    .unwrap_or_revert()
+}
+
+fn write_allowance(token_id: U256, spender: Option<Address>) {
+    storage::dictionary_put(
+        get_uref(ALLOWANCES_KEY_NAME),
+        &make_dictionary_item_key(token_id),
+        spender,
+    );
+}
+
+/// Moves `token_ids` from `sender` to `recipient`, clearing their allowances.
+fn transfer_tokens(sender: Address, recipient: Address, token_ids: Vec<U256>) {
+    for token_id in token_ids {
+        if read_owner(token_id) != Some(sender) {
+            runtime::revert(Error::PermissionDenied);
+        }
+        write_owner(token_id, recipient);
+        write_allowance(token_id, None);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn owner_of() {
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let owner = read_owner(token_id);
+    runtime::ret(CLValue::from_t(owner).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let token_ids: Vec<U256> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let caller = get_immediate_caller_address();
+    for token_id in token_ids {
+        if read_owner(token_id) != Some(caller) {
+            runtime::revert(Error::PermissionDenied);
+        }
+        write_allowance(token_id, Some(spender));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let token_ids: Vec<U256> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    transfer_tokens(get_immediate_caller_address(), recipient, token_ids);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let sender: Address = runtime::get_named_arg(SENDER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let token_ids: Vec<U256> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let spender = get_immediate_caller_address();
+    for token_id in &token_ids {
+        if read_allowance(*token_id) != Some(spender) {
+            runtime::revert(Error::InsufficientAllowance);
+        }
+    }
+    transfer_tokens(sender, recipient, token_ids);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        OWNER_OF_ENTRY_POINT_NAME,
+        vec![Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, U256::cl_type())],
+        Option::<Address>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        APPROVE_ENTRY_POINT_NAME,
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        TRANSFER_ENTRY_POINT_NAME,
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        vec![
+            Parameter::new(SENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let owners_uref = storage::new_dictionary(OWNERS_KEY_NAME).unwrap_or_revert();
+    let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
+    let installer = Address::from(runtime::get_caller());
+    for token_id in 1..=MINTED_COUNT {
+        storage::dictionary_put(
+            owners_uref,
+            &make_dictionary_item_key(U256::from(token_id)),
+            installer,
+        );
+    }
+    runtime::remove_key(OWNERS_KEY_NAME);
+    runtime::remove_key(ALLOWANCES_KEY_NAME);
+
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(OWNERS_KEY_NAME.to_string(), Key::from(owners_uref));
+    named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), Key::from(allowances_uref));
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(CEP47_TEST_KEY.to_string()),
+        None,
+    );
+    runtime::put_key(CEP47_TEST_CONTRACT_KEY, Key::from(contract_hash));
+}
@@ -15,11 +15,12 @@ use casper_contract::{
 };
 use casper_erc1155::{
     constants::{
-        ACCOUNTS_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
-        AMOUNTS_RUNTIME_ARG_NAME, APPROVED_RUNTIME_ARG_NAME, OPERATOR_RUNTIME_ARG_NAME,
-        RECIPIENT_RUNTIME_ARG_NAME, SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME,
-        SAFE_TRANSFER_FROM_ENTRY_POINT_NAME, SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
-        TOKEN_IDS_RUNTIME_ARG_NAME, TOKEN_ID_RUNTIME_ARG_NAME,
+        ACCOUNTS_RUNTIME_ARG_NAME, ACCOUNT_RUNTIME_ARG_NAME, AMOUNTS_RUNTIME_ARG_NAME,
+        AMOUNT_RUNTIME_ARG_NAME, APPROVED_RUNTIME_ARG_NAME, FROM_RUNTIME_ARG_NAME,
+        OPERATOR_RUNTIME_ARG_NAME, RECIPIENT_RUNTIME_ARG_NAME,
+        SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME, SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+        SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME, TOKEN_IDS_RUNTIME_ARG_NAME,
+        TOKEN_ID_RUNTIME_ARG_NAME,
     },
     Address,
 };
@@ -38,9 +39,20 @@ const SAFE_TRANSFER_FROM_STORED_CONTRACT_ENTRY_POINT_NAME: &str =
     "safe_transfer_from_stored_contract";
 const SAFE_BATCH_TRANFER_FROM_STORED_CONTRACT_ENTRY_POINT_NAME: &str =
     "safe_batch_transfer_from_stored_contract";
+const SET_APPROVAL_FOR_ALL_STORED_SESSION_ENTRY_POINT_NAME: &str =
+    "set_approval_for_all_stored_session";
+const SET_APPROVAL_FOR_ALL_NESTED_ENTRY_POINT_NAME: &str = "set_approval_for_all_nested";
+const CHECK_ERC20_BALANCE_OF_ENTRY_POINT_NAME: &str = "check_erc20_balance_of";
+const ERC20_BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+const ERC20_ADDRESS_RUNTIME_ARG_NAME: &str = "address";
+const CHECK_NFT_OWNER_OF_ENTRY_POINT_NAME: &str = "check_nft_owner_of";
+const NFT_OWNER_OF_ENTRY_POINT_NAME: &str = "owner_of";
+const NFT_TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
+const TEST_CALL_CONTRACT_RUNTIME_ARG_NAME: &str = "test_call_contract";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const RESULT_KEY: &str = "result";
 const ERC1155_TEST_CALL_KEY: &str = "erc1155_test_call";
+const ERC1155_TEST_CALL_CONTRACT_KEY: &str = "erc1155_test_call_contract";
 
 fn store_result<T: CLTyped + ToBytes>(result: T) {
     match runtime::get_key(RESULT_KEY) {
@@ -88,8 +100,8 @@ extern "C" fn check_balance_of() {
 #[no_mangle]
 extern "C" fn check_balance_of_batch() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
-    let accounts: Address = runtime::get_named_arg(ACCOUNTS_RUNTIME_ARG_NAME);
-    let ids: String = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let accounts: Vec<Address> = runtime::get_named_arg(ACCOUNTS_RUNTIME_ARG_NAME);
+    let ids: Vec<String> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
 
     let balance_args = runtime_args! {
         casper_erc1155::constants::ACCOUNTS_RUNTIME_ARG_NAME => accounts,
@@ -141,11 +153,13 @@ extern "C" fn check_is_approval_for_all() {
 #[no_mangle]
 extern "C" fn safe_transfer_from_stored_contract() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let from: Address = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
     let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
     let id: String = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
     let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
 
     let transfer_args = runtime_args! {
+        FROM_RUNTIME_ARG_NAME => from,
         RECIPIENT_RUNTIME_ARG_NAME => recipient,
         TOKEN_ID_RUNTIME_ARG_NAME => id,
         AMOUNT_RUNTIME_ARG_NAME => amount,
@@ -160,11 +174,13 @@ extern "C" fn safe_transfer_from_stored_contract() {
 #[no_mangle]
 extern "C" fn safe_batch_transfer_from_stored_contract() {
     let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let from: Address = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
     let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
-    let ids: String = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
-    let amounts: U256 = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
+    let ids: Vec<String> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let amounts: Vec<U256> = runtime::get_named_arg(AMOUNTS_RUNTIME_ARG_NAME);
 
     let transfer_args = runtime_args! {
+        FROM_RUNTIME_ARG_NAME => from,
         RECIPIENT_RUNTIME_ARG_NAME => recipient,
         TOKEN_IDS_RUNTIME_ARG_NAME => ids,
         AMOUNTS_RUNTIME_ARG_NAME => amounts,
@@ -177,6 +193,81 @@ extern "C" fn safe_batch_transfer_from_stored_contract() {
     );
 }
 
+/// Calls `set_approval_for_all` of the token from stored session code, so the token sees a
+/// `StoredSession` as its immediate caller.
+#[no_mangle]
+extern "C" fn set_approval_for_all_stored_session() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let operator: Address = runtime::get_named_arg(OPERATOR_RUNTIME_ARG_NAME);
+    let approved: bool = runtime::get_named_arg(APPROVED_RUNTIME_ARG_NAME);
+
+    let set_approval_args = runtime_args! {
+        OPERATOR_RUNTIME_ARG_NAME => operator,
+        APPROVED_RUNTIME_ARG_NAME => approved,
+    };
+
+    runtime::call_contract::<()>(
+        token_contract,
+        SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+        set_approval_args,
+    );
+}
+/// Calls `set_approval_for_all_stored_contract` of this contract, so the token is called by a
+/// contract which was itself called by a contract.
+#[no_mangle]
+extern "C" fn set_approval_for_all_nested() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let test_call_contract: ContractHash =
+        runtime::get_named_arg(TEST_CALL_CONTRACT_RUNTIME_ARG_NAME);
+    let operator: Address = runtime::get_named_arg(OPERATOR_RUNTIME_ARG_NAME);
+    let approved: bool = runtime::get_named_arg(APPROVED_RUNTIME_ARG_NAME);
+
+    let set_approval_args = runtime_args! {
+        TOKEN_CONTRACT_RUNTIME_ARG_NAME => token_contract,
+        OPERATOR_RUNTIME_ARG_NAME => operator,
+        APPROVED_RUNTIME_ARG_NAME => approved,
+    };
+
+    runtime::call_contract::<()>(
+        test_call_contract,
+        SET_APPROVAL_FOR_ALL_STORED_CONTRACT_ENTRY_POINT_NAME,
+        set_approval_args,
+    );
+}
+
+/// Reads the balance of `address` in the ERC20 token `token_contract`.
+#[no_mangle]
+extern "C" fn check_erc20_balance_of() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let address: Address = runtime::get_named_arg(ERC20_ADDRESS_RUNTIME_ARG_NAME);
+
+    let balance_args = runtime_args! {
+        ERC20_ADDRESS_RUNTIME_ARG_NAME => address,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        ERC20_BALANCE_OF_ENTRY_POINT_NAME,
+        balance_args,
+    );
+
+    store_result(result);
+}
+
+/// Reads the owner of `token_id` in the CEP-47 token `token_contract`.
+#[no_mangle]
+extern "C" fn check_nft_owner_of() {
+    let token_contract: ContractHash = runtime::get_named_arg(TOKEN_CONTRACT_RUNTIME_ARG_NAME);
+    let token_id: U256 = runtime::get_named_arg(NFT_TOKEN_ID_RUNTIME_ARG_NAME);
+
+    let owner_of_args = runtime_args! {
+        NFT_TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+    };
+    let result: Option<Address> =
+        runtime::call_contract(token_contract, NFT_OWNER_OF_ENTRY_POINT_NAME, owner_of_args);
+
+    store_result(result);
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
     let mut entry_points = EntryPoints::new();
@@ -184,9 +275,9 @@ pub extern "C" fn call() {
     let check_total_supply_entrypoint = EntryPoint::new(
         String::from(CHECK_TOTAL_SUPPLY_ENTRY_POINT_NAME),
         vec![
-          Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
-          Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
-          ],
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
+        ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -228,8 +319,8 @@ pub extern "C" fn call() {
         String::from(CHECK_IS_APPROVAL_FOR_ALL_ENTRY_POINT_NAME),
         vec![
             Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ACCOUNT_RUNTIME_ARG_NAME, Address::cl_type()),
             Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Address::cl_type()),
-            Parameter::new(APPROVED_RUNTIME_ARG_NAME, Address::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
@@ -239,6 +330,7 @@ pub extern "C" fn call() {
         String::from(SAFE_TRANSFER_FROM_STORED_CONTRACT_ENTRY_POINT_NAME),
         vec![
             Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Address::cl_type()),
             Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
             Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
             Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
@@ -251,6 +343,7 @@ pub extern "C" fn call() {
         String::from(SAFE_BATCH_TRANFER_FROM_STORED_CONTRACT_ENTRY_POINT_NAME),
         vec![
             Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Address::cl_type()),
             Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
             Parameter::new(TOKEN_IDS_RUNTIME_ARG_NAME, Vec::<String>::cl_type()),
             Parameter::new(AMOUNTS_RUNTIME_ARG_NAME, Vec::<U256>::cl_type()),
@@ -260,6 +353,52 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let set_approval_for_all_stored_session_entrypoint = EntryPoint::new(
+        String::from(SET_APPROVAL_FOR_ALL_STORED_SESSION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(APPROVED_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Session,
+    );
+    let set_approval_for_all_nested_entrypoint = EntryPoint::new(
+        String::from(SET_APPROVAL_FOR_ALL_NESTED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(TEST_CALL_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OPERATOR_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(APPROVED_RUNTIME_ARG_NAME, bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_erc20_balance_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_ERC20_BALANCE_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ERC20_ADDRESS_RUNTIME_ARG_NAME, Address::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_nft_owner_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_NFT_OWNER_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(NFT_TOKEN_ID_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_balance_of_batch_entrypoint);
@@ -267,11 +406,16 @@ pub extern "C" fn call() {
     entry_points.add_entry_point(check_is_approval_for_all_entrypoint);
     entry_points.add_entry_point(safe_transfer_from_stored_contract_entrypoint);
     entry_points.add_entry_point(safe_batch_transfer_from_stored_contract_entrypoint);
+    entry_points.add_entry_point(set_approval_for_all_stored_session_entrypoint);
+    entry_points.add_entry_point(set_approval_for_all_nested_entrypoint);
+    entry_points.add_entry_point(check_erc20_balance_of_entrypoint);
+    entry_points.add_entry_point(check_nft_owner_of_entrypoint);
 
-    let (_contract_hash, _version) = storage::new_contract(
+    let (contract_hash, _version) = storage::new_contract(
         entry_points,
         None,
         Some(ERC1155_TEST_CALL_KEY.to_string()),
         None,
     );
+    runtime::put_key(ERC1155_TEST_CALL_CONTRACT_KEY, Key::from(contract_hash));
 }
@@ -21,8 +21,8 @@ use casper_erc1155::{
     Address, Error, ERC1155,
 };
 use casper_types::{
-    account::AccountHash, CLType, CLTyped, ContractPackageHash, CLValue, EntryPoint, EntryPointAccess, EntryPointType,
-    EntryPoints, Parameter, U256,
+    account::AccountHash, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Parameter, U256,
 };
 
 // const MINT_ENTRY_POINT_NAME: &str = "mint";
@@ -54,7 +54,7 @@ impl TestToken {
         let mint_entrypoint = EntryPoint::new(
             MINT_ENTRY_POINT_NAME,
             vec![
-                Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+                Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
                 Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, String::cl_type()),
                 Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
             ],
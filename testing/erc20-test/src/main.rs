@@ -0,0 +1,209 @@
+//! Minimal ERC20 token exposing the entry points which the wrapping extension of ERC1155 calls.
+//!
+//! It only implements `balance_of`, `approve`, `transfer` and `transfer_from`, and shouldn't be
+//! used as a template for a real ERC20 token.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_erc1155::{Address, Error};
+use casper_types::{
+    bytesrepr::ToBytes, system::CallStackElement, CLType, CLTyped, CLValue, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, Key, Parameter, URef, U256,
+};
+
+const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
+const APPROVE_ENTRY_POINT_NAME: &str = "approve";
+const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
+const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
+const OWNER_RUNTIME_ARG_NAME: &str = "owner";
+const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const RECIPIENT_RUNTIME_ARG_NAME: &str = "recipient";
+const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+const BALANCES_KEY_NAME: &str = "balances";
+const ALLOWANCES_KEY_NAME: &str = "allowances";
+const ERC20_TEST_KEY: &str = "erc20_test";
+const ERC20_TEST_CONTRACT_KEY: &str = "erc20_test_contract";
+
+/// Total supply minted to the installing account.
+const TOTAL_SUPPLY: u64 = 1_000_000_000;
+
+/// Returns the address which called the current entry point.
+fn get_immediate_caller_address() -> Address {
+    let call_stack = runtime::get_call_stack();
+    match call_stack.iter().nth_back(1).unwrap_or_revert() {
+        CallStackElement::Session { account_hash }
+        | CallStackElement::StoredSession { account_hash, .. } => Address::from(*account_hash),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        } => Address::from(*contract_package_hash),
+    }
+}
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert()
+}
+
+fn make_dictionary_item_key<T: ToBytes>(value: &T) -> String {
+    let key_bytes = runtime::blake2b(value.to_bytes().unwrap_or_revert());
+    base64::encode(&key_bytes)
+}
+
+fn read_balance(owner: Address) -> U256 {
+    storage::dictionary_get(
+        get_uref(BALANCES_KEY_NAME),
+        &make_dictionary_item_key(&owner),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn write_balance(owner: Address, balance: U256) {
+    storage::dictionary_put(
+        get_uref(BALANCES_KEY_NAME),
+        &make_dictionary_item_key(&owner),
+        balance,
+    );
+}
+
+fn read_allowance(owner: Address, spender: Address) -> U256 {
+    storage::dictionary_get(
+        get_uref(ALLOWANCES_KEY_NAME),
+        &make_dictionary_item_key(&(owner, spender)),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn write_allowance(owner: Address, spender: Address, allowance: U256) {
+    storage::dictionary_put(
+        get_uref(ALLOWANCES_KEY_NAME),
+        &make_dictionary_item_key(&(owner, spender)),
+        allowance,
+    );
+}
+
+fn transfer_balance(sender: Address, recipient: Address, amount: U256) {
+    let sender_balance = read_balance(sender)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Error::InsufficientBalance);
+    write_balance(sender, sender_balance);
+    let recipient_balance = read_balance(recipient)
+        .checked_add(amount)
+        .unwrap_or_revert_with(Error::Overflow);
+    write_balance(recipient, recipient_balance);
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of() {
+    let address: Address = runtime::get_named_arg(ADDRESS_RUNTIME_ARG_NAME);
+    let balance = read_balance(address);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve() {
+    let spender: Address = runtime::get_named_arg(SPENDER_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    write_allowance(get_immediate_caller_address(), spender, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer() {
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    transfer_balance(get_immediate_caller_address(), recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let owner: Address = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let recipient: Address = runtime::get_named_arg(RECIPIENT_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let spender = get_immediate_caller_address();
+    let allowance = read_allowance(owner, spender)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(Error::InsufficientAllowance);
+    write_allowance(owner, spender, allowance);
+    transfer_balance(owner, recipient, amount);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        BALANCE_OF_ENTRY_POINT_NAME,
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Address::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        APPROVE_ENTRY_POINT_NAME,
+        vec![
+            Parameter::new(SPENDER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        TRANSFER_ENTRY_POINT_NAME,
+        vec![
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        TRANSFER_FROM_ENTRY_POINT_NAME,
+        vec![
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, Address::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let balances_uref = storage::new_dictionary(BALANCES_KEY_NAME).unwrap_or_revert();
+    let allowances_uref = storage::new_dictionary(ALLOWANCES_KEY_NAME).unwrap_or_revert();
+    storage::dictionary_put(
+        balances_uref,
+        &make_dictionary_item_key(&Address::from(runtime::get_caller())),
+        U256::from(TOTAL_SUPPLY),
+    );
+    runtime::remove_key(BALANCES_KEY_NAME);
+    runtime::remove_key(ALLOWANCES_KEY_NAME);
+
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(BALANCES_KEY_NAME.to_string(), Key::from(balances_uref));
+    named_keys.insert(ALLOWANCES_KEY_NAME.to_string(), Key::from(allowances_uref));
+
+    let (contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(ERC20_TEST_KEY.to_string()),
+        None,
+    );
+    runtime::put_key(ERC20_TEST_CONTRACT_KEY, Key::from(contract_hash));
+}
@@ -0,0 +1,162 @@
+use std::convert::TryFrom;
+
+use casper_erc1155::{Address, AddressFromStrError};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::{EntryPoints, NamedKeys},
+    AccessRights, Contract, ContractPackageHash, ContractWasmHash, Key, ProtocolVersion, PublicKey,
+    SecretKey, URef,
+};
+
+const ACCOUNT_ADDRESS: Address = Address::Account(AccountHash::new([1; 32]));
+const CONTRACT_ADDRESS: Address = Address::Contract(ContractPackageHash::new([2; 32]));
+
+fn round_trip(address: Address) -> Address {
+    let bytes = address.to_bytes().unwrap();
+    assert_eq!(bytes.len(), address.serialized_length());
+    let (decoded, remainder) = Address::from_bytes(&bytes).unwrap();
+    assert!(remainder.is_empty());
+    decoded
+}
+
+#[test]
+fn should_serialize_account_as_account_key() {
+    assert_eq!(
+        ACCOUNT_ADDRESS.to_bytes().unwrap(),
+        Key::Account(AccountHash::new([1; 32])).to_bytes().unwrap()
+    );
+    assert_eq!(round_trip(ACCOUNT_ADDRESS), ACCOUNT_ADDRESS);
+}
+
+#[test]
+fn should_serialize_contract_package_as_hash_key() {
+    assert_eq!(
+        CONTRACT_ADDRESS.to_bytes().unwrap(),
+        Key::Hash([2; 32]).to_bytes().unwrap()
+    );
+    assert_eq!(round_trip(CONTRACT_ADDRESS), CONTRACT_ADDRESS);
+}
+
+#[test]
+fn should_convert_public_keys_to_their_account_hash() {
+    let public_keys = vec![
+        PublicKey::from(&SecretKey::ed25519_from_bytes([3u8; 32]).unwrap()),
+        PublicKey::from(&SecretKey::secp256k1_from_bytes([4u8; 32]).unwrap()),
+    ];
+    for public_key in public_keys {
+        let address = Address::from(&public_key);
+        assert_eq!(address, Address::Account(public_key.to_account_hash()));
+        assert_eq!(Address::from(public_key), address);
+        assert_eq!(round_trip(address), address);
+    }
+}
+
+#[test]
+fn should_convert_contract_to_its_package() {
+    let contract = Contract::new(
+        ContractPackageHash::new([5; 32]),
+        ContractWasmHash::new([6; 32]),
+        NamedKeys::new(),
+        EntryPoints::new(),
+        ProtocolVersion::V1_0_0,
+    );
+    assert_eq!(
+        Address::from_contract(&contract),
+        Address::Contract(ContractPackageHash::new([5; 32]))
+    );
+}
+
+#[test]
+fn should_convert_account_and_hash_keys() {
+    for address in [ACCOUNT_ADDRESS, CONTRACT_ADDRESS] {
+        assert_eq!(Address::try_from(Key::from(address)), Ok(address));
+    }
+}
+
+#[test]
+fn should_reject_other_keys() {
+    let keys = vec![
+        Key::URef(URef::new([7; 32], AccessRights::READ_ADD_WRITE)),
+        Key::Balance([8; 32]),
+        Key::Dictionary([9; 32]),
+    ];
+    for key in keys {
+        assert_eq!(Address::try_from(key), Err(key));
+        assert_eq!(
+            Address::from_bytes(&key.to_bytes().unwrap()).unwrap_err(),
+            bytesrepr::Error::Formatting
+        );
+    }
+}
+
+#[test]
+fn should_format_addresses_as_keys() {
+    assert_eq!(
+        ACCOUNT_ADDRESS.to_string(),
+        format!("account-hash-{}", "01".repeat(32))
+    );
+    assert_eq!(
+        CONTRACT_ADDRESS.to_string(),
+        format!("hash-{}", "02".repeat(32))
+    );
+    for address in [ACCOUNT_ADDRESS, CONTRACT_ADDRESS] {
+        assert_eq!(
+            address.to_string(),
+            Key::from(address).to_formatted_string()
+        );
+    }
+}
+
+#[test]
+fn should_parse_formatted_addresses() {
+    for address in [ACCOUNT_ADDRESS, CONTRACT_ADDRESS] {
+        assert_eq!(address.to_string().parse(), Ok(address));
+    }
+    let contract_package_hash = ContractPackageHash::new([2; 32]);
+    assert_eq!(
+        contract_package_hash.to_formatted_string().parse(),
+        Ok(CONTRACT_ADDRESS)
+    );
+}
+
+#[test]
+fn should_not_parse_other_strings() {
+    let uref = URef::new([7; 32], AccessRights::READ_ADD_WRITE);
+    assert_eq!(
+        uref.to_formatted_string().parse::<Address>(),
+        Err(AddressFromStrError::UnsupportedKey)
+    );
+    for input in ["", "nobody", "account-hash-01", "hash-zz"] {
+        assert_eq!(
+            input.parse::<Address>(),
+            Err(AddressFromStrError::InvalidFormat)
+        );
+    }
+}
+
+#[test]
+fn should_serialize_addresses_as_json_strings() {
+    let addresses = vec![ACCOUNT_ADDRESS, CONTRACT_ADDRESS];
+    let json = serde_json::to_string(&addresses).unwrap();
+    assert_eq!(
+        json,
+        format!(
+            "[\"account-hash-{}\",\"hash-{}\"]",
+            "01".repeat(32),
+            "02".repeat(32)
+        )
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<Address>>(&json).unwrap(),
+        addresses
+    );
+
+    let contract_package_hash = ContractPackageHash::new([2; 32]).to_formatted_string();
+    assert_eq!(
+        serde_json::from_value::<Address>(contract_package_hash.into()).unwrap(),
+        CONTRACT_ADDRESS
+    );
+    assert!(serde_json::from_str::<Address>("\"nobody\"").is_err());
+    assert!(serde_json::from_str::<Address>("42").is_err());
+}
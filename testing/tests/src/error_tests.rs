@@ -0,0 +1,139 @@
+use std::convert::TryFrom;
+
+use casper_erc1155::{Error, ErrorRange};
+use casper_types::ApiError;
+
+const LIBRARY_ERRORS: [Error; 41] = [
+    Error::InvalidContext,
+    Error::InsufficientBalance,
+    Error::InsufficientAllowance,
+    Error::Overflow,
+    Error::PermissionDenied,
+    Error::UnknownCampaign,
+    Error::InvalidProof,
+    Error::AlreadyClaimed,
+    Error::SaleNotActive,
+    Error::InsufficientPayment,
+    Error::SoldOut,
+    Error::PurchaseLimitExceeded,
+    Error::UnknownWrappedToken,
+    Error::NftLocked,
+    Error::NftNotLocked,
+    Error::InvalidAmount,
+    Error::LengthMismatch,
+    Error::UnknownOffer,
+    Error::OfferExpired,
+    Error::UnknownSnapshot,
+    Error::BalanceRented,
+    Error::NotRented,
+    Error::TokenExpired,
+    Error::TokenNotExpired,
+    Error::AlreadyMinted,
+    Error::UnknownTokenType,
+    Error::NonFungibleAmount,
+    Error::UnknownProposal,
+    Error::ProposalExpired,
+    Error::InsufficientApprovals,
+    Error::InvalidThreshold,
+    Error::Paused,
+    Error::UnknownOperation,
+    Error::OperationNotReady,
+    Error::DelayTooShort,
+    Error::NoPendingOwner,
+    Error::ReservedTokenId,
+    Error::InvalidTokenId,
+    Error::SupplyCapExceeded,
+    Error::InvalidSale,
+    Error::EmptyOffer,
+];
+
+fn user_error_code(error: Error) -> u16 {
+    match ApiError::from(error) {
+        ApiError::User(user_error) => user_error,
+        api_error => panic!("{:?} is not a user error", api_error),
+    }
+}
+
+#[test]
+fn should_keep_the_codes_of_library_errors() {
+    for (index, error) in LIBRARY_ERRORS.iter().enumerate() {
+        assert_eq!(user_error_code(*error), u16::MAX - index as u16);
+    }
+}
+
+#[test]
+fn should_decode_every_library_error() {
+    for error in LIBRARY_ERRORS.iter() {
+        let api_error = ApiError::from(*error);
+        assert_eq!(Error::try_from(api_error), Ok(*error));
+        assert_eq!(Error::try_from(user_error_code(*error)), Ok(*error));
+    }
+}
+
+#[test]
+fn should_decode_user_errors() {
+    for user_error in [0, 1, 42, Error::MAX_USER_ERROR] {
+        let error = Error::try_from(ApiError::User(user_error)).unwrap();
+        assert_eq!(error, Error::User(user_error));
+        assert_eq!(ApiError::from(error), ApiError::User(user_error));
+    }
+}
+
+#[test]
+fn should_decode_extension_errors_without_colliding() {
+    let mut codes = Vec::new();
+    for range in ErrorRange::ALL.iter() {
+        for code in [0, 1, u8::MAX] {
+            let error = range.error(code);
+            let user_error = user_error_code(error);
+            assert!(user_error > Error::MAX_USER_ERROR);
+            assert_eq!(Error::try_from(user_error), Ok(error));
+            match Error::try_from(user_error) {
+                Ok(Error::Extension(extension_error)) => {
+                    assert_eq!(extension_error.range(), *range);
+                    assert_eq!(extension_error.code(), code);
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+            codes.push(user_error);
+        }
+    }
+    for error in LIBRARY_ERRORS.iter() {
+        codes.push(user_error_code(*error));
+    }
+
+    let count = codes.len();
+    codes.sort_unstable();
+    codes.dedup();
+    assert_eq!(codes.len(), count);
+}
+
+#[test]
+fn should_not_decode_unassigned_or_system_errors() {
+    let unassigned = u16::MAX - LIBRARY_ERRORS.len() as u16;
+    assert_eq!(Error::try_from(unassigned), Err(unassigned));
+    assert_eq!(
+        Error::try_from(ApiError::User(unassigned)),
+        Err(ApiError::User(unassigned))
+    );
+    assert_eq!(
+        Error::try_from(ApiError::InvalidArgument),
+        Err(ApiError::InvalidArgument)
+    );
+}
+
+#[test]
+fn should_display_human_readable_messages() {
+    assert_eq!(
+        Error::try_from(ApiError::User(65534)).unwrap().to_string(),
+        "insufficient balance"
+    );
+    assert_eq!(Error::User(7).to_string(), "user error 7");
+    assert_eq!(
+        ErrorRange::ALL[2].error(5).to_string(),
+        "extension error 5 of range 2"
+    );
+
+    let error: Box<dyn std::error::Error> = Box::new(Error::Paused);
+    assert_eq!(error.to_string(), "token transfers are paused");
+}
@@ -0,0 +1,736 @@
+//! Harness running the token, `erc1155_test`, `erc1155_test_call`, the `erc20_test` and
+//! `cep47_test` mocks and the purchase session code on the engine test support.
+//!
+//! Every call goes through a real deploy, so the token sees the same call stack as on a network:
+//! accounts call it from session code, and the test call contract calls it from stored contract
+//! code, acting under its own contract package hash.
+use std::convert::TryFrom;
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_erc1155::{constants as consts, CallerPolicy, Error};
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, runtime_args, system::mint, CLTyped, ContractHash,
+    ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey, U256, U512,
+};
+
+const EXAMPLE_ERC1155_TOKEN: &str = "erc1155_token.wasm";
+const CONTRACT_ERC1155_TEST: &str = "erc1155_test.wasm";
+const CONTRACT_ERC1155_TEST_CALL: &str = "erc1155_test_call.wasm";
+const CONTRACT_ERC1155_PURCHASE: &str = "erc1155_purchase.wasm";
+const CONTRACT_ERC20_TEST: &str = "erc20_test.wasm";
+const CONTRACT_CEP47_TEST: &str = "cep47_test.wasm";
+const TOKEN_URI: &str = "https://myuri-example.com";
+/// Token types created by the default account at setup, so that it can mint them.
+const CREATED_TOKEN_IDS: &[&str] = &["1", "2"];
+
+const TEST_CONTRACT_KEY: &str = "test_contract";
+const ERC20_TEST_CONTRACT_KEY: &str = "erc20_test_contract";
+const CEP47_TEST_CONTRACT_KEY: &str = "cep47_test_contract";
+const ERC1155_TEST_CALL_KEY: &str = "erc1155_test_call";
+const ERC1155_TEST_CALL_CONTRACT_KEY: &str = "erc1155_test_call_contract";
+const RESULT_KEY: &str = "result";
+
+const ARG_TOKEN_CONTRACT: &str = "token_contract";
+const ARG_TEST_CALL_CONTRACT: &str = "test_call_contract";
+const ARG_PAYMENT_AMOUNT: &str = "payment_amount";
+const ARG_ERC20_ADDRESS: &str = "address";
+const ARG_ERC20_SPENDER: &str = "spender";
+const ARG_NFT_SPENDER: &str = "spender";
+const ARG_NFT_TOKEN_IDS: &str = "token_ids";
+
+const CHECK_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_total_supply";
+const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
+const CHECK_BALANCE_OF_BATCH_ENTRYPOINT: &str = "check_balance_of_batch";
+const CHECK_IS_APPROVAL_FOR_ALL_ENTRYPOINT: &str = "check_is_approval_for_all";
+const CHECK_ERC20_BALANCE_OF_ENTRYPOINT: &str = "check_erc20_balance_of";
+const ERC20_APPROVE_ENTRYPOINT: &str = "approve";
+const CHECK_NFT_OWNER_OF_ENTRYPOINT: &str = "check_nft_owner_of";
+const NFT_APPROVE_ENTRYPOINT: &str = "approve";
+const METHOD_SAFE_TRANSFER_FROM_STORED_CONTRACT: &str = "safe_transfer_from_stored_contract";
+const METHOD_SAFE_BATCH_TRANSFER_FROM_STORED_CONTRACT: &str =
+    "safe_batch_transfer_from_stored_contract";
+const METHOD_SET_APPROVAL_FOR_ALL_STORED_CONTRACT: &str = "set_approval_for_all_stored_contract";
+const METHOD_SET_APPROVAL_FOR_ALL_STORED_SESSION: &str = "set_approval_for_all_stored_session";
+const METHOD_SET_APPROVAL_FOR_ALL_NESTED: &str = "set_approval_for_all_nested";
+
+static ACCOUNT_1_SECRET_KEY: Lazy<SecretKey> =
+    Lazy::new(|| SecretKey::secp256k1_from_bytes(&[221u8; 32]).unwrap());
+static ACCOUNT_1_PUBLIC_KEY: Lazy<PublicKey> =
+    Lazy::new(|| PublicKey::from(&*ACCOUNT_1_SECRET_KEY));
+pub static ACCOUNT_1_ADDR: Lazy<AccountHash> = Lazy::new(|| ACCOUNT_1_PUBLIC_KEY.to_account_hash());
+
+static ACCOUNT_2_SECRET_KEY: Lazy<SecretKey> =
+    Lazy::new(|| SecretKey::secp256k1_from_bytes(&[212u8; 32]).unwrap());
+static ACCOUNT_2_PUBLIC_KEY: Lazy<PublicKey> =
+    Lazy::new(|| PublicKey::from(&*ACCOUNT_2_SECRET_KEY));
+pub static ACCOUNT_2_ADDR: Lazy<AccountHash> = Lazy::new(|| ACCOUNT_2_PUBLIC_KEY.to_account_hash());
+
+/// Immediate caller of a token entry point.
+#[derive(Clone, Copy)]
+pub enum Caller {
+    /// Session code of the account.
+    Account(AccountHash),
+    /// The test call contract, called by the default account through a stored contract entry
+    /// point.
+    TestCall,
+}
+
+/// Converts hash addr of Account into Hash, and Hash into Account
+///
+/// This is useful for making sure ERC1155 library respects different variants of Key when storing
+/// balances.
+pub fn invert_erc1155_address(address: Key) -> Key {
+    match address {
+        Key::Account(account_hash) => Key::Hash(account_hash.value()),
+        Key::Hash(contract_hash) => Key::Account(AccountHash::new(contract_hash)),
+        _ => panic!("Unsupported Key variant"),
+    }
+}
+
+/// Asserts that the last deploy executed by `builder` reverted with `expected`.
+pub fn assert_reverted_with(builder: &InMemoryWasmTestBuilder, expected: Error) {
+    match builder.get_error() {
+        Some(CoreError::Exec(ExecError::Revert(api_error))) => match Error::try_from(api_error) {
+            Ok(error) if error == expected => {}
+            Ok(error) => panic!("expected revert with \"{}\", got \"{}\"", expected, error),
+            Err(api_error) => panic!("expected revert with \"{}\", got {:?}", expected, api_error),
+        },
+        Some(error) => panic!("expected revert with \"{}\", got {:?}", expected, error),
+        None => panic!(
+            "expected revert with \"{}\", but the deploy succeeded",
+            expected
+        ),
+    }
+}
+
+/// The example token and the test call contract, installed by the default account.
+pub struct TestContext {
+    pub builder: InMemoryWasmTestBuilder,
+    pub erc1155_token: ContractHash,
+    pub erc1155_test_call: ContractPackageHash,
+    pub erc1155_test_call_contract: ContractHash,
+}
+
+impl TestContext {
+    /// Installs the contracts with the [`CallerPolicy::ImmediateCaller`] policy, funds
+    /// [`ACCOUNT_1_ADDR`] and [`ACCOUNT_2_ADDR`], and creates the token types `1` and `2` as the
+    /// default account.
+    pub fn setup() -> TestContext {
+        TestContext::setup_with_caller_policy(CallerPolicy::ImmediateCaller)
+    }
+
+    pub fn setup_with_caller_policy(caller_policy: CallerPolicy) -> TestContext {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+        fund_account(&mut builder, *ACCOUNT_1_ADDR);
+        fund_account(&mut builder, *ACCOUNT_2_ADDR);
+
+        let install_token_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            EXAMPLE_ERC1155_TOKEN,
+            runtime_args! {
+                consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+                consts::SIGNERS_RUNTIME_ARG_NAME => Vec::<Key>::new(),
+                consts::THRESHOLD_RUNTIME_ARG_NAME => 1u32,
+                consts::MIN_DELAY_RUNTIME_ARG_NAME => 0u64,
+                consts::CALLER_POLICY_RUNTIME_ARG_NAME => caller_policy,
+            },
+        )
+        .build();
+        builder
+            .exec(install_token_request)
+            .expect_success()
+            .commit();
+
+        let install_test_call_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_ERC1155_TEST_CALL,
+            RuntimeArgs::default(),
+        )
+        .build();
+        builder
+            .exec(install_test_call_request)
+            .expect_success()
+            .commit();
+
+        let erc1155_token = ContractHash::new(default_account_named_hash(
+            &builder,
+            consts::ERC1155_TOKEN_CONTRACT_KEY_NAME,
+        ));
+        let erc1155_test_call =
+            ContractPackageHash::new(default_account_named_hash(&builder, ERC1155_TEST_CALL_KEY));
+        let erc1155_test_call_contract = ContractHash::new(default_account_named_hash(
+            &builder,
+            ERC1155_TEST_CALL_CONTRACT_KEY,
+        ));
+
+        let mut context = TestContext {
+            builder,
+            erc1155_token,
+            erc1155_test_call,
+            erc1155_test_call_contract,
+        };
+        for id in CREATED_TOKEN_IDS {
+            let create_request = context.create_request(id);
+            context.exec(create_request);
+        }
+        context
+    }
+
+    /// Installs `erc1155_test`, which mints tokens of id `1` to fixed addresses at install.
+    pub fn install_test_token(&mut self) -> ContractHash {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_ERC1155_TEST,
+            RuntimeArgs::default(),
+        )
+        .build();
+        self.builder.exec(install_request).expect_success().commit();
+        ContractHash::new(default_account_named_hash(&self.builder, TEST_CONTRACT_KEY))
+    }
+
+    /// Installs `erc20_test`, which mints its whole supply to the default account.
+    pub fn install_erc20_test(&mut self) -> ContractHash {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_ERC20_TEST,
+            RuntimeArgs::default(),
+        )
+        .build();
+        self.builder.exec(install_request).expect_success().commit();
+        ContractHash::new(default_account_named_hash(
+            &self.builder,
+            ERC20_TEST_CONTRACT_KEY,
+        ))
+    }
+
+    /// Installs `cep47_test`, which mints the NFTs `1` to `3` to the default account.
+    pub fn install_cep47_test(&mut self) -> ContractHash {
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_CEP47_TEST,
+            RuntimeArgs::default(),
+        )
+        .build();
+        self.builder.exec(install_request).expect_success().commit();
+        ContractHash::new(default_account_named_hash(
+            &self.builder,
+            CEP47_TEST_CONTRACT_KEY,
+        ))
+    }
+
+    /// Returns the address of the token contract package, which holds the tokens locked by the
+    /// wrapping and fractionalization extensions.
+    pub fn token_address(&self) -> Key {
+        let contract = self
+            .builder
+            .get_contract(self.erc1155_token)
+            .expect("should have contract");
+        Key::Hash(contract.contract_package_hash().value())
+    }
+
+    /// Returns the names of the named keys of `contract`.
+    pub fn named_key_names(&self, contract: ContractHash) -> Vec<String> {
+        self.builder
+            .get_contract(contract)
+            .expect("should have contract")
+            .named_keys()
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the balance of the treasury purse of the token.
+    pub fn treasury_balance(&self) -> U512 {
+        let treasury = self
+            .builder
+            .get_contract(self.erc1155_token)
+            .expect("should have contract")
+            .named_keys()
+            .get(consts::TREASURY_KEY_NAME)
+            .and_then(|key| key.into_uref())
+            .expect("should have treasury purse");
+        self.builder.get_purse_balance(treasury)
+    }
+
+    /// Returns the balance of the main purse of `account`.
+    pub fn main_purse_balance(&self, account: AccountHash) -> U512 {
+        let main_purse = self
+            .builder
+            .get_account(account)
+            .expect("should have account")
+            .main_purse();
+        self.builder.get_purse_balance(main_purse)
+    }
+
+    /// Returns the motes paid for the gas of the last executed deploy.
+    pub fn last_exec_cost(&self) -> U512 {
+        self.builder.last_exec_gas_cost().value()
+    }
+
+    /// Creates `account` by transferring motes from the default account.
+    pub fn fund_account(&mut self, account: AccountHash) {
+        fund_account(&mut self.builder, account);
+    }
+
+    /// Returns the address the token sees for `caller`.
+    pub fn address(&self, caller: Caller) -> Key {
+        match caller {
+            Caller::Account(account_hash) => Key::Account(account_hash),
+            Caller::TestCall => Key::Hash(self.erc1155_test_call.value()),
+        }
+    }
+
+    /// Executes `request`, expecting it to succeed.
+    pub fn exec(&mut self, request: ExecuteRequest) {
+        self.builder.exec(request).expect_success().commit();
+    }
+
+    /// Executes `request`, expecting it to revert with `expected`.
+    pub fn exec_reverted_with(&mut self, request: ExecuteRequest, expected: Error) {
+        self.builder.exec(request).commit();
+        assert_reverted_with(&self.builder, expected);
+    }
+
+    pub fn create_request(&self, id: &str) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::CREATE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => Some(id.to_string()),
+                consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::zero(),
+                consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+                consts::FUNGIBLE_RUNTIME_ARG_NAME => true,
+            },
+        )
+        .build()
+    }
+
+    pub fn mint_request(&self, to: Key, id: &str, amount: U256) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::MINT_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        )
+        .build()
+    }
+
+    /// Puts `supply` tokens of `id` on sale for `price` motes each, as the default account.
+    pub fn set_sale_request(&self, id: &str, price: U512, supply: U256) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::SET_SALE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::PRICE_RUNTIME_ARG_NAME => price,
+                consts::START_TIME_RUNTIME_ARG_NAME => 0u64,
+                consts::END_TIME_RUNTIME_ARG_NAME => u64::MAX,
+                consts::WALLET_LIMIT_RUNTIME_ARG_NAME => U256::zero(),
+                consts::SUPPLY_RUNTIME_ARG_NAME => supply,
+            },
+        )
+        .build()
+    }
+
+    /// Purchases `amount` tokens of `id` through the purchase session code, moving
+    /// `payment_amount` motes out of the main purse of `buyer`.
+    pub fn purchase_request(
+        &self,
+        buyer: AccountHash,
+        id: &str,
+        amount: U256,
+        payment_amount: U512,
+    ) -> ExecuteRequest {
+        ExecuteRequestBuilder::standard(
+            buyer,
+            CONTRACT_ERC1155_PURCHASE,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => self.erc1155_token,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+                ARG_PAYMENT_AMOUNT => payment_amount,
+            },
+        )
+        .build()
+    }
+
+    /// Withdraws `amount` motes from the treasury as the default account.
+    pub fn withdraw_request(&self, amount: U512) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::WITHDRAW_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        )
+        .build()
+    }
+
+    /// Approves `spender` to spend `amount` ERC20 tokens of the default account.
+    pub fn erc20_approve_request(
+        &self,
+        erc20_contract: ContractHash,
+        spender: Key,
+        amount: U256,
+    ) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            erc20_contract,
+            ERC20_APPROVE_ENTRYPOINT,
+            runtime_args! {
+                ARG_ERC20_SPENDER => spender,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        )
+        .build()
+    }
+
+    /// Approves `spender` to transfer the NFT `token_id` of the default account.
+    pub fn nft_approve_request(
+        &self,
+        nft_contract: ContractHash,
+        spender: Key,
+        token_id: U256,
+    ) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract,
+            NFT_APPROVE_ENTRYPOINT,
+            runtime_args! {
+                ARG_NFT_SPENDER => spender,
+                ARG_NFT_TOKEN_IDS => vec![token_id],
+            },
+        )
+        .build()
+    }
+
+    /// Locks the NFT `token_id` of the default account, and mints `amount` fractions of it to them.
+    pub fn fractionalize_request(
+        &self,
+        nft_contract: ContractHash,
+        token_id: U256,
+        amount: U256,
+    ) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::FRACTIONALIZE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::NFT_CONTRACT_RUNTIME_ARG_NAME => nft_contract,
+                consts::NFT_TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        )
+        .build()
+    }
+
+    /// Redeems every fraction of `id` owned by `redeemer`.
+    pub fn redeem_request(&self, redeemer: AccountHash, id: &str) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            redeemer,
+            self.erc1155_token,
+            consts::REDEEM_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            },
+        )
+        .build()
+    }
+
+    /// Wraps `amount` ERC20 tokens of the default account.
+    pub fn wrap_request(&self, erc20_contract: ContractHash, amount: U256) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::WRAP_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::ERC20_CONTRACT_RUNTIME_ARG_NAME => erc20_contract,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        )
+        .build()
+    }
+
+    /// Unwraps `amount` tokens of the wrapped `id` owned by the default account.
+    pub fn unwrap_request(&self, id: &str, amount: U256) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_token,
+            consts::UNWRAP_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        )
+        .build()
+    }
+
+    pub fn safe_transfer_from_request(
+        &self,
+        caller: Caller,
+        from: Key,
+        to: Key,
+        id: &str,
+        amount: U256,
+    ) -> ExecuteRequest {
+        match caller {
+            Caller::Account(sender) => ExecuteRequestBuilder::contract_call_by_hash(
+                sender,
+                self.erc1155_token,
+                consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::FROM_RUNTIME_ARG_NAME => from,
+                    consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                    consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                    consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+                },
+            )
+            .build(),
+            Caller::TestCall => self.test_call_request(
+                METHOD_SAFE_TRANSFER_FROM_STORED_CONTRACT,
+                runtime_args! {
+                    ARG_TOKEN_CONTRACT => self.erc1155_token,
+                    consts::FROM_RUNTIME_ARG_NAME => from,
+                    consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                    consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                    consts::AMOUNT_RUNTIME_ARG_NAME => amount,
+                },
+            ),
+        }
+    }
+
+    pub fn safe_batch_transfer_from_request(
+        &self,
+        caller: Caller,
+        from: Key,
+        to: Key,
+        ids: Vec<String>,
+        amounts: Vec<U256>,
+    ) -> ExecuteRequest {
+        match caller {
+            Caller::Account(sender) => ExecuteRequestBuilder::contract_call_by_hash(
+                sender,
+                self.erc1155_token,
+                consts::SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::FROM_RUNTIME_ARG_NAME => from,
+                    consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                    consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+                    consts::AMOUNTS_RUNTIME_ARG_NAME => amounts,
+                },
+            )
+            .build(),
+            Caller::TestCall => self.test_call_request(
+                METHOD_SAFE_BATCH_TRANSFER_FROM_STORED_CONTRACT,
+                runtime_args! {
+                    ARG_TOKEN_CONTRACT => self.erc1155_token,
+                    consts::FROM_RUNTIME_ARG_NAME => from,
+                    consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                    consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+                    consts::AMOUNTS_RUNTIME_ARG_NAME => amounts,
+                },
+            ),
+        }
+    }
+
+    pub fn set_approval_for_all_request(
+        &self,
+        caller: Caller,
+        operator: Key,
+        approved: bool,
+    ) -> ExecuteRequest {
+        match caller {
+            Caller::Account(sender) => ExecuteRequestBuilder::contract_call_by_hash(
+                sender,
+                self.erc1155_token,
+                consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                    consts::APPROVED_RUNTIME_ARG_NAME => approved,
+                },
+            )
+            .build(),
+            Caller::TestCall => self.test_call_request(
+                METHOD_SET_APPROVAL_FOR_ALL_STORED_CONTRACT,
+                runtime_args! {
+                    ARG_TOKEN_CONTRACT => self.erc1155_token,
+                    consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                    consts::APPROVED_RUNTIME_ARG_NAME => approved,
+                },
+            ),
+        }
+    }
+
+    /// Sets an operator of the default account from stored session code of the test call
+    /// contract.
+    pub fn set_approval_for_all_stored_session_request(
+        &self,
+        operator: Key,
+        approved: bool,
+    ) -> ExecuteRequest {
+        self.test_call_request(
+            METHOD_SET_APPROVAL_FOR_ALL_STORED_SESSION,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => self.erc1155_token,
+                consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                consts::APPROVED_RUNTIME_ARG_NAME => approved,
+            },
+        )
+    }
+
+    /// Sets an operator of the test call contract through a nested call of itself.
+    pub fn set_approval_for_all_nested_request(
+        &self,
+        operator: Key,
+        approved: bool,
+    ) -> ExecuteRequest {
+        self.test_call_request(
+            METHOD_SET_APPROVAL_FOR_ALL_NESTED,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => self.erc1155_token,
+                ARG_TEST_CALL_CONTRACT => self.erc1155_test_call_contract,
+                consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+                consts::APPROVED_RUNTIME_ARG_NAME => approved,
+            },
+        )
+    }
+
+    pub fn balance_of(&mut self, account: Key, id: &str) -> U256 {
+        let erc1155_token = self.erc1155_token;
+        self.check_balance_of(erc1155_token, account, id)
+    }
+
+    pub fn total_supply(&mut self, id: &str) -> U256 {
+        let erc1155_token = self.erc1155_token;
+        self.check_total_supply(erc1155_token, id)
+    }
+
+    /// Reads the balance of `account` in `token` through the test call contract.
+    pub fn check_balance_of(&mut self, token: ContractHash, account: Key, id: &str) -> U256 {
+        self.check(
+            CHECK_BALANCE_OF_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => token,
+                consts::ACCOUNT_RUNTIME_ARG_NAME => account,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            },
+        )
+    }
+
+    /// Reads the total supply of `id` in `token` through the test call contract.
+    pub fn check_total_supply(&mut self, token: ContractHash, id: &str) -> U256 {
+        self.check(
+            CHECK_TOTAL_SUPPLY_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => token,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            },
+        )
+    }
+
+    pub fn balance_of_batch(&mut self, accounts: Vec<Key>, ids: Vec<String>) -> Vec<U256> {
+        let erc1155_token = self.erc1155_token;
+        self.check(
+            CHECK_BALANCE_OF_BATCH_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => erc1155_token,
+                consts::ACCOUNTS_RUNTIME_ARG_NAME => accounts,
+                consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+            },
+        )
+    }
+
+    pub fn is_approval_for_all(&mut self, account: Key, operator: Key) -> bool {
+        let erc1155_token = self.erc1155_token;
+        self.check(
+            CHECK_IS_APPROVAL_FOR_ALL_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => erc1155_token,
+                consts::ACCOUNT_RUNTIME_ARG_NAME => account,
+                consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+            },
+        )
+    }
+
+    /// Reads the ERC20 balance of `address` in `erc20_contract` through the test call contract.
+    pub fn erc20_balance_of(&mut self, erc20_contract: ContractHash, address: Key) -> U256 {
+        self.check(
+            CHECK_ERC20_BALANCE_OF_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => erc20_contract,
+                ARG_ERC20_ADDRESS => address,
+            },
+        )
+    }
+
+    /// Reads the owner of the NFT `token_id` of `nft_contract` through the test call contract.
+    pub fn nft_owner_of(&mut self, nft_contract: ContractHash, token_id: U256) -> Option<Key> {
+        self.check(
+            CHECK_NFT_OWNER_OF_ENTRYPOINT,
+            runtime_args! {
+                ARG_TOKEN_CONTRACT => nft_contract,
+                consts::NFT_TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+            },
+        )
+    }
+
+    /// Calls a `check_*` entry point of the test call contract, and reads the result it stored.
+    fn check<T: FromBytes + CLTyped>(&mut self, entry_point: &str, args: RuntimeArgs) -> T {
+        let request = self.test_call_request(entry_point, args);
+        self.exec(request);
+        self.builder
+            .get_value(self.erc1155_test_call_contract, RESULT_KEY)
+    }
+
+    fn test_call_request(&self, entry_point: &str, args: RuntimeArgs) -> ExecuteRequest {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            self.erc1155_test_call_contract,
+            entry_point,
+            args,
+        )
+        .build()
+    }
+}
+
+fn fund_account(builder: &mut InMemoryWasmTestBuilder, account: AccountHash) {
+    let id: Option<u64> = None;
+    let transfer_request = ExecuteRequestBuilder::transfer(
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {
+            mint::ARG_TARGET => account,
+            mint::ARG_ID => id,
+            mint::ARG_AMOUNT => MINIMUM_ACCOUNT_CREATION_BALANCE,
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+fn default_account_named_hash(builder: &InMemoryWasmTestBuilder, name: &str) -> [u8; 32] {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)
+        .and_then(|key| key.into_hash())
+        .unwrap_or_else(|| panic!("should have {} named key", name))
+}
@@ -1,2 +1,10 @@
 #[cfg(test)]
+mod address_tests;
+#[cfg(test)]
+mod error_tests;
+#[cfg(test)]
+mod harness;
+#[cfg(test)]
 mod lib_integration_tests;
+#[cfg(test)]
+mod multi_transfer_gas_tests;
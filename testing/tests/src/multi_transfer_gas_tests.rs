@@ -0,0 +1,117 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_erc1155::{constants as consts, CallerPolicy};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256, U512};
+
+const EXAMPLE_ERC1155_TOKEN: &str = "erc1155_token.wasm";
+const TOKEN_URI: &str = "https://myuri-example.com";
+const TOKEN_ID: &str = "gold";
+const RECIPIENTS_COUNT: u8 = 10;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        EXAMPLE_ERC1155_TOKEN,
+        runtime_args! {
+            consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+            consts::SIGNERS_RUNTIME_ARG_NAME => Vec::<Key>::new(),
+            consts::THRESHOLD_RUNTIME_ARG_NAME => 1u32,
+            consts::MIN_DELAY_RUNTIME_ARG_NAME => 0u64,
+            consts::CALLER_POLICY_RUNTIME_ARG_NAME => CallerPolicy::ImmediateCaller,
+        },
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let create_request = token_call(
+        consts::CREATE_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => Some(TOKEN_ID.to_string()),
+            consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::zero(),
+            consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+            consts::FUNGIBLE_RUNTIME_ARG_NAME => true,
+        },
+    );
+    builder.exec(create_request).expect_success().commit();
+
+    let mint_request = token_call(
+        consts::MINT_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(*DEFAULT_ACCOUNT_ADDR),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => TOKEN_ID,
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(1_000),
+        },
+    );
+    builder.exec(mint_request).expect_success().commit();
+    builder
+}
+
+fn token_call(entry_point: &str, args: RuntimeArgs) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_name(
+        *DEFAULT_ACCOUNT_ADDR,
+        consts::ERC1155_TOKEN_CONTRACT_KEY_NAME,
+        entry_point,
+        args,
+    )
+    .build()
+}
+
+fn recipients(seed: u8) -> Vec<Key> {
+    (0..RECIPIENTS_COUNT)
+        .map(|index| Key::from(AccountHash::new([seed.wrapping_add(index); 32])))
+        .collect()
+}
+
+fn last_gas_cost(builder: &InMemoryWasmTestBuilder) -> U512 {
+    builder.last_exec_gas_cost().value()
+}
+
+#[test]
+fn multi_transfer_should_cost_less_than_single_transfers() {
+    let mut builder = setup();
+    let from = Key::from(*DEFAULT_ACCOUNT_ADDR);
+
+    let mut single_transfers_gas = U512::zero();
+    for to in recipients(1) {
+        let transfer_request = token_call(
+            consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::FROM_RUNTIME_ARG_NAME => from,
+                consts::RECIPIENT_RUNTIME_ARG_NAME => to,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => TOKEN_ID,
+                consts::AMOUNT_RUNTIME_ARG_NAME => U256::one(),
+            },
+        );
+        builder.exec(transfer_request).expect_success().commit();
+        single_transfers_gas += last_gas_cost(&builder);
+    }
+
+    let multi_transfer_request = token_call(
+        consts::SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => from,
+            consts::RECIPIENTS_RUNTIME_ARG_NAME => recipients(101),
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => vec![TOKEN_ID.to_string(); RECIPIENTS_COUNT.into()],
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::one(); RECIPIENTS_COUNT.into()],
+        },
+    );
+    builder
+        .exec(multi_transfer_request)
+        .expect_success()
+        .commit();
+    let multi_transfer_gas = last_gas_cost(&builder);
+
+    assert!(
+        multi_transfer_gas < single_transfers_gas,
+        "multi transfer cost {} while {} single transfers cost {}",
+        multi_transfer_gas,
+        RECIPIENTS_COUNT,
+        single_transfers_gas
+    );
+}
@@ -0,0 +1,99 @@
+//! Measures the gas cost of the entry points of the example token, and writes a JSON and a
+//! markdown report.
+//!
+//! Usage:
+//!
+//! ```text
+//! erc1155-bench [--output-dir <dir>] [--baseline <report.json>] [--max-regression <percent>]
+//! ```
+//!
+//! With `--baseline`, the report of another commit is compared with the current one, and the
+//! benchmark fails if the gas of any entry point grew by more than `--max-regression` percent.
+mod report;
+mod scenarios;
+
+use std::{env, fs, path::PathBuf, process};
+
+use crate::report::{Measurement, Report};
+
+const USAGE: &str = "usage: erc1155-bench [--output-dir <dir>] [--baseline <report.json>] \
+    [--max-regression <percent>]";
+const DEFAULT_OUTPUT_DIR: &str = "target/erc1155-bench";
+const DEFAULT_MAX_REGRESSION: f64 = 5.0;
+const JSON_REPORT: &str = "report.json";
+const MARKDOWN_REPORT: &str = "report.md";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn main() {
+    let mut output_dir = PathBuf::from(DEFAULT_OUTPUT_DIR);
+    let mut baseline = None;
+    let mut max_regression = DEFAULT_MAX_REGRESSION;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| fail(USAGE));
+        match arg.as_str() {
+            "--output-dir" => output_dir = PathBuf::from(value),
+            "--baseline" => {
+                let json = fs::read_to_string(&value).unwrap_or_else(|error| {
+                    fail(&format!("can't read baseline {}: {}", value, error))
+                });
+                let report: Report = serde_json::from_str(&json).unwrap_or_else(|error| {
+                    fail(&format!("invalid baseline {}: {}", value, error))
+                });
+                baseline = Some(report);
+            }
+            "--max-regression" => {
+                max_regression = value.parse().unwrap_or_else(|_| fail(USAGE));
+            }
+            _ => fail(USAGE),
+        }
+    }
+
+    let mut report = Report::default();
+    for (entry_point, sizes) in scenarios::scenarios() {
+        for size in sizes {
+            let gas = scenarios::measure(entry_point, *size);
+            eprintln!("{} ({} ids): {}", entry_point, size, gas);
+            report.measurements.push(Measurement {
+                entry_point: entry_point.to_string(),
+                size: *size,
+                gas,
+            });
+        }
+    }
+
+    let markdown = report.to_markdown(baseline.as_ref());
+    fs::create_dir_all(&output_dir).unwrap_or_else(|error| fail(&error.to_string()));
+    let json = serde_json::to_string_pretty(&report).unwrap();
+    fs::write(output_dir.join(JSON_REPORT), json + "\n")
+        .unwrap_or_else(|error| fail(&error.to_string()));
+    fs::write(output_dir.join(MARKDOWN_REPORT), &markdown)
+        .unwrap_or_else(|error| fail(&error.to_string()));
+    print!("{}", markdown);
+
+    if let Some(baseline) = baseline {
+        let regressions = report.regressions(&baseline, max_regression);
+        for regression in &regressions {
+            eprintln!(
+                "{} ({} ids) regressed by {:.2}%: {} -> {}",
+                regression.measurement.entry_point,
+                regression.measurement.size,
+                regression.change,
+                regression.baseline_gas,
+                regression.measurement.gas
+            );
+        }
+        if !regressions.is_empty() {
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod report_tests;
+#[cfg(test)]
+mod scenarios_tests;
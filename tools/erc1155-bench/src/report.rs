@@ -0,0 +1,111 @@
+//! Gas report of a benchmark run, comparable with the report of another commit.
+use std::fmt::Write;
+
+use casper_types::U512;
+use serde::{Deserialize, Serialize};
+
+/// Gas consumed by one entry point for one input size.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct Measurement {
+    /// Name of the entry point.
+    pub entry_point: String,
+    /// Amount of ids passed to the entry point.
+    pub size: usize,
+    /// Gas consumed by the deploy calling the entry point.
+    pub gas: U512,
+}
+
+/// Gas consumed by every benchmarked entry point.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    /// Measurements, in the order they were run.
+    pub measurements: Vec<Measurement>,
+}
+
+/// Measurement whose gas grew by more than the allowed ratio since the baseline.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Regression {
+    /// Current measurement.
+    pub measurement: Measurement,
+    /// Gas consumed in the baseline.
+    pub baseline_gas: U512,
+    /// Growth of the gas since the baseline, in percent.
+    pub change: f64,
+}
+
+impl Report {
+    /// Returns the gas of `entry_point` for `size` ids, if it was measured.
+    pub fn gas(&self, entry_point: &str, size: usize) -> Option<U512> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.entry_point == entry_point && measurement.size == size)
+            .map(|measurement| measurement.gas)
+    }
+
+    /// Returns the measurements whose gas grew by more than `max_change` percent since `baseline`.
+    ///
+    /// Measurements missing from the baseline are not compared.
+    pub fn regressions(&self, baseline: &Report, max_change: f64) -> Vec<Regression> {
+        self.measurements
+            .iter()
+            .filter_map(|measurement| {
+                let baseline_gas = baseline.gas(&measurement.entry_point, measurement.size)?;
+                let change = change(baseline_gas, measurement.gas);
+                if change > max_change {
+                    Some(Regression {
+                        measurement: measurement.clone(),
+                        baseline_gas,
+                        change,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the report as a markdown table, with the change since `baseline` if given.
+    pub fn to_markdown(&self, baseline: Option<&Report>) -> String {
+        let mut markdown = String::new();
+        match baseline {
+            Some(_) => {
+                markdown.push_str("| Entry point | Ids | Gas | Baseline | Change |\n");
+                markdown.push_str("|---|---:|---:|---:|---:|\n");
+            }
+            None => {
+                markdown.push_str("| Entry point | Ids | Gas |\n");
+                markdown.push_str("|---|---:|---:|\n");
+            }
+        }
+        for measurement in &self.measurements {
+            let _ = write!(
+                markdown,
+                "| `{}` | {} | {} |",
+                measurement.entry_point, measurement.size, measurement.gas
+            );
+            if let Some(baseline) = baseline {
+                let _ = match baseline.gas(&measurement.entry_point, measurement.size) {
+                    Some(baseline_gas) => write!(
+                        markdown,
+                        " {} | {:+.2}% |",
+                        baseline_gas,
+                        change(baseline_gas, measurement.gas)
+                    ),
+                    None => write!(markdown, " - | - |"),
+                };
+            }
+            markdown.push('\n');
+        }
+        markdown
+    }
+}
+
+/// Returns the change from `before` to `after`, in percent.
+fn change(before: U512, after: U512) -> f64 {
+    let before = before.as_u128() as f64;
+    let after = after.as_u128() as f64;
+    if before == 0.0 {
+        return 0.0;
+    }
+    (after - before) * 100.0 / before
+}
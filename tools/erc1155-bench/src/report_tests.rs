@@ -0,0 +1,47 @@
+use casper_types::U512;
+
+use crate::report::{Measurement, Report};
+
+fn report(measurements: &[(&str, usize, u64)]) -> Report {
+    Report {
+        measurements: measurements
+            .iter()
+            .map(|(entry_point, size, gas)| Measurement {
+                entry_point: entry_point.to_string(),
+                size: *size,
+                gas: U512::from(*gas),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn should_report_regressions_above_max_change() {
+    let baseline = report(&[("mint", 1, 1_000), ("mint_batch", 10, 2_000)]);
+    let current = report(&[
+        ("mint", 1, 1_040),
+        ("mint_batch", 10, 2_200),
+        ("burn", 1, 500),
+    ]);
+
+    let regressions = current.regressions(&baseline, 5.0);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].measurement.entry_point, "mint_batch");
+    assert_eq!(regressions[0].baseline_gas, U512::from(2_000));
+    assert!((regressions[0].change - 10.0).abs() < f64::EPSILON);
+    assert!(current.regressions(&baseline, 10.0).is_empty());
+}
+
+#[test]
+fn should_round_trip_through_json_and_render_markdown() {
+    let baseline = report(&[("mint", 1, 1_000)]);
+    let current = report(&[("mint", 1, 900), ("burn", 1, 500)]);
+
+    let json = serde_json::to_string(&current).unwrap();
+    assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), current);
+
+    let markdown = current.to_markdown(Some(&baseline));
+    assert!(markdown.contains("| `mint` | 1 | 900 | 1000 | -10.00% |"));
+    assert!(markdown.contains("| `burn` | 1 | 500 | - | - |"));
+    assert!(!current.to_markdown(None).contains("Baseline"));
+}
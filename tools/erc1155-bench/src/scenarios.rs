@@ -0,0 +1,427 @@
+//! Scenarios calling each benchmarked entry point of the example token.
+//!
+//! Every measurement runs on a fresh chain where the default account installed the token and
+//! created `size` token types, with an initial supply unless the entry point mints them itself.
+//! Only the gas of the measured deploy is recorded. Entry points needing another contract, a
+//! purse or a merkle proof are not benchmarked, see `scenarios_tests`.
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_erc1155::{constants as consts, Action, CallerPolicy, Operation};
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256, U512};
+
+const EXAMPLE_ERC1155_TOKEN: &str = "erc1155_token.wasm";
+const TOKEN_URI: &str = "https://myuri-example.com";
+const MINTED_AMOUNT: u64 = 1_000;
+const TRANSFERRED_AMOUNT: u64 = 1;
+const CAMPAIGN: &str = "campaign";
+
+/// Input sizes of the entry points taking a list of ids.
+pub const BATCH_SIZES: &[usize] = &[1, 10, 100];
+/// Input size of the entry points taking a single id.
+pub const SINGLE_SIZE: &[usize] = &[1];
+
+/// Returns every benchmarked entry point with the input sizes it runs with.
+pub fn scenarios() -> Vec<(&'static str, &'static [usize])> {
+    vec![
+        (consts::BALANCE_OF_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::BALANCE_OF_BATCH_ENTRY_POINT_NAME, BATCH_SIZES),
+        (consts::TOTAL_SUPPLY_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::IS_APPROVAL_FOR_ALL_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (
+            consts::SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME,
+            BATCH_SIZES,
+        ),
+        (
+            consts::SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME,
+            BATCH_SIZES,
+        ),
+        (consts::MINT_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::MINT_BATCH_ENTRY_POINT_NAME, BATCH_SIZES),
+        (consts::BURN_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::BURN_BATCH_ENTRY_POINT_NAME, BATCH_SIZES),
+        (consts::SNAPSHOT_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::CREATE_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SET_MINTER_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::TRANSFER_OWNERSHIP_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::URI_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SET_CLAIM_ROOT_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SET_SALE_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::FRACTIONALIZED_NFT_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::IS_NFT_LOCKED_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::CREATE_OFFER_ENTRY_POINT_NAME, BATCH_SIZES),
+        (consts::ACCEPT_OFFER_ENTRY_POINT_NAME, BATCH_SIZES),
+        (consts::CANCEL_OFFER_ENTRY_POINT_NAME, BATCH_SIZES),
+        (consts::BALANCE_OF_AT_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::TOTAL_SUPPLY_AT_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SET_USER_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::REVOKE_USER_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::USER_OF_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::USABLE_BALANCE_OF_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::MINT_WITH_EXPIRY_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SWEEP_EXPIRED_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::EXPIRY_OF_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::CREATOR_OF_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::EXISTS_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::OWNER_OF_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::PROPOSE_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::APPROVE_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::EXECUTE_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::QUEUE_OPERATION_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::CANCEL_OPERATION_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::EXECUTE_OPERATION_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::MIN_DELAY_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::SUPPLY_CAP_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::OWNER_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::PENDING_OWNER_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::ACCEPT_OWNERSHIP_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (
+            consts::CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME,
+            SINGLE_SIZE,
+        ),
+        (consts::RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME, SINGLE_SIZE),
+        (consts::CALLER_POLICY_ENTRY_POINT_NAME, SINGLE_SIZE),
+    ]
+}
+
+/// A chain where the example token is installed by the default account.
+struct Bench {
+    builder: InMemoryWasmTestBuilder,
+    blocktime: u64,
+}
+
+impl Bench {
+    fn install() -> Bench {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            EXAMPLE_ERC1155_TOKEN,
+            runtime_args! {
+                consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+                consts::SIGNERS_RUNTIME_ARG_NAME => Vec::<Key>::new(),
+                consts::THRESHOLD_RUNTIME_ARG_NAME => 1u32,
+                consts::MIN_DELAY_RUNTIME_ARG_NAME => 0u64,
+                consts::CALLER_POLICY_RUNTIME_ARG_NAME => CallerPolicy::ImmediateCaller,
+            },
+        )
+        .build();
+        builder.exec(install_request).expect_success().commit();
+        Bench {
+            builder,
+            blocktime: 0,
+        }
+    }
+
+    /// Calls `entry_point` from the default account at the current blocktime, and returns the gas
+    /// it consumed.
+    fn call(&mut self, entry_point: &str, args: RuntimeArgs) -> U512 {
+        let request = ExecuteRequestBuilder::contract_call_by_name(
+            *DEFAULT_ACCOUNT_ADDR,
+            consts::ERC1155_TOKEN_CONTRACT_KEY_NAME,
+            entry_point,
+            args,
+        )
+        .with_block_time(self.blocktime)
+        .build();
+        self.builder.exec(request).expect_success().commit();
+        self.builder.last_exec_gas_cost().value()
+    }
+}
+
+fn token_ids(size: usize) -> Vec<String> {
+    (0..size).map(|index| format!("token-{}", index)).collect()
+}
+
+fn accounts(size: usize) -> Vec<Key> {
+    (0..size)
+        .map(|index| Key::from(AccountHash::new([index as u8 + 1; 32])))
+        .collect()
+}
+
+/// Returns true if `entry_point` mints ids itself, so they must not be minted beforehand.
+fn mints_ids(entry_point: &str) -> bool {
+    entry_point == consts::MINT_ENTRY_POINT_NAME
+        || entry_point == consts::MINT_BATCH_ENTRY_POINT_NAME
+        || entry_point == consts::MINT_WITH_EXPIRY_ENTRY_POINT_NAME
+        || entry_point == consts::SWEEP_EXPIRED_ENTRY_POINT_NAME
+}
+
+/// Offers every id for the same ids, and returns the arguments of `create_offer`.
+fn offer_args(ids: &[String], amounts: &[U256]) -> RuntimeArgs {
+    runtime_args! {
+        consts::TAKER_RUNTIME_ARG_NAME => Option::<Key>::None,
+        consts::OFFERED_IDS_RUNTIME_ARG_NAME => ids.to_vec(),
+        consts::OFFERED_AMOUNTS_RUNTIME_ARG_NAME => amounts.to_vec(),
+        consts::WANTED_IDS_RUNTIME_ARG_NAME => ids.to_vec(),
+        consts::WANTED_AMOUNTS_RUNTIME_ARG_NAME => amounts.to_vec(),
+        consts::EXPIRY_RUNTIME_ARG_NAME => u64::MAX,
+    }
+}
+
+/// Rents `id` of `owner` to the owner itself, and returns the arguments of `set_user`.
+fn rental_args(owner: Key, id: &str) -> RuntimeArgs {
+    runtime_args! {
+        consts::OWNER_RUNTIME_ARG_NAME => owner,
+        consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+        consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(TRANSFERRED_AMOUNT),
+        consts::USER_RUNTIME_ARG_NAME => owner,
+        consts::EXPIRES_RUNTIME_ARG_NAME => u64::MAX,
+    }
+}
+
+fn proposal_args() -> RuntimeArgs {
+    runtime_args! {
+        consts::ACTION_RUNTIME_ARG_NAME => Action::SetPaused(false),
+        consts::EXPIRY_RUNTIME_ARG_NAME => u64::MAX,
+    }
+}
+
+fn operation_args(id: &str) -> RuntimeArgs {
+    runtime_args! {
+        consts::OPERATION_RUNTIME_ARG_NAME => Operation::SetSupplyCap {
+            id: id.to_string(),
+            cap: Some(U256::from(MINTED_AMOUNT)),
+        },
+        consts::DELAY_RUNTIME_ARG_NAME => 0u64,
+    }
+}
+
+/// Runs `entry_point` with an input of `size` ids, and returns the gas it consumed.
+pub fn measure(entry_point: &str, size: usize) -> U512 {
+    let mut bench = Bench::install();
+    let owner = Key::from(*DEFAULT_ACCOUNT_ADDR);
+    let operator = Key::from(AccountHash::new([255u8; 32]));
+    let ids = token_ids(size);
+    let id = ids[0].clone();
+
+    if entry_point != consts::CREATE_ENTRY_POINT_NAME {
+        let initial_supply = if mints_ids(entry_point) {
+            U256::zero()
+        } else {
+            U256::from(MINTED_AMOUNT)
+        };
+        for id in &ids {
+            bench.call(
+                consts::CREATE_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::TOKEN_ID_RUNTIME_ARG_NAME => Some(id.clone()),
+                    consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => initial_supply,
+                    consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+                    consts::FUNGIBLE_RUNTIME_ARG_NAME => true,
+                },
+            );
+        }
+    }
+    let amounts = vec![U256::from(TRANSFERRED_AMOUNT); size];
+    let args = match entry_point {
+        consts::BALANCE_OF_ENTRY_POINT_NAME => runtime_args! {
+            consts::ACCOUNT_RUNTIME_ARG_NAME => owner,
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+        },
+        consts::BALANCE_OF_BATCH_ENTRY_POINT_NAME => runtime_args! {
+            consts::ACCOUNTS_RUNTIME_ARG_NAME => vec![owner; size],
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+        },
+        consts::TOTAL_SUPPLY_ENTRY_POINT_NAME => runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+        },
+        consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME => runtime_args! {
+            consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+            consts::APPROVED_RUNTIME_ARG_NAME => true,
+        },
+        consts::IS_APPROVAL_FOR_ALL_ENTRY_POINT_NAME => runtime_args! {
+            consts::ACCOUNT_RUNTIME_ARG_NAME => owner,
+            consts::OPERATOR_RUNTIME_ARG_NAME => operator,
+        },
+        consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME => runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => owner,
+            consts::RECIPIENT_RUNTIME_ARG_NAME => operator,
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(TRANSFERRED_AMOUNT),
+        },
+        consts::SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME => runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => owner,
+            consts::RECIPIENT_RUNTIME_ARG_NAME => operator,
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+            consts::AMOUNTS_RUNTIME_ARG_NAME => amounts,
+        },
+        consts::SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME => runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => owner,
+            consts::RECIPIENTS_RUNTIME_ARG_NAME => accounts(size),
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+            consts::AMOUNTS_RUNTIME_ARG_NAME => amounts,
+        },
+        consts::MINT_ENTRY_POINT_NAME => runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => owner,
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(MINTED_AMOUNT),
+        },
+        consts::MINT_BATCH_ENTRY_POINT_NAME => runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => owner,
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::from(MINTED_AMOUNT); size],
+        },
+        consts::BURN_ENTRY_POINT_NAME => runtime_args! {
+            consts::OWNER_RUNTIME_ARG_NAME => owner,
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(TRANSFERRED_AMOUNT),
+        },
+        consts::BURN_BATCH_ENTRY_POINT_NAME => runtime_args! {
+            consts::OWNER_RUNTIME_ARG_NAME => owner,
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => ids,
+            consts::AMOUNTS_RUNTIME_ARG_NAME => amounts,
+        },
+        consts::SNAPSHOT_ENTRY_POINT_NAME => RuntimeArgs::new(),
+        consts::CREATE_ENTRY_POINT_NAME => runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => Some(id),
+            consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::from(MINTED_AMOUNT),
+            consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+            consts::FUNGIBLE_RUNTIME_ARG_NAME => true,
+        },
+        consts::SET_MINTER_ENTRY_POINT_NAME => {
+            bench.call(
+                consts::CREATE_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::TOKEN_ID_RUNTIME_ARG_NAME => Some("created".to_string()),
+                    consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::zero(),
+                    consts::URI_RUNTIME_ARG_NAME => TOKEN_URI,
+                    consts::FUNGIBLE_RUNTIME_ARG_NAME => true,
+                },
+            );
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => "created",
+                consts::MINTER_RUNTIME_ARG_NAME => operator,
+                consts::APPROVED_RUNTIME_ARG_NAME => true,
+            }
+        }
+        consts::TRANSFER_OWNERSHIP_ENTRY_POINT_NAME => runtime_args! {
+            consts::NEW_OWNER_RUNTIME_ARG_NAME => operator,
+        },
+        consts::URI_ENTRY_POINT_NAME
+        | consts::MIN_DELAY_ENTRY_POINT_NAME
+        | consts::OWNER_ENTRY_POINT_NAME
+        | consts::PENDING_OWNER_ENTRY_POINT_NAME
+        | consts::RENOUNCE_OWNERSHIP_ENTRY_POINT_NAME
+        | consts::CALLER_POLICY_ENTRY_POINT_NAME => RuntimeArgs::new(),
+        consts::SET_CLAIM_ROOT_ENTRY_POINT_NAME => runtime_args! {
+            consts::CAMPAIGN_RUNTIME_ARG_NAME => CAMPAIGN,
+            consts::ROOT_RUNTIME_ARG_NAME => [1u8; 32],
+        },
+        consts::SET_SALE_ENTRY_POINT_NAME => runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            consts::PRICE_RUNTIME_ARG_NAME => U512::one(),
+            consts::START_TIME_RUNTIME_ARG_NAME => 0u64,
+            consts::END_TIME_RUNTIME_ARG_NAME => u64::MAX,
+            consts::WALLET_LIMIT_RUNTIME_ARG_NAME => U256::zero(),
+            consts::SUPPLY_RUNTIME_ARG_NAME => U256::from(MINTED_AMOUNT),
+        },
+        consts::FRACTIONALIZED_NFT_ENTRY_POINT_NAME
+        | consts::EXPIRY_OF_ENTRY_POINT_NAME
+        | consts::CREATOR_OF_ENTRY_POINT_NAME
+        | consts::EXISTS_ENTRY_POINT_NAME
+        | consts::OWNER_OF_ENTRY_POINT_NAME
+        | consts::SUPPLY_CAP_ENTRY_POINT_NAME => runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+        },
+        consts::IS_NFT_LOCKED_ENTRY_POINT_NAME => runtime_args! {
+            consts::NFT_CONTRACT_RUNTIME_ARG_NAME => [1u8; 32],
+            consts::NFT_TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+        },
+        consts::CREATE_OFFER_ENTRY_POINT_NAME => offer_args(&ids, &amounts),
+        consts::ACCEPT_OFFER_ENTRY_POINT_NAME | consts::CANCEL_OFFER_ENTRY_POINT_NAME => {
+            bench.call(
+                consts::CREATE_OFFER_ENTRY_POINT_NAME,
+                offer_args(&ids, &amounts),
+            );
+            runtime_args! {
+                consts::OFFER_ID_RUNTIME_ARG_NAME => 0u64,
+            }
+        }
+        consts::BALANCE_OF_AT_ENTRY_POINT_NAME => {
+            bench.call(consts::SNAPSHOT_ENTRY_POINT_NAME, RuntimeArgs::new());
+            runtime_args! {
+                consts::ACCOUNT_RUNTIME_ARG_NAME => owner,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::SNAPSHOT_ID_RUNTIME_ARG_NAME => 1u64,
+            }
+        }
+        consts::TOTAL_SUPPLY_AT_ENTRY_POINT_NAME => {
+            bench.call(consts::SNAPSHOT_ENTRY_POINT_NAME, RuntimeArgs::new());
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::SNAPSHOT_ID_RUNTIME_ARG_NAME => 1u64,
+            }
+        }
+        consts::SET_USER_ENTRY_POINT_NAME => rental_args(owner, &id),
+        consts::REVOKE_USER_ENTRY_POINT_NAME | consts::USER_OF_ENTRY_POINT_NAME => {
+            bench.call(consts::SET_USER_ENTRY_POINT_NAME, rental_args(owner, &id));
+            runtime_args! {
+                consts::OWNER_RUNTIME_ARG_NAME => owner,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            }
+        }
+        consts::USABLE_BALANCE_OF_ENTRY_POINT_NAME => {
+            bench.call(consts::SET_USER_ENTRY_POINT_NAME, rental_args(owner, &id));
+            runtime_args! {
+                consts::USER_RUNTIME_ARG_NAME => owner,
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            }
+        }
+        consts::MINT_WITH_EXPIRY_ENTRY_POINT_NAME => runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => owner,
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(MINTED_AMOUNT),
+            consts::EXPIRY_RUNTIME_ARG_NAME => u64::MAX,
+        },
+        consts::SWEEP_EXPIRED_ENTRY_POINT_NAME => {
+            bench.call(
+                consts::MINT_WITH_EXPIRY_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::RECIPIENT_RUNTIME_ARG_NAME => owner,
+                    consts::TOKEN_ID_RUNTIME_ARG_NAME => id.clone(),
+                    consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(MINTED_AMOUNT),
+                    consts::EXPIRY_RUNTIME_ARG_NAME => 1u64,
+                },
+            );
+            bench.blocktime = 1;
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => id,
+                consts::ACCOUNTS_RUNTIME_ARG_NAME => vec![owner],
+            }
+        }
+        consts::PROPOSE_ENTRY_POINT_NAME => proposal_args(),
+        consts::APPROVE_ENTRY_POINT_NAME | consts::EXECUTE_ENTRY_POINT_NAME => {
+            bench.call(consts::PROPOSE_ENTRY_POINT_NAME, proposal_args());
+            runtime_args! {
+                consts::PROPOSAL_ID_RUNTIME_ARG_NAME => 0u64,
+            }
+        }
+        consts::QUEUE_OPERATION_ENTRY_POINT_NAME => operation_args(&id),
+        consts::CANCEL_OPERATION_ENTRY_POINT_NAME | consts::EXECUTE_OPERATION_ENTRY_POINT_NAME => {
+            bench.call(
+                consts::QUEUE_OPERATION_ENTRY_POINT_NAME,
+                operation_args(&id),
+            );
+            runtime_args! {
+                consts::OPERATION_ID_RUNTIME_ARG_NAME => 0u64,
+            }
+        }
+        consts::ACCEPT_OWNERSHIP_ENTRY_POINT_NAME
+        | consts::CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME => {
+            bench.call(
+                consts::TRANSFER_OWNERSHIP_ENTRY_POINT_NAME,
+                runtime_args! {
+                    consts::NEW_OWNER_RUNTIME_ARG_NAME => owner,
+                },
+            );
+            RuntimeArgs::new()
+        }
+        _ => panic!("no scenario for `{}`", entry_point),
+    };
+    bench.call(entry_point, args)
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeSet;
+
+use casper_erc1155::constants as consts;
+
+use crate::scenarios::scenarios;
+
+/// Entry points of the example token without a scenario, with the reason they are not
+/// benchmarked.
+const UNBENCHED: &[(&str, &str)] = &[
+    (
+        consts::CLAIM_ENTRY_POINT_NAME,
+        "needs a merkle proof of a claim",
+    ),
+    (
+        consts::PURCHASE_ENTRY_POINT_NAME,
+        "needs a purse, which only session code can pass",
+    ),
+    (
+        consts::WITHDRAW_ENTRY_POINT_NAME,
+        "needs motes paid by a purchase",
+    ),
+    (consts::WRAP_ENTRY_POINT_NAME, "needs an ERC20 contract"),
+    (consts::UNWRAP_ENTRY_POINT_NAME, "needs an ERC20 contract"),
+    (
+        consts::FRACTIONALIZE_ENTRY_POINT_NAME,
+        "needs an NFT contract",
+    ),
+    (consts::REDEEM_ENTRY_POINT_NAME, "needs an NFT contract"),
+];
+
+#[test]
+fn should_bench_every_example_token_entry_point() {
+    let covered: BTreeSet<&str> = scenarios()
+        .into_iter()
+        .map(|(entry_point, _)| entry_point)
+        .chain(UNBENCHED.iter().map(|(entry_point, _)| *entry_point))
+        .collect();
+    let missing: Vec<String> = erc1155_schema::example_token()
+        .take_entry_points()
+        .into_iter()
+        .map(|entry_point| entry_point.name().to_string())
+        .filter(|name| !covered.contains(name.as_str()))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "entry points without a scenario or a reason in `UNBENCHED`: {:?}",
+        missing
+    );
+}
+
+#[test]
+fn should_only_bench_example_token_entry_points() {
+    let entry_points = erc1155_schema::example_token();
+    for (entry_point, _) in scenarios() {
+        assert!(
+            entry_points.has_entry_point(entry_point),
+            "`{}` has a scenario but is not an entry point of the example token",
+            entry_point
+        );
+    }
+    for (entry_point, _) in UNBENCHED {
+        assert!(
+            entry_points.has_entry_point(entry_point),
+            "`{}` is excluded but is not an entry point of the example token",
+            entry_point
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Parsing of typed command-line flags into the runtime arguments of an entry point.
+//!
+//! Every parameter of an entry point is given as a flag named after it, e.g. `--to`, `--id` or
+//! `--amount`. Values are parsed according to the [`CLType`] of the parameter:
+//!
+//! * `Key`: a formatted key or address, e.g. `account-hash-…`, `hash-…` or
+//!   `contract-package-wasm…`,
+//! * `URef`: a formatted uref, e.g. `uref-…-007`,
+//! * `ByteArray`: hex-encoded bytes,
+//! * `List`: a repeated flag, or values separated by commas,
+//! * `Option`: the value, or nothing when the flag is omitted,
+//! * `Any`: a variant of the encoding listed in the schema, followed by a colon and its fields
+//!   separated by commas, e.g. `Mint:account-hash-…,gold,5` for an `Action`. An encoded field is
+//!   given the same way, e.g. `Action:SetPaused:true` for an `Operation`, and an `Option` field is
+//!   left empty for nothing, e.g. `SetSupplyCap:gold,`,
+//! * numbers and `String` as is, `Bool` as `true` or `false`.
+use std::{collections::BTreeMap, fmt};
+
+use casper_erc1155::{schema, Address};
+use casper_types::{
+    bytesrepr::ToBytes, CLType, CLValue, Key, Parameter, RuntimeArgs, URef, U256, U512,
+};
+
+/// Errors which can be returned while parsing flags.
+#[derive(PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// A flag was given without a value.
+    MissingValue(String),
+    /// A flag doesn't match any parameter of the entry point.
+    UnknownFlag(String),
+    /// A required parameter has no flag.
+    MissingArg(String),
+    /// A value can't be parsed as the type of its parameter.
+    InvalidValue {
+        /// Name of the parameter.
+        name: String,
+        /// Value which can't be parsed.
+        value: String,
+    },
+    /// The type of a parameter can't be given from the command line.
+    UnsupportedType(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingValue(flag) => write!(f, "missing value of `{}`", flag),
+            ParseError::UnknownFlag(flag) => write!(f, "unknown flag `{}`", flag),
+            ParseError::MissingArg(name) => write!(f, "missing `--{}`", flag_name(name)),
+            ParseError::InvalidValue { name, value } => {
+                write!(f, "invalid value `{}` of `--{}`", value, flag_name(name))
+            }
+            ParseError::UnsupportedType(name) => write!(
+                f,
+                "`--{}` can't be given from the command line",
+                flag_name(name)
+            ),
+        }
+    }
+}
+
+/// Returns the flag of the parameter `name`, e.g. `min-delay` for `min_delay`.
+pub fn flag_name(name: &str) -> String {
+    name.replace('_', "-")
+}
+
+/// Groups `--flag value` pairs by flag, keeping the order of repeated flags.
+pub fn collect_flags(args: &[String]) -> Result<BTreeMap<String, Vec<String>>, ParseError> {
+    let mut flags: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let name = flag
+            .strip_prefix("--")
+            .ok_or_else(|| ParseError::UnknownFlag(flag.clone()))?;
+        let value = args
+            .next()
+            .ok_or_else(|| ParseError::MissingValue(flag.clone()))?;
+        flags
+            .entry(name.to_string())
+            .or_default()
+            .push(value.clone());
+    }
+    Ok(flags)
+}
+
+/// Builds the runtime arguments of `parameters` out of `flags`.
+///
+/// Parameters found in `defaults` may be omitted, as well as the `Option` ones.
+pub fn runtime_args(
+    parameters: &[Parameter],
+    mut flags: BTreeMap<String, Vec<String>>,
+    defaults: &RuntimeArgs,
+) -> Result<RuntimeArgs, ParseError> {
+    let mut runtime_args = RuntimeArgs::new();
+    for parameter in parameters {
+        let name = parameter.name();
+        let value = match flags.remove(&flag_name(name)) {
+            Some(values) => parse_value(name, parameter.cl_type(), &values)?,
+            None => match (defaults.get(name), parameter.cl_type()) {
+                (Some(default), _) => default.clone(),
+                (None, CLType::Option(_)) => parse_value(name, parameter.cl_type(), &[])?,
+                (None, _) => return Err(ParseError::MissingArg(name.to_string())),
+            },
+        };
+        runtime_args.insert_cl_value(name, value);
+    }
+    match flags.into_iter().next() {
+        Some((flag, _)) => Err(ParseError::UnknownFlag(format!("--{}", flag))),
+        None => Ok(runtime_args),
+    }
+}
+
+/// Parses the values given for the parameter `name` as a value of `cl_type`.
+pub fn parse_value(name: &str, cl_type: &CLType, values: &[String]) -> Result<CLValue, ParseError> {
+    let values: Vec<&str> = match cl_type {
+        CLType::List(_) => values
+            .iter()
+            .flat_map(|value| value.split(','))
+            .filter(|value| !value.is_empty())
+            .collect(),
+        _ => values.iter().map(String::as_str).collect(),
+    };
+    let bytes = match (cl_type, values.as_slice()) {
+        (CLType::List(item_type), items) => {
+            let mut bytes = (items.len() as u32).to_bytes().unwrap();
+            for item in items {
+                bytes.append(&mut parse_bytes(name, item_type, item)?);
+            }
+            bytes
+        }
+        (CLType::Option(_), []) => vec![0],
+        (CLType::Option(some_type), [value]) => {
+            let mut bytes = vec![1];
+            bytes.append(&mut parse_bytes(name, some_type, value)?);
+            bytes
+        }
+        (_, [value]) => parse_bytes(name, cl_type, value)?,
+        (_, values) => {
+            return Err(ParseError::InvalidValue {
+                name: name.to_string(),
+                value: values.join(","),
+            })
+        }
+    };
+    Ok(CLValue::from_components(cl_type.clone(), bytes))
+}
+
+/// Parses a single value as the serialized bytes of a value of `cl_type`.
+fn parse_bytes(name: &str, cl_type: &CLType, value: &str) -> Result<Vec<u8>, ParseError> {
+    let invalid = || ParseError::InvalidValue {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+    let bytes = match cl_type {
+        CLType::Bool => value.parse::<bool>().map_err(|_| invalid())?.to_bytes(),
+        CLType::U8 => value.parse::<u8>().map_err(|_| invalid())?.to_bytes(),
+        CLType::U32 => value.parse::<u32>().map_err(|_| invalid())?.to_bytes(),
+        CLType::U64 => value.parse::<u64>().map_err(|_| invalid())?.to_bytes(),
+        CLType::U256 => U256::from_dec_str(value).map_err(|_| invalid())?.to_bytes(),
+        CLType::U512 => U512::from_dec_str(value).map_err(|_| invalid())?.to_bytes(),
+        CLType::String => value.to_string().to_bytes(),
+        CLType::Key => Key::from_formatted_str(value)
+            .or_else(|_| value.parse::<Address>().map(Key::from))
+            .map_err(|_| invalid())?
+            .to_bytes(),
+        CLType::URef => URef::from_formatted_str(value)
+            .map_err(|_| invalid())?
+            .to_bytes(),
+        CLType::ByteArray(length) => {
+            let bytes = hex::decode(value).map_err(|_| invalid())?;
+            if bytes.len() != *length as usize {
+                return Err(invalid());
+            }
+            return Ok(bytes);
+        }
+        CLType::Any => {
+            let encoding = schema::encoding(name, cl_type)
+                .ok_or_else(|| ParseError::UnsupportedType(name.to_string()))?;
+            return parse_encoded(name, encoding, value);
+        }
+        _ => return Err(ParseError::UnsupportedType(name.to_string())),
+    };
+    bytes.map_err(|_| invalid())
+}
+
+/// Parses `<variant>:<field>,<field>…` as the serialized bytes of a value of the type `encoding`
+/// of the schema.
+///
+/// The last field takes the rest of the value, so it may contain commas.
+fn parse_encoded(name: &str, encoding: &str, value: &str) -> Result<Vec<u8>, ParseError> {
+    let invalid = || ParseError::InvalidValue {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+    let type_schema = schema::type_schema(encoding)
+        .ok_or_else(|| ParseError::UnsupportedType(name.to_string()))?;
+    let (variant_name, fields) = match value.find(':') {
+        Some(index) => (&value[..index], &value[index + 1..]),
+        None => (value, ""),
+    };
+    let variant = type_schema
+        .variants
+        .iter()
+        .find(|variant| variant.name == variant_name)
+        .ok_or_else(invalid)?;
+    let values: Vec<&str> = match variant.fields.len() {
+        0 => Vec::new(),
+        count => fields.splitn(count, ',').collect(),
+    };
+    if values.len() != variant.fields.len() {
+        return Err(invalid());
+    }
+
+    let mut bytes = vec![variant.tag];
+    for (field, value) in variant.fields.iter().zip(values) {
+        let mut field_bytes = match (&field.encoding, &field.cl_type) {
+            (Some(encoding), _) => {
+                parse_encoded(&field.name, encoding, value).map_err(|_| invalid())?
+            }
+            (None, CLType::Option(_)) if value.is_empty() => vec![0],
+            (None, cl_type) => {
+                let field_value = parse_value(&field.name, cl_type, &[value.to_string()])
+                    .map_err(|_| invalid())?;
+                field_value.inner_bytes().clone()
+            }
+        };
+        bytes.append(&mut field_bytes);
+    }
+    Ok(bytes)
+}
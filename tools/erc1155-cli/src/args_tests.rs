@@ -0,0 +1,261 @@
+use casper_erc1155::{constants as consts, entry_points, Action, Address, CallerPolicy, Operation};
+use casper_types::{
+    account::AccountHash, runtime_args, CLType, ContractPackageHash, Key, RuntimeArgs, U256,
+};
+
+use crate::{
+    args::{collect_flags, parse_value, runtime_args, ParseError},
+    install_defaults, install_parameters, install_warning,
+};
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn should_build_safe_transfer_from_args() {
+    let from = AccountHash::new([1u8; 32]);
+    let to = AccountHash::new([2u8; 32]);
+    let flags = collect_flags(&strings(&[
+        "--from",
+        &from.to_formatted_string(),
+        "--to",
+        &to.to_formatted_string(),
+        "--id",
+        "gold",
+        "--amount",
+        "5",
+    ]))
+    .unwrap();
+
+    let args = runtime_args(
+        entry_points::safe_transfer_from().args(),
+        flags,
+        &RuntimeArgs::new(),
+    )
+    .unwrap();
+    assert_eq!(
+        args,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(from),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(to),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(5),
+        }
+    );
+}
+
+#[test]
+fn should_build_list_args_from_repeated_and_comma_separated_flags() {
+    let owner = AccountHash::new([1u8; 32]);
+    let flags = collect_flags(&strings(&[
+        "--owner",
+        &owner.to_formatted_string(),
+        "--ids",
+        "gold,silver",
+        "--ids",
+        "bronze",
+        "--amounts",
+        "1,2,3",
+    ]))
+    .unwrap();
+
+    let args = runtime_args(
+        entry_points::burn_batch().args(),
+        flags,
+        &RuntimeArgs::new(),
+    )
+    .unwrap();
+    assert_eq!(
+        args.get(consts::TOKEN_IDS_RUNTIME_ARG_NAME)
+            .unwrap()
+            .clone()
+            .into_t::<Vec<String>>()
+            .unwrap(),
+        strings(&["gold", "silver", "bronze"])
+    );
+    assert_eq!(
+        args.get(consts::AMOUNTS_RUNTIME_ARG_NAME)
+            .unwrap()
+            .clone()
+            .into_t::<Vec<U256>>()
+            .unwrap(),
+        vec![U256::from(1), U256::from(2), U256::from(3)]
+    );
+}
+
+#[test]
+fn should_parse_optional_and_byte_array_values() {
+    let none = parse_value("id", &CLType::Option(Box::new(CLType::String)), &[]).unwrap();
+    assert_eq!(none.into_t::<Option<String>>().unwrap(), None);
+
+    let some = parse_value(
+        "id",
+        &CLType::Option(Box::new(CLType::String)),
+        &strings(&["gold"]),
+    )
+    .unwrap();
+    assert_eq!(
+        some.into_t::<Option<String>>().unwrap(),
+        Some("gold".to_string())
+    );
+
+    let root = parse_value(
+        "root",
+        &CLType::ByteArray(32),
+        &strings(&[&"ab".repeat(32)]),
+    )
+    .unwrap();
+    assert_eq!(root.into_t::<[u8; 32]>().unwrap(), [0xab; 32]);
+}
+
+#[test]
+fn should_parse_contract_package_hashes_as_keys() {
+    let contract_package_hash = ContractPackageHash::new([3u8; 32]);
+    let key = parse_value(
+        "to",
+        &CLType::Key,
+        &strings(&[&contract_package_hash.to_formatted_string()]),
+    )
+    .unwrap();
+    assert_eq!(key.into_t::<Key>().unwrap(), Key::Hash([3u8; 32]));
+}
+
+#[test]
+fn should_reject_invalid_flags() {
+    let parameters = entry_points::mint();
+    let parameters = parameters.args();
+
+    let flags = collect_flags(&strings(&["--id", "gold", "--amount", "5"])).unwrap();
+    assert_eq!(
+        runtime_args(parameters, flags, &RuntimeArgs::new()),
+        Err(ParseError::MissingArg(
+            consts::RECIPIENT_RUNTIME_ARG_NAME.to_string()
+        ))
+    );
+
+    let flags = collect_flags(&strings(&[
+        "--to", "nobody", "--id", "gold", "--amount", "5",
+    ]))
+    .unwrap();
+    assert_eq!(
+        runtime_args(parameters, flags, &RuntimeArgs::new()),
+        Err(ParseError::InvalidValue {
+            name: consts::RECIPIENT_RUNTIME_ARG_NAME.to_string(),
+            value: "nobody".to_string(),
+        })
+    );
+
+    let flags = collect_flags(&strings(&["--id", "gold", "--colour", "red"])).unwrap();
+    assert!(matches!(
+        runtime_args(&parameters[1..2], flags, &RuntimeArgs::new()),
+        Err(ParseError::UnknownFlag(_))
+    ));
+
+    assert_eq!(
+        collect_flags(&strings(&["--id"])),
+        Err(ParseError::MissingValue("--id".to_string()))
+    );
+    assert!(matches!(
+        parse_value("payload", &CLType::Any, &strings(&["Mint:"])),
+        Err(ParseError::UnsupportedType(_))
+    ));
+}
+
+#[test]
+fn should_build_propose_args() {
+    let to = AccountHash::new([1u8; 32]);
+    let mint = format!("Mint:{},gold,5", to.to_formatted_string());
+    let flags = collect_flags(&strings(&["--action", &mint, "--expiry", "100"])).unwrap();
+
+    let args = runtime_args(entry_points::propose().args(), flags, &RuntimeArgs::new()).unwrap();
+    assert_eq!(
+        args,
+        runtime_args! {
+            consts::ACTION_RUNTIME_ARG_NAME => Action::Mint {
+                to: Address::from(to),
+                id: "gold".to_string(),
+                amount: U256::from(5),
+            },
+            consts::EXPIRY_RUNTIME_ARG_NAME => 100u64,
+        }
+    );
+}
+
+#[test]
+fn should_build_queue_operation_args() {
+    let queue_operation = |operation: &str| {
+        let flags = collect_flags(&strings(&["--operation", operation, "--delay", "60"])).unwrap();
+        runtime_args(
+            entry_points::queue_operation().args(),
+            flags,
+            &RuntimeArgs::new(),
+        )
+    };
+
+    assert_eq!(
+        queue_operation("SetSupplyCap:gold,1000"),
+        Ok(runtime_args! {
+            consts::OPERATION_RUNTIME_ARG_NAME => Operation::SetSupplyCap {
+                id: "gold".to_string(),
+                cap: Some(U256::from(1000)),
+            },
+            consts::DELAY_RUNTIME_ARG_NAME => 60u64,
+        })
+    );
+    assert_eq!(
+        queue_operation("SetSupplyCap:gold,"),
+        Ok(runtime_args! {
+            consts::OPERATION_RUNTIME_ARG_NAME => Operation::SetSupplyCap {
+                id: "gold".to_string(),
+                cap: None,
+            },
+            consts::DELAY_RUNTIME_ARG_NAME => 60u64,
+        })
+    );
+    assert_eq!(
+        queue_operation("Action:SetUri:ipfs://token,v2"),
+        Ok(runtime_args! {
+            consts::OPERATION_RUNTIME_ARG_NAME =>
+                Operation::Action(Action::SetUri("ipfs://token,v2".to_string())),
+            consts::DELAY_RUNTIME_ARG_NAME => 60u64,
+        })
+    );
+
+    for operation in &[
+        "SetMinDelay",
+        "SetMinDelay:soon",
+        "Upgrade:1",
+        "Action:Mint:gold",
+    ] {
+        assert_eq!(
+            queue_operation(operation),
+            Err(ParseError::InvalidValue {
+                name: consts::OPERATION_RUNTIME_ARG_NAME.to_string(),
+                value: operation.to_string(),
+            })
+        );
+    }
+}
+
+#[test]
+fn should_warn_about_originating_account_caller_policy() {
+    let install = |flags: &[&str]| {
+        let flags = collect_flags(&strings(flags)).unwrap();
+        runtime_args(&install_parameters(), flags, &install_defaults()).unwrap()
+    };
+
+    let args = install(&["--uri", "ipfs://token"]);
+    assert_eq!(
+        args.get(consts::CALLER_POLICY_RUNTIME_ARG_NAME)
+            .cloned()
+            .unwrap()
+            .into_t::<CallerPolicy>()
+            .unwrap(),
+        CallerPolicy::ImmediateCaller
+    );
+    assert_eq!(install_warning(&args), None);
+
+    let args = install(&["--uri", "ipfs://token", "--caller-policy", "1"]);
+    assert!(install_warning(&args).is_some());
+}
@@ -0,0 +1,243 @@
+//! Builds, signs and sends deploys installing or calling an ERC1155 token.
+//!
+//! Usage:
+//!
+//! ```text
+//! erc1155-cli entry-points
+//! erc1155-cli install --wasm <path> --uri <uri> [<flag> <value>]... <deploy options>
+//! erc1155-cli call <entry point> --contract-hash <hash> [<flag> <value>]... <deploy options>
+//! ```
+//!
+//! Every runtime argument is given as a typed flag named after it, e.g.
+//! `erc1155-cli call safe_transfer_from --from account-hash-… --to account-hash-… --id gold
+//! --amount 5`. See the [`args`] module for the format of each type.
+//!
+//! Deploy options are `--secret-key <path>`, `--chain-name <name>`, `--payment-amount <motes>`, an
+//! optional `--ttl <duration>` and `--gas-price <price>`, and either `--output <path>` to write the
+//! signed deploy to disk, or `--node-address <url>` to send it.
+mod args;
+
+use std::{collections::BTreeMap, env, fs, process};
+
+use casper_client::{DeployStrParams, PaymentStrParams, SessionStrParams};
+use casper_erc1155::{constants as consts, entry_points, Address, CallerPolicy};
+use casper_types::{bytesrepr::ToBytes, runtime_args, CLTyped, Key, Parameter, RuntimeArgs};
+use erc1155_schema::{all_extensions, entry_point};
+
+use crate::args::{collect_flags, flag_name, runtime_args, ParseError};
+
+const USAGE: &str = "usage:
+    erc1155-cli entry-points
+    erc1155-cli install --wasm <path> --uri <uri> [<flag> <value>]... <deploy options>
+    erc1155-cli call <entry point> --contract-hash <hash> [<flag> <value>]... <deploy options>
+
+deploy options:
+    --secret-key <path> --chain-name <name> --payment-amount <motes>
+    [--ttl <duration>] [--gas-price <price>] (--output <path> | --node-address <url>)";
+
+const WASM_FLAG: &str = "wasm";
+const CONTRACT_HASH_FLAG: &str = "contract-hash";
+const SECRET_KEY_FLAG: &str = "secret-key";
+const CHAIN_NAME_FLAG: &str = "chain-name";
+const PAYMENT_AMOUNT_FLAG: &str = "payment-amount";
+const TTL_FLAG: &str = "ttl";
+const GAS_PRICE_FLAG: &str = "gas-price";
+const OUTPUT_FLAG: &str = "output";
+const NODE_ADDRESS_FLAG: &str = "node-address";
+
+const DEFAULT_TTL: &str = "30min";
+const DEFAULT_GAS_PRICE: &str = "1";
+
+/// Code executed by a deploy.
+enum Session {
+    /// Installs the token from its compiled contract.
+    Install { wasm: String },
+    /// Calls an entry point of an installed token.
+    Call {
+        contract_hash: String,
+        entry_point: String,
+    },
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+/// Returns the runtime arguments read by `call()` of the example token.
+fn install_parameters() -> Vec<Parameter> {
+    vec![
+        Parameter::new(consts::URI_RUNTIME_ARG_NAME, String::cl_type()),
+        Parameter::new(consts::SIGNERS_RUNTIME_ARG_NAME, Vec::<Address>::cl_type()),
+        Parameter::new(consts::THRESHOLD_RUNTIME_ARG_NAME, u32::cl_type()),
+        Parameter::new(consts::MIN_DELAY_RUNTIME_ARG_NAME, u64::cl_type()),
+        Parameter::new(
+            consts::CALLER_POLICY_RUNTIME_ARG_NAME,
+            CallerPolicy::cl_type(),
+        ),
+    ]
+}
+
+/// Returns the install arguments which may be omitted.
+fn install_defaults() -> RuntimeArgs {
+    runtime_args! {
+        consts::SIGNERS_RUNTIME_ARG_NAME => Vec::<Key>::new(),
+        consts::THRESHOLD_RUNTIME_ARG_NAME => 1u32,
+        consts::MIN_DELAY_RUNTIME_ARG_NAME => 0u64,
+        consts::CALLER_POLICY_RUNTIME_ARG_NAME => CallerPolicy::default(),
+    }
+}
+
+/// Returns a warning about the install arguments `runtime_args`, if any.
+///
+/// Resolving callers to the originating account lets any contract the account calls act with its
+/// tokens, so installing with [`CallerPolicy::OriginatingAccount`] is only done on purpose.
+fn install_warning(runtime_args: &RuntimeArgs) -> Option<&'static str> {
+    let caller_policy: CallerPolicy = runtime_args
+        .get(consts::CALLER_POLICY_RUNTIME_ARG_NAME)?
+        .clone()
+        .into_t()
+        .ok()?;
+    (caller_policy == CallerPolicy::OriginatingAccount).then(|| {
+        "warning: the originating account caller policy lets any contract called by a token \
+         holder transfer, approve and burn its tokens"
+    })
+}
+
+/// Removes the single value of `flag`, if given.
+fn take_flag(flags: &mut BTreeMap<String, Vec<String>>, flag: &str) -> Option<String> {
+    let mut values = flags.remove(flag)?;
+    if values.len() > 1 {
+        fail(&format!("`--{}` can only be given once", flag));
+    }
+    values.pop()
+}
+
+/// Removes the single value of `flag`, failing if it isn't given.
+fn require_flag(flags: &mut BTreeMap<String, Vec<String>>, flag: &str) -> String {
+    take_flag(flags, flag).unwrap_or_else(|| fail(&format!("missing `--{}`", flag)))
+}
+
+fn print_entry_points() {
+    let mut names: Vec<String> = entry_points::extended(all_extensions())
+        .take_entry_points()
+        .into_iter()
+        .map(|entry_point| {
+            let flags: Vec<String> = entry_point
+                .args()
+                .iter()
+                .map(|parameter| format!("--{}", flag_name(parameter.name())))
+                .collect();
+            format!("{} {}", entry_point.name(), flags.join(" "))
+        })
+        .collect();
+    names.sort();
+    for name in names {
+        println!("{}", name.trim_end());
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (session, parameters, defaults, flags) = match args.as_slice() {
+        [command] if command == "entry-points" => return print_entry_points(),
+        [command, flags @ ..] if command == "install" => {
+            let mut flags = collect_flags(flags).unwrap_or_else(|error| fail(&error.to_string()));
+            let wasm = require_flag(&mut flags, WASM_FLAG);
+            let session = Session::Install { wasm };
+            (session, install_parameters(), install_defaults(), flags)
+        }
+        [command, name, flags @ ..] if command == "call" => {
+            let entry_point = entry_point(name)
+                .unwrap_or_else(|| fail(&format!("unknown entry point `{}`", name)));
+            let mut flags = collect_flags(flags).unwrap_or_else(|error| fail(&error.to_string()));
+            let contract_hash = require_flag(&mut flags, CONTRACT_HASH_FLAG);
+            let session = Session::Call {
+                contract_hash,
+                entry_point: name.clone(),
+            };
+            (
+                session,
+                entry_point.args().to_vec(),
+                RuntimeArgs::new(),
+                flags,
+            )
+        }
+        _ => fail(USAGE),
+    };
+
+    let mut flags = flags;
+    let secret_key = require_flag(&mut flags, SECRET_KEY_FLAG);
+    let chain_name = require_flag(&mut flags, CHAIN_NAME_FLAG);
+    let payment_amount = require_flag(&mut flags, PAYMENT_AMOUNT_FLAG);
+    let ttl = take_flag(&mut flags, TTL_FLAG).unwrap_or_else(|| DEFAULT_TTL.to_string());
+    let gas_price =
+        take_flag(&mut flags, GAS_PRICE_FLAG).unwrap_or_else(|| DEFAULT_GAS_PRICE.to_string());
+    let output = take_flag(&mut flags, OUTPUT_FLAG);
+    let node_address = take_flag(&mut flags, NODE_ADDRESS_FLAG);
+
+    let runtime_args = runtime_args(&parameters, flags, &defaults)
+        .unwrap_or_else(|error: ParseError| fail(&error.to_string()));
+    if let Session::Install { .. } = session {
+        if let Some(warning) = install_warning(&runtime_args) {
+            eprintln!("{}", warning);
+        }
+    }
+
+    // Complex arguments are passed to the client as a file of serialized runtime arguments.
+    let args_path = env::temp_dir().join(format!("erc1155-cli-{}.args", process::id()));
+    fs::write(&args_path, runtime_args.to_bytes().unwrap())
+        .unwrap_or_else(|error| fail(&error.to_string()));
+    let args_path = args_path.to_string_lossy().into_owned();
+
+    let session_params = match &session {
+        Session::Install { wasm } => SessionStrParams::with_path(wasm, Vec::new(), &args_path),
+        Session::Call {
+            contract_hash,
+            entry_point,
+        } => {
+            let contract_hash = contract_hash
+                .trim_start_matches("contract-")
+                .trim_start_matches("hash-");
+            SessionStrParams::with_hash(contract_hash, entry_point, Vec::new(), &args_path)
+        }
+    };
+    let payment_params = PaymentStrParams::with_amount(&payment_amount);
+    let deploy_params = DeployStrParams {
+        secret_key: &secret_key,
+        timestamp: "",
+        ttl: &ttl,
+        gas_price: &gas_price,
+        dependencies: Vec::new(),
+        chain_name: &chain_name,
+    };
+
+    let result = match (output, node_address) {
+        (Some(output), None) => casper_client::make_deploy(
+            &output,
+            deploy_params,
+            session_params,
+            payment_params,
+            false,
+        )
+        .map(|()| format!("deploy written to {}", output)),
+        (None, Some(node_address)) => casper_client::put_deploy(
+            "",
+            &node_address,
+            0,
+            deploy_params,
+            session_params,
+            payment_params,
+        )
+        .map(|response| serde_json::to_string_pretty(&response).unwrap()),
+        _ => fail("expected either `--output` or `--node-address`"),
+    };
+    let _ = fs::remove_file(&args_path);
+    match result {
+        Ok(message) => println!("{}", message),
+        Err(error) => fail(&error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod args_tests;
@@ -0,0 +1,50 @@
+//! Error handling of the indexer.
+use std::fmt;
+
+/// Errors which can be returned while indexing deploys.
+#[derive(Debug)]
+pub enum Error {
+    /// Deploy is missing a runtime argument of its entry point.
+    MissingArg(String),
+    /// Runtime argument can't be decoded as the type expected by its entry point.
+    InvalidArg(String),
+    /// Deploy would leave a balance or total supply of the index below zero or above `U256::MAX`,
+    /// which means the index missed some deploys.
+    Inconsistent(String),
+    /// Deploy called an entry point which moves tokens in ways that can't be decoded from its
+    /// runtime arguments.
+    Unsupported(String),
+    /// Records can't be read.
+    Io(std::io::Error),
+    /// Records are not valid JSON.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingArg(name) => write!(f, "missing runtime argument `{}`", name),
+            Error::InvalidArg(name) => write!(f, "invalid runtime argument `{}`", name),
+            Error::Inconsistent(id) => write!(f, "inconsistent amounts of token id `{}`", id),
+            Error::Unsupported(entry_point) => {
+                write!(f, "entry point `{}` can't be indexed", entry_point)
+            }
+            Error::Io(error) => write!(f, "can't read records: {}", error),
+            Error::Json(error) => write!(f, "invalid records: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(error)
+    }
+}
@@ -0,0 +1,267 @@
+//! An off-chain index of the balances, total supplies and operator approvals of an ERC1155 token.
+//!
+//! Dictionary item keys of the token are hashes, so holders can't be listed from the global state.
+//! The index instead replays the deploys which called the token entry points, decoding their
+//! runtime arguments with the names from [`casper_erc1155::constants`].
+//!
+//! Only deploys calling the token directly are supported: the caller of each entry point is the
+//! account which sent the deploy.
+#![warn(missing_docs)]
+
+pub mod error;
+pub mod record;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use casper_erc1155::{constants as consts, Address};
+use casper_types::{account::AccountHash, U256};
+
+pub use error::Error;
+pub use record::{read_records, DeployRecord};
+
+/// Entry points which move tokens depending on contract state missing from their runtime
+/// arguments, and can't be indexed. `create` is only indexed when it names the created id.
+pub const UNSUPPORTED_ENTRY_POINTS: &[&str] = &[
+    consts::CLAIM_ENTRY_POINT_NAME,
+    consts::PURCHASE_ENTRY_POINT_NAME,
+    consts::WRAP_ENTRY_POINT_NAME,
+    consts::UNWRAP_ENTRY_POINT_NAME,
+    consts::FRACTIONALIZE_ENTRY_POINT_NAME,
+    consts::REDEEM_ENTRY_POINT_NAME,
+    consts::CREATE_OFFER_ENTRY_POINT_NAME,
+    consts::ACCEPT_OFFER_ENTRY_POINT_NAME,
+    consts::CANCEL_OFFER_ENTRY_POINT_NAME,
+    consts::SWEEP_EXPIRED_ENTRY_POINT_NAME,
+    consts::EXECUTE_OPERATION_ENTRY_POINT_NAME,
+];
+
+/// Balances, total supplies and operator approvals rebuilt from deploy records.
+#[derive(Default, Debug)]
+pub struct Index {
+    balances: BTreeMap<(String, Address), U256>,
+    total_supplies: BTreeMap<String, U256>,
+    operators: BTreeSet<(Address, Address)>,
+    deploys: u64,
+}
+
+impl Index {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds an index by applying every record, in order.
+    pub fn from_records<'a, I: IntoIterator<Item = &'a DeployRecord>>(
+        records: I,
+    ) -> Result<Self, Error> {
+        let mut index = Self::new();
+        for record in records {
+            index.apply(record)?;
+        }
+        Ok(index)
+    }
+
+    /// Returns the amount of tokens of `id` owned by `account`.
+    pub fn balance_of(&self, account: Address, id: &str) -> U256 {
+        self.balances
+            .get(&(id.to_string(), account))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the total supply of `id`.
+    pub fn total_supply(&self, id: &str) -> U256 {
+        self.total_supplies.get(id).copied().unwrap_or_default()
+    }
+
+    /// Returns true if `operator` is approved to transfer the tokens of `account`.
+    pub fn is_approval_for_all(&self, account: Address, operator: Address) -> bool {
+        self.operators.contains(&(account, operator))
+    }
+
+    /// Returns every holder of `id` with a non-zero balance, and its balance.
+    pub fn holders(&self, id: &str) -> Vec<(Address, U256)> {
+        self.balances
+            .range((id.to_string(), min_address())..)
+            .take_while(|((balance_id, _), _)| balance_id == id)
+            .filter(|(_, balance)| !balance.is_zero())
+            .map(|((_, account), balance)| (*account, *balance))
+            .collect()
+    }
+
+    /// Returns every id which was ever minted.
+    pub fn ids(&self) -> Vec<String> {
+        self.total_supplies.keys().cloned().collect()
+    }
+
+    /// Returns every operator approved by `account`.
+    pub fn operators_of(&self, account: Address) -> Vec<Address> {
+        self.operators
+            .range((account, min_address())..)
+            .take_while(|(owner, _)| *owner == account)
+            .map(|(_, operator)| *operator)
+            .collect()
+    }
+
+    /// Returns the amount of deploys which changed the index.
+    pub fn deploys(&self) -> u64 {
+        self.deploys
+    }
+
+    /// Applies the effects of a deploy to the index.
+    ///
+    /// Failed deploys and entry points which never move tokens or approvals are ignored.
+    pub fn apply(&mut self, record: &DeployRecord) -> Result<(), Error> {
+        if !record.success {
+            return Ok(());
+        }
+        let caller = Address::Account(record.account);
+        match record.entry_point.as_str() {
+            consts::MINT_ENTRY_POINT_NAME | consts::MINT_WITH_EXPIRY_ENTRY_POINT_NAME => {
+                let to = record.arg(consts::RECIPIENT_RUNTIME_ARG_NAME)?;
+                let id: String = record.arg(consts::TOKEN_ID_RUNTIME_ARG_NAME)?;
+                let amount = record.arg(consts::AMOUNT_RUNTIME_ARG_NAME)?;
+                self.mint(to, &id, amount)?;
+            }
+            consts::CREATE_ENTRY_POINT_NAME => {
+                let id: Option<String> = record.arg(consts::TOKEN_ID_RUNTIME_ARG_NAME)?;
+                let initial_supply: U256 = record.arg(consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME)?;
+                let fungible: bool = record.arg(consts::FUNGIBLE_RUNTIME_ARG_NAME)?;
+                let id = id.ok_or_else(|| Error::Unsupported(record.entry_point.clone()))?;
+                // The contract rejects an initial supply for non-fungible types, whose base id is
+                // never minted, so a successful deploy with one means the records are corrupted.
+                if !fungible && !initial_supply.is_zero() {
+                    return Err(Error::Inconsistent(id));
+                }
+                self.mint(caller, &id, initial_supply)?;
+            }
+            consts::BURN_ENTRY_POINT_NAME => {
+                let owner = record.arg(consts::OWNER_RUNTIME_ARG_NAME)?;
+                let id: String = record.arg(consts::TOKEN_ID_RUNTIME_ARG_NAME)?;
+                let amount = record.arg(consts::AMOUNT_RUNTIME_ARG_NAME)?;
+                self.burn(owner, &id, amount)?;
+            }
+            consts::MINT_BATCH_ENTRY_POINT_NAME => {
+                let to = record.arg(consts::RECIPIENT_RUNTIME_ARG_NAME)?;
+                let ids: Vec<String> = record.arg(consts::TOKEN_IDS_RUNTIME_ARG_NAME)?;
+                let amounts: Vec<U256> = record.arg(consts::AMOUNTS_RUNTIME_ARG_NAME)?;
+                for (id, amount) in ids.iter().zip(amounts) {
+                    self.mint(to, id, amount)?;
+                }
+            }
+            consts::BURN_BATCH_ENTRY_POINT_NAME => {
+                let owner = record.arg(consts::OWNER_RUNTIME_ARG_NAME)?;
+                let ids: Vec<String> = record.arg(consts::TOKEN_IDS_RUNTIME_ARG_NAME)?;
+                let amounts: Vec<U256> = record.arg(consts::AMOUNTS_RUNTIME_ARG_NAME)?;
+                for (id, amount) in ids.iter().zip(amounts) {
+                    self.burn(owner, id, amount)?;
+                }
+            }
+            consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME => {
+                let from = record.arg(consts::FROM_RUNTIME_ARG_NAME)?;
+                let to = record.arg(consts::RECIPIENT_RUNTIME_ARG_NAME)?;
+                let id: String = record.arg(consts::TOKEN_ID_RUNTIME_ARG_NAME)?;
+                let amount = record.arg(consts::AMOUNT_RUNTIME_ARG_NAME)?;
+                // The contract silently ignores self-transfers, even above the balance of `from`.
+                if self.can_transfer(caller, from) && from != to {
+                    self.transfer(from, to, &id, amount)?;
+                }
+            }
+            consts::SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME => {
+                let from = record.arg(consts::FROM_RUNTIME_ARG_NAME)?;
+                let to = record.arg(consts::RECIPIENT_RUNTIME_ARG_NAME)?;
+                let ids: Vec<String> = record.arg(consts::TOKEN_IDS_RUNTIME_ARG_NAME)?;
+                let amounts: Vec<U256> = record.arg(consts::AMOUNTS_RUNTIME_ARG_NAME)?;
+                // Self-transfers are ignored by the contract, as for single transfers.
+                if self.can_transfer(caller, from) && from != to {
+                    for (id, amount) in ids.iter().zip(amounts) {
+                        self.transfer(from, to, id, amount)?;
+                    }
+                }
+            }
+            consts::SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME => {
+                let from = record.arg(consts::FROM_RUNTIME_ARG_NAME)?;
+                let recipients: Vec<Address> = record.arg(consts::RECIPIENTS_RUNTIME_ARG_NAME)?;
+                let ids: Vec<String> = record.arg(consts::TOKEN_IDS_RUNTIME_ARG_NAME)?;
+                let amounts: Vec<U256> = record.arg(consts::AMOUNTS_RUNTIME_ARG_NAME)?;
+                if self.can_transfer(caller, from) {
+                    for ((to, id), amount) in recipients.into_iter().zip(ids.iter()).zip(amounts) {
+                        self.transfer(from, to, id, amount)?;
+                    }
+                }
+            }
+            consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME => {
+                let operator = record.arg(consts::OPERATOR_RUNTIME_ARG_NAME)?;
+                let approved = record.arg(consts::APPROVED_RUNTIME_ARG_NAME)?;
+                if approved {
+                    self.operators.insert((caller, operator));
+                } else {
+                    self.operators.remove(&(caller, operator));
+                }
+            }
+            entry_point if UNSUPPORTED_ENTRY_POINTS.contains(&entry_point) => {
+                return Err(Error::Unsupported(entry_point.to_string()));
+            }
+            _ => return Ok(()),
+        }
+        self.deploys += 1;
+        Ok(())
+    }
+
+    /// Returns true if `spender` can move the tokens of `owner`. The contract silently ignores
+    /// transfers made by other callers.
+    fn can_transfer(&self, spender: Address, owner: Address) -> bool {
+        spender == owner || self.is_approval_for_all(owner, spender)
+    }
+
+    fn mint(&mut self, to: Address, id: &str, amount: U256) -> Result<(), Error> {
+        let total_supply = self.total_supplies.entry(id.to_string()).or_default();
+        *total_supply = total_supply
+            .checked_add(amount)
+            .ok_or_else(|| Error::Inconsistent(id.to_string()))?;
+        self.credit(to, id, amount)
+    }
+
+    fn burn(&mut self, owner: Address, id: &str, amount: U256) -> Result<(), Error> {
+        let total_supply = self.total_supplies.entry(id.to_string()).or_default();
+        *total_supply = total_supply
+            .checked_sub(amount)
+            .ok_or_else(|| Error::Inconsistent(id.to_string()))?;
+        self.debit(owner, id, amount)
+    }
+
+    fn transfer(
+        &mut self,
+        from: Address,
+        to: Address,
+        id: &str,
+        amount: U256,
+    ) -> Result<(), Error> {
+        self.debit(from, id, amount)?;
+        self.credit(to, id, amount)
+    }
+
+    fn credit(&mut self, account: Address, id: &str, amount: U256) -> Result<(), Error> {
+        let balance = self.balances.entry((id.to_string(), account)).or_default();
+        *balance = balance
+            .checked_add(amount)
+            .ok_or_else(|| Error::Inconsistent(id.to_string()))?;
+        Ok(())
+    }
+
+    fn debit(&mut self, account: Address, id: &str, amount: U256) -> Result<(), Error> {
+        let balance = self.balances.entry((id.to_string(), account)).or_default();
+        *balance = balance
+            .checked_sub(amount)
+            .ok_or_else(|| Error::Inconsistent(id.to_string()))?;
+        Ok(())
+    }
+}
+
+/// Returns the lowest address, used as the start of range queries.
+fn min_address() -> Address {
+    Address::Account(AccountHash::new([0; 32]))
+}
+
+#[cfg(test)]
+mod replay_tests;
@@ -0,0 +1,64 @@
+//! Rebuilds the index of an ERC1155 token from a JSON file of deploy records, and queries it.
+//!
+//! Usage:
+//!
+//! ```text
+//! erc1155-indexer <records.json> ids
+//! erc1155-indexer <records.json> holders <id>
+//! erc1155-indexer <records.json> balance <account> <id>
+//! erc1155-indexer <records.json> supply <id>
+//! erc1155-indexer <records.json> operators <account>
+//! ```
+//!
+//! Accounts are formatted addresses, e.g. `account-hash-…`, `hash-…` or
+//! `contract-package-wasm…`.
+use std::{env, process};
+
+use casper_erc1155::{Address, AddressFromStrError};
+use erc1155_indexer::{read_records, Index};
+
+const USAGE: &str = "usage: erc1155-indexer <records.json> \
+    (ids | holders <id> | balance <account> <id> | supply <id> | operators <account>)";
+
+fn parse_address(input: &str) -> Address {
+    input
+        .parse()
+        .unwrap_or_else(|error: AddressFromStrError| fail(&error.to_string()))
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (path, query) = match args.split_first() {
+        Some((path, query)) if !query.is_empty() => (path, query),
+        _ => fail(USAGE),
+    };
+    let records = read_records(path).unwrap_or_else(|error| fail(&error.to_string()));
+    let index = Index::from_records(&records).unwrap_or_else(|error| fail(&error.to_string()));
+
+    let query: Vec<&str> = query.iter().map(String::as_str).collect();
+    match query.as_slice() {
+        ["ids"] => {
+            for id in index.ids() {
+                println!("{}", id);
+            }
+        }
+        ["holders", id] => {
+            for (holder, balance) in index.holders(id) {
+                println!("{} {}", holder, balance);
+            }
+        }
+        ["balance", account, id] => println!("{}", index.balance_of(parse_address(account), id)),
+        ["supply", id] => println!("{}", index.total_supply(id)),
+        ["operators", account] => {
+            for operator in index.operators_of(parse_address(account)) {
+                println!("{}", operator);
+            }
+        }
+        _ => fail(USAGE),
+    }
+}
@@ -0,0 +1,41 @@
+//! Implementation of the deploy records consumed by the indexer.
+use std::{fs::File, io::BufReader, path::Path};
+
+use casper_types::{account::AccountHash, bytesrepr::FromBytes, CLTyped, RuntimeArgs};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// A deploy which called an entry point of the token, with its execution result.
+///
+/// Records can be built from the deploys and execution results returned by a node, or written by
+/// hand as a stand-in event stream.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct DeployRecord {
+    /// Account which sent the deploy.
+    pub account: AccountHash,
+    /// Name of the called entry point.
+    pub entry_point: String,
+    /// Runtime arguments passed to the entry point.
+    pub args: RuntimeArgs,
+    /// Whether the deploy executed successfully. Failed deploys leave the token untouched.
+    pub success: bool,
+}
+
+impl DeployRecord {
+    /// Decodes the runtime argument `name`.
+    pub fn arg<T: CLTyped + FromBytes>(&self, name: &str) -> Result<T, Error> {
+        self.args
+            .get(name)
+            .ok_or_else(|| Error::MissingArg(name.to_string()))?
+            .clone()
+            .into_t()
+            .map_err(|_| Error::InvalidArg(name.to_string()))
+    }
+}
+
+/// Reads a JSON array of deploy records from `path`.
+pub fn read_records<P: AsRef<Path>>(path: P) -> Result<Vec<DeployRecord>, Error> {
+    let reader = BufReader::new(File::open(path)?);
+    Ok(serde_json::from_reader(reader)?)
+}
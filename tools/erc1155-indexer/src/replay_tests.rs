@@ -0,0 +1,456 @@
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use casper_engine_test_support::{Code, SessionBuilder, TestContext, TestContextBuilder};
+use casper_erc1155::{constants as consts, Address, CallerPolicy};
+use casper_types::{
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, AsymmetricType, ContractHash, Key,
+    PublicKey, RuntimeArgs, U256, U512,
+};
+
+use crate::{DeployRecord, Error, Index};
+
+const CONTRACT_ERC1155_TOKEN: &str = "erc1155_token.wasm";
+const CONTRACT_KEY_NAME: &str = "erc1155_token_contract";
+
+fn blake2b256(item_key_string: &[u8]) -> Box<[u8]> {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(item_key_string);
+    hasher.finalize_boxed()
+}
+
+/// Runs deploys against the example token, and records them the way the indexer consumes them.
+struct Replay {
+    context: TestContext,
+    records: Vec<DeployRecord>,
+    ali: AccountHash,
+    bob: AccountHash,
+    joe: AccountHash,
+}
+
+impl Replay {
+    fn install() -> Replay {
+        let ali = PublicKey::ed25519_from_bytes([3u8; 32]).unwrap();
+        let bob = PublicKey::ed25519_from_bytes([6u8; 32]).unwrap();
+        let joe = PublicKey::ed25519_from_bytes([9u8; 32]).unwrap();
+
+        let mut context = TestContextBuilder::new()
+            .with_public_key(ali.clone(), U512::from(500_000_000_000_000_000u64))
+            .with_public_key(bob.clone(), U512::from(500_000_000_000_000_000u64))
+            .build();
+
+        let session_args = runtime_args! {
+            consts::URI_RUNTIME_ARG_NAME => "https://myuri-example.com",
+            consts::SIGNERS_RUNTIME_ARG_NAME => Vec::<Key>::new(),
+            consts::THRESHOLD_RUNTIME_ARG_NAME => 1u32,
+            consts::MIN_DELAY_RUNTIME_ARG_NAME => 0u64,
+            consts::CALLER_POLICY_RUNTIME_ARG_NAME => CallerPolicy::ImmediateCaller,
+        };
+        let session = SessionBuilder::new(Code::from(CONTRACT_ERC1155_TOKEN), session_args)
+            .with_address(ali.to_account_hash())
+            .with_authorization_keys(&[ali.to_account_hash()])
+            .build();
+        context.run(session);
+
+        Replay {
+            context,
+            records: Vec::new(),
+            ali: ali.to_account_hash(),
+            bob: bob.to_account_hash(),
+            joe: joe.to_account_hash(),
+        }
+    }
+
+    fn contract_hash(&self) -> ContractHash {
+        self.context
+            .get_account(self.ali)
+            .unwrap()
+            .named_keys()
+            .get(CONTRACT_KEY_NAME)
+            .unwrap()
+            .normalize()
+            .into_hash()
+            .unwrap()
+            .into()
+    }
+
+    fn call(&mut self, account: AccountHash, entry_point: &str, args: RuntimeArgs) {
+        let code = Code::Hash(self.contract_hash().value(), entry_point.to_string());
+        let session = SessionBuilder::new(code, args.clone())
+            .with_address(account)
+            .with_authorization_keys(&[account])
+            .build();
+        self.context.run(session);
+        self.records.push(DeployRecord {
+            account,
+            entry_point: entry_point.to_string(),
+            args,
+            success: true,
+        });
+    }
+
+    /// Creates the fungible token type `id` without initial supply, so that `account` can mint it.
+    fn create(&mut self, account: AccountHash, id: &str) {
+        self.call(
+            account,
+            consts::CREATE_ENTRY_POINT_NAME,
+            runtime_args! {
+                consts::TOKEN_ID_RUNTIME_ARG_NAME => Some(id.to_string()),
+                consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::zero(),
+                consts::URI_RUNTIME_ARG_NAME => "",
+                consts::FUNGIBLE_RUNTIME_ARG_NAME => true,
+            },
+        );
+    }
+
+    fn query(&self, dictionary: &str, item_key: String) -> Option<casper_types::CLValue> {
+        self.context
+            .query_dictionary_item(
+                Key::Hash(self.contract_hash().value()),
+                Some(dictionary.to_string()),
+                item_key,
+            )
+            .ok()
+    }
+
+    fn balance_of(&self, account: Address, id: &str) -> U256 {
+        let mut preimage = Vec::new();
+        preimage.append(&mut id.to_bytes().unwrap());
+        preimage.append(&mut account.to_bytes().unwrap());
+        let item_key = base64::encode(blake2b256(&preimage));
+        self.query(consts::BALANCES_KEY_NAME, item_key)
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    fn total_supply(&self, id: &str) -> U256 {
+        let item_key = format!("total_supply_{}", id);
+        self.query(consts::TOTAL_SUPPLY_KEY_NAME, item_key)
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    fn is_approval_for_all(&self, account: Address, operator: Address) -> bool {
+        let mut preimage = Vec::new();
+        preimage.append(&mut account.to_bytes().unwrap());
+        preimage.append(&mut operator.to_bytes().unwrap());
+        let item_key = hex::encode(blake2b256(&preimage));
+        self.query(consts::OPERATORS_KEY_NAME, item_key)
+            .map(|value| value.into_t().unwrap())
+            .unwrap_or_default()
+    }
+
+    /// Asserts that an index rebuilt from the records matches the contract state.
+    fn assert_index_matches(&self, ids: &[&str]) -> Index {
+        let json = serde_json::to_string(&self.records).unwrap();
+        let records: Vec<DeployRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(records, self.records);
+
+        let index = Index::from_records(&records).unwrap();
+        let accounts: Vec<Address> = vec![self.ali, self.bob, self.joe]
+            .into_iter()
+            .map(Address::Account)
+            .collect();
+        for id in ids {
+            assert_eq!(index.total_supply(id), self.total_supply(id), "{}", id);
+            for account in &accounts {
+                assert_eq!(
+                    index.balance_of(*account, id),
+                    self.balance_of(*account, id),
+                    "{} of {:?}",
+                    id,
+                    account
+                );
+            }
+        }
+        for account in &accounts {
+            for operator in &accounts {
+                assert_eq!(
+                    index.is_approval_for_all(*account, *operator),
+                    self.is_approval_for_all(*account, *operator)
+                );
+            }
+        }
+        index
+    }
+}
+
+#[test]
+fn should_replay_mints_burns_and_transfers() {
+    let mut replay = Replay::install();
+    let (ali, bob, joe) = (replay.ali, replay.bob, replay.joe);
+
+    for id in &["gold", "silver"] {
+        replay.create(ali, id);
+    }
+    replay.call(
+        ali,
+        consts::MINT_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(100),
+        },
+    );
+    replay.call(
+        ali,
+        consts::MINT_BATCH_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(bob),
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => vec![
+                "gold".to_string(),
+                "silver".to_string(),
+                "gold".to_string(),
+            ],
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::from(5), U256::from(50), U256::from(5)],
+        },
+    );
+    replay.call(
+        ali,
+        consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(joe),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(30),
+        },
+    );
+    replay.call(
+        bob,
+        consts::SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(bob),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => vec!["silver".to_string(), "gold".to_string()],
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::from(20), U256::from(10)],
+        },
+    );
+    replay.call(
+        ali,
+        consts::SAFE_MULTI_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::RECIPIENTS_RUNTIME_ARG_NAME => vec![Key::from(bob), Key::from(joe)],
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => vec!["gold".to_string(), "silver".to_string()],
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::from(7), U256::from(3)],
+        },
+    );
+    replay.call(
+        ali,
+        consts::BURN_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::OWNER_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(10),
+        },
+    );
+    replay.call(
+        bob,
+        consts::BURN_BATCH_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::OWNER_RUNTIME_ARG_NAME => Key::from(bob),
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => vec!["silver".to_string()],
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::from(30)],
+        },
+    );
+
+    let index = replay.assert_index_matches(&["gold", "silver"]);
+    assert_eq!(index.deploys(), 9);
+    assert_eq!(index.ids(), vec!["gold".to_string(), "silver".to_string()]);
+    let mut holders = vec![
+        (Address::Account(ali), U256::from(63)),
+        (Address::Account(bob), U256::from(7)),
+        (Address::Account(joe), U256::from(30)),
+    ];
+    holders.sort();
+    assert_eq!(index.holders("gold"), holders);
+    assert_eq!(index.holders("silver").len(), 2);
+}
+
+#[test]
+fn should_replay_operator_approvals_and_ignored_transfers() {
+    let mut replay = Replay::install();
+    let (ali, bob, joe) = (replay.ali, replay.bob, replay.joe);
+
+    replay.create(ali, "gold");
+    replay.call(
+        ali,
+        consts::MINT_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(100),
+        },
+    );
+    // Bob is no operator of Ali yet, so the contract silently ignores the transfer.
+    replay.call(
+        bob,
+        consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(bob),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(40),
+        },
+    );
+    replay.call(
+        ali,
+        consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::OPERATOR_RUNTIME_ARG_NAME => Key::from(bob),
+            consts::APPROVED_RUNTIME_ARG_NAME => true,
+        },
+    );
+    replay.call(
+        bob,
+        consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(joe),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(40),
+        },
+    );
+    replay.call(
+        ali,
+        consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::OPERATOR_RUNTIME_ARG_NAME => Key::from(joe),
+            consts::APPROVED_RUNTIME_ARG_NAME => true,
+        },
+    );
+    replay.call(
+        ali,
+        consts::SET_APPROVAL_FOR_ALL_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::OPERATOR_RUNTIME_ARG_NAME => Key::from(bob),
+            consts::APPROVED_RUNTIME_ARG_NAME => false,
+        },
+    );
+
+    let index = replay.assert_index_matches(&["gold"]);
+    assert_eq!(
+        index.balance_of(Address::Account(bob), "gold"),
+        U256::zero()
+    );
+    assert_eq!(
+        index.operators_of(Address::Account(ali)),
+        vec![Address::Account(joe)]
+    );
+}
+
+#[test]
+fn should_replay_non_fungible_items_and_ignored_self_transfers() {
+    let mut replay = Replay::install();
+    let ali = replay.ali;
+
+    replay.call(
+        ali,
+        consts::CREATE_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => Some("sword".to_string()),
+            consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::zero(),
+            consts::URI_RUNTIME_ARG_NAME => "",
+            consts::FUNGIBLE_RUNTIME_ARG_NAME => false,
+        },
+    );
+    replay.call(
+        ali,
+        consts::MINT_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "sword#1",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::one(),
+        },
+    );
+    // Self-transfers are ignored by the contract, even of items Ali doesn't hold.
+    replay.call(
+        ali,
+        consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "sword#2",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::one(),
+        },
+    );
+    replay.call(
+        ali,
+        consts::SAFE_BATCH_TRANSFER_FROM_ENTRY_POINT_NAME,
+        runtime_args! {
+            consts::FROM_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::RECIPIENT_RUNTIME_ARG_NAME => Key::from(ali),
+            consts::TOKEN_IDS_RUNTIME_ARG_NAME => vec!["sword#1".to_string(), "sword#2".to_string()],
+            consts::AMOUNTS_RUNTIME_ARG_NAME => vec![U256::one(), U256::one()],
+        },
+    );
+
+    let index = replay.assert_index_matches(&["sword", "sword#1", "sword#2"]);
+    assert!(index.holders("sword").is_empty());
+    assert_eq!(
+        index.holders("sword#1"),
+        vec![(Address::Account(ali), U256::one())]
+    );
+}
+
+#[test]
+fn should_reject_initial_supply_of_non_fungible_type() {
+    let mut index = Index::new();
+    let record = DeployRecord {
+        account: AccountHash::new([3u8; 32]),
+        entry_point: consts::CREATE_ENTRY_POINT_NAME.to_string(),
+        args: runtime_args! {
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => Some("sword".to_string()),
+            consts::INITIAL_SUPPLY_RUNTIME_ARG_NAME => U256::one(),
+            consts::URI_RUNTIME_ARG_NAME => "",
+            consts::FUNGIBLE_RUNTIME_ARG_NAME => false,
+        },
+        success: false,
+    };
+    index.apply(&record).unwrap();
+    assert_eq!(index.deploys(), 0);
+
+    let record = DeployRecord {
+        success: true,
+        ..record
+    };
+    assert!(matches!(index.apply(&record), Err(Error::Inconsistent(_))));
+    assert_eq!(index.total_supply("sword"), U256::zero());
+}
+
+#[test]
+fn should_skip_failed_deploys_and_reject_unsupported_ones() {
+    let mut index = Index::new();
+    let record = DeployRecord {
+        account: AccountHash::new([3u8; 32]),
+        entry_point: consts::BURN_ENTRY_POINT_NAME.to_string(),
+        args: runtime_args! {
+            consts::OWNER_RUNTIME_ARG_NAME => Key::from(AccountHash::new([3u8; 32])),
+            consts::TOKEN_ID_RUNTIME_ARG_NAME => "gold",
+            consts::AMOUNT_RUNTIME_ARG_NAME => U256::from(1),
+        },
+        success: false,
+    };
+    index.apply(&record).unwrap();
+    assert_eq!(index.deploys(), 0);
+
+    let record = DeployRecord {
+        success: true,
+        ..record
+    };
+    assert!(matches!(index.apply(&record), Err(Error::Inconsistent(_))));
+
+    let record = DeployRecord {
+        entry_point: consts::CLAIM_ENTRY_POINT_NAME.to_string(),
+        ..record
+    };
+    assert!(matches!(index.apply(&record), Err(Error::Unsupported(_))));
+
+    let record = DeployRecord {
+        entry_point: consts::MINT_ENTRY_POINT_NAME.to_string(),
+        args: RuntimeArgs::new(),
+        ..record
+    };
+    assert!(matches!(index.apply(&record), Err(Error::MissingArg(_))));
+}
@@ -0,0 +1,195 @@
+use casper_erc1155::{
+    constants as consts, entry_points,
+    schema::{self, Schema},
+    Action, Address, Operation,
+};
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, CLType, EntryPoints, U256};
+
+use crate::{entry_point, example_token, extension, EXTENSIONS};
+
+const DEFAULT_SCHEMA: &str = include_str!("../schema/default.json");
+const ERC1155_TOKEN_SCHEMA: &str = include_str!("../schema/erc1155-token.json");
+
+fn assert_schema(entry_points: &EntryPoints, expected: &str, command: &str) {
+    let expected = Schema::from_json(expected).unwrap();
+    let actual = Schema::from(entry_points);
+    assert!(
+        actual == expected,
+        "entry points changed, regenerate the schema with `{}`:\n{}",
+        command,
+        actual.to_json()
+    );
+}
+
+#[test]
+fn default_schema_should_match_default_entry_points() {
+    assert_schema(
+        &entry_points::default(),
+        DEFAULT_SCHEMA,
+        "cargo run -p erc1155-schema -- --output tools/erc1155-schema/schema/default.json",
+    );
+}
+
+#[test]
+fn example_token_schema_should_match_its_entry_points() {
+    assert_schema(
+        &example_token(),
+        ERC1155_TOKEN_SCHEMA,
+        "cargo run -p erc1155-schema -- --all-extensions \
+         --output tools/erc1155-schema/schema/erc1155-token.json",
+    );
+}
+
+#[test]
+fn every_extension_should_be_known() {
+    for name in EXTENSIONS {
+        assert!(!extension(name).unwrap().is_empty(), "{}", name);
+    }
+    assert!(extension("unknown").is_none());
+}
+
+#[test]
+fn should_export_custom_set() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(entry_points::safe_transfer_from());
+    entry_points.add_entry_point(entry_point(consts::CLAIM_ENTRY_POINT_NAME).unwrap());
+
+    let schema = Schema::from(&entry_points);
+    let names: Vec<&str> = schema
+        .entry_points
+        .iter()
+        .map(|entry_point| entry_point.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            consts::CLAIM_ENTRY_POINT_NAME,
+            consts::SAFE_TRANSFER_FROM_ENTRY_POINT_NAME
+        ]
+    );
+
+    let transfer = &schema.entry_points[1];
+    let parameters: Vec<(&str, &CLType)> = transfer
+        .parameters
+        .iter()
+        .map(|parameter| (parameter.name.as_str(), &parameter.cl_type))
+        .collect();
+    assert_eq!(
+        parameters,
+        vec![
+            (consts::FROM_RUNTIME_ARG_NAME, &CLType::Key),
+            (consts::RECIPIENT_RUNTIME_ARG_NAME, &CLType::Key),
+            (consts::TOKEN_ID_RUNTIME_ARG_NAME, &CLType::String),
+            (consts::AMOUNT_RUNTIME_ARG_NAME, &CLType::U256),
+        ]
+    );
+    assert_eq!(transfer.ret, CLType::Unit);
+
+    let json = schema::to_json(&entry_points);
+    assert_eq!(Schema::from_json(&json).unwrap(), schema);
+}
+
+#[test]
+fn any_parameters_should_name_a_listed_encoding() {
+    let schema = Schema::from(&example_token());
+    let parameters = schema
+        .entry_points
+        .iter()
+        .flat_map(|entry_point| entry_point.parameters.iter())
+        .chain(
+            schema
+                .types
+                .iter()
+                .flat_map(|type_schema| type_schema.variants.iter())
+                .flat_map(|variant| variant.fields.iter()),
+        );
+    for parameter in parameters.filter(|parameter| parameter.cl_type == CLType::Any) {
+        let encoding = parameter.encoding.as_ref().unwrap();
+        assert!(
+            schema
+                .types
+                .iter()
+                .any(|type_schema| &type_schema.name == encoding),
+            "{}",
+            encoding
+        );
+    }
+}
+
+#[test]
+fn encodings_should_match_serialized_tags() {
+    let schema = Schema::from(&example_token());
+    let tag = |type_name: &str, variant_name: &str| {
+        schema
+            .types
+            .iter()
+            .find(|type_schema| type_schema.name == type_name)
+            .and_then(|type_schema| {
+                type_schema
+                    .variants
+                    .iter()
+                    .find(|variant| variant.name == variant_name)
+            })
+            .map(|variant| variant.tag)
+            .unwrap()
+    };
+    let address = Address::from(AccountHash::new([1u8; 32]));
+
+    let actions = vec![
+        (
+            Action::Mint {
+                to: address,
+                id: String::from("gold"),
+                amount: U256::one(),
+            },
+            "Mint",
+        ),
+        (
+            Action::Burn {
+                owner: address,
+                id: String::from("gold"),
+                amount: U256::one(),
+            },
+            "Burn",
+        ),
+        (
+            Action::SetSigner {
+                signer: address,
+                approved: true,
+            },
+            "SetSigner",
+        ),
+        (Action::SetThreshold(1), "SetThreshold"),
+        (Action::SetPaused(true), "SetPaused"),
+        (Action::SetUri(String::from("ipfs://token")), "SetUri"),
+    ];
+    for (action, name) in actions {
+        assert_eq!(
+            action.to_bytes().unwrap()[0],
+            tag("Action", name),
+            "{}",
+            name
+        );
+    }
+
+    let operations = vec![
+        (Operation::Action(Action::SetPaused(true)), "Action"),
+        (Operation::SetMinDelay(1), "SetMinDelay"),
+        (Operation::TransferOwnership(address), "TransferOwnership"),
+        (
+            Operation::SetSupplyCap {
+                id: String::from("gold"),
+                cap: None,
+            },
+            "SetSupplyCap",
+        ),
+    ];
+    for (operation, name) in operations {
+        assert_eq!(
+            operation.to_bytes().unwrap()[0],
+            tag("Operation", name),
+            "{}",
+            name
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Builds the entry point sets of an ERC1155 token, whose JSON schema is exported by the
+//! `erc1155-schema` binary.
+//!
+//! The schema itself is built by [`casper_erc1155::schema`]. The sets checked in under `schema/`
+//! are compared with the current entry points by the tests of this crate, so any change of a name,
+//! parameter or type has to be reflected in them.
+#![warn(missing_docs)]
+
+use casper_erc1155::entry_points;
+use casper_types::{EntryPoint, EntryPoints};
+
+/// Names of the extension sets of entry points, in the order they are installed by the example
+/// token.
+pub const EXTENSIONS: &[&str] = &[
+    "claims",
+    "sales",
+    "wrapper",
+    "fractions",
+    "escrow",
+    "snapshots",
+    "rentals",
+    "expiries",
+    "registry",
+    "multisig",
+    "timelock",
+    "ownership",
+    "caller_policy",
+    "safe_multi_transfer_from",
+];
+
+/// Returns the entry points of the extension `name`, one of [`EXTENSIONS`].
+pub fn extension(name: &str) -> Option<Vec<EntryPoint>> {
+    let entry_points = match name {
+        "claims" => entry_points::claims(),
+        "sales" => entry_points::sales(),
+        "wrapper" => entry_points::wrapper(),
+        "fractions" => entry_points::fractions(),
+        "escrow" => entry_points::escrow(),
+        "snapshots" => entry_points::snapshots(),
+        "rentals" => entry_points::rentals(),
+        "expiries" => entry_points::expiries(),
+        "registry" => entry_points::registry(),
+        "multisig" => entry_points::multisig(),
+        "timelock" => entry_points::timelock(),
+        "ownership" => entry_points::ownership(),
+        "caller_policy" => vec![entry_points::caller_policy()],
+        "safe_multi_transfer_from" => vec![entry_points::safe_multi_transfer_from()],
+        _ => return None,
+    };
+    Some(entry_points)
+}
+
+/// Returns every entry point of every extension.
+pub fn all_extensions() -> Vec<EntryPoint> {
+    EXTENSIONS
+        .iter()
+        .flat_map(|name| extension(name).unwrap_or_default())
+        .collect()
+}
+
+/// Returns the entry point `name` of the default set or of any extension.
+pub fn entry_point(name: &str) -> Option<EntryPoint> {
+    entry_points::extended(all_extensions()).get(name).cloned()
+}
+
+/// Returns the entry points installed by the example token `example/erc1155-token`.
+pub fn example_token() -> EntryPoints {
+    entry_points::extended(all_extensions())
+}
+
+#[cfg(test)]
+mod drift_tests;
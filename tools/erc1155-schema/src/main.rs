@@ -0,0 +1,72 @@
+//! Prints the JSON schema of a set of ERC1155 token entry points.
+//!
+//! Usage:
+//!
+//! ```text
+//! erc1155-schema [--no-default] [--all-extensions] [--extension <name>]...
+//!                [--entry-point <name>]... [--output <path>]
+//! ```
+//!
+//! Without flags, the schema of `entry_points::default()` is printed. `--no-default` together with
+//! `--entry-point` describes a custom set passed to `install_custom`.
+use std::{env, fs, process};
+
+use casper_erc1155::{entry_points, schema};
+use casper_types::EntryPoints;
+use erc1155_schema::{all_extensions, entry_point, extension, EXTENSIONS};
+
+const USAGE: &str = "usage: erc1155-schema [--no-default] [--all-extensions] \
+    [--extension <name>]... [--entry-point <name>]... [--output <path>]";
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1)
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut with_default = true;
+    let mut selected = Vec::new();
+    let mut output = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| fail(USAGE));
+        match arg.as_str() {
+            "--no-default" => with_default = false,
+            "--all-extensions" => selected.extend(all_extensions()),
+            "--extension" => {
+                let name = value();
+                let extension = extension(&name).unwrap_or_else(|| {
+                    fail(&format!(
+                        "unknown extension `{}`, expected one of: {}",
+                        name,
+                        EXTENSIONS.join(", ")
+                    ))
+                });
+                selected.extend(extension);
+            }
+            "--entry-point" => {
+                let name = value();
+                let entry_point = entry_point(&name)
+                    .unwrap_or_else(|| fail(&format!("unknown entry point `{}`", name)));
+                selected.push(entry_point);
+            }
+            "--output" => output = Some(value()),
+            _ => fail(USAGE),
+        }
+    }
+
+    let entry_points = if with_default {
+        entry_points::extended(selected)
+    } else {
+        let mut entry_points = EntryPoints::new();
+        for entry_point in selected {
+            entry_points.add_entry_point(entry_point);
+        }
+        entry_points
+    };
+    let json = schema::to_json(&entry_points);
+    match output {
+        Some(path) => fs::write(path, json + "\n").unwrap_or_else(|error| fail(&error.to_string())),
+        None => println!("{}", json),
+    }
+}